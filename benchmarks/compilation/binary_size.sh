@@ -0,0 +1,49 @@
+#!/usr/bin/env bash
+#
+# Measures the size of the machine code generated for the builders. It's
+# mostly affected by how many times the setters are instantiated for the
+# different types of their arguments and the states of the builder.
+
+set -euo pipefail
+
+macros=(
+    bon
+    typed-builder
+    derive_builder
+    ""
+)
+
+suites=(
+    "${@:-into_structs_10_fields_20}"
+)
+
+out_dir=$(mktemp -d)
+
+trap 'rm -rf "$out_dir"' EXIT
+
+echo "| Suite | Macro | Debug \`.text\` [bytes] | Release \`.text\` [bytes] |"
+echo "| :---- | :---- | ---------------------: | -----------------------: |"
+
+for suite in "${suites[@]}"; do
+    for macro in "${macros[@]}"; do
+        sizes=()
+
+        for profile in dev release; do
+            obj="$out_dir/$suite-${macro:-none}-$profile.o"
+
+            cargo rustc \
+                --quiet \
+                --package compilation-benchmarks \
+                --lib \
+                --profile "$profile" \
+                --features "$suite,$macro" \
+                -- \
+                --emit "obj=$obj" \
+                -C codegen-units=1
+
+            sizes+=("$(size -A "$obj" | awk '$1 ~ /^\.text/ { sum += $2 } END { print sum }')")
+        done
+
+        echo "| \`$suite\` | \`${macro:-none}\` | ${sizes[0]} | ${sizes[1]} |"
+    done
+done
@@ -20,6 +20,16 @@ fn main() -> anyhow::Result<()> {
         structs_n_fields_n(structs_number, fields_number).to_string(),
     )?;
 
+    let structs_number = 10;
+    let fields_number = 20;
+
+    std::fs::write(
+        src_dir.join(format!(
+            "into_structs_{structs_number}_fields_{fields_number}.rs"
+        )),
+        into_structs_n_fields_n(structs_number, fields_number).to_string(),
+    )?;
+
     println!("Running cargo fmt in {}", bench_dir.display());
 
     let status = std::process::Command::new("cargo")
@@ -58,3 +68,78 @@ fn structs_n_fields_n(structs_number: usize, fields_number: usize) -> TokenStrea
         })
         .collect()
 }
+
+/// Generates structs with `String` fields that have `into` setters and the
+/// functions that call these setters with arguments of different types and
+/// in different order. This way the setters are instantiated for a lot of
+/// combinations of the argument types and the builder's states, which
+/// shows how much code is generated for them.
+fn into_structs_n_fields_n(structs_number: usize, fields_number: usize) -> TokenStream {
+    let field_names = (1..=fields_number)
+        .map(|i| format_ident!("x{i}"))
+        .collect::<Vec<_>>();
+
+    let values = [
+        quote!("value"),
+        quote!(String::new()),
+        quote!('v'),
+        quote!(Box::<str>::from("value")),
+    ];
+
+    (1..=structs_number)
+        .map(|i| {
+            let struct_name = format_ident!("Struct{i}");
+            let builder_name = format_ident!("Struct{i}Builder");
+
+            let build_fns = (0..values.len()).map(|j| {
+                let fn_name = format_ident!("build_struct{i}_{j}");
+
+                // The setters are called in the same order in every function,
+                // but the types of their arguments are rotated. This way every
+                // state of the builder is reached with arguments of different types.
+                let fields = (0..fields_number).map(|k| {
+                    let name = &field_names[(k + i * 3) % fields_number];
+                    let value = &values[(k + j) % values.len()];
+                    quote!(#name: #value)
+                });
+
+                quote! {
+                    #[must_use]
+                    pub fn #fn_name() -> #struct_name {
+                        build!(#struct_name, #builder_name { #( #fields, )* })
+                    }
+                }
+            });
+
+            quote! {
+                #[cfg_attr(
+                    any(
+                        feature = "bon",
+                        feature = "typed-builder",
+                        feature = "derive_builder",
+                    ),
+                    derive(crate::Builder),
+                )]
+                // The config of the builder must match the macro selected by
+                // the same priority as the `crate::Builder` import in `lib.rs`
+                #[cfg_attr(feature = "bon", builder(on(String, into)))]
+                #[cfg_attr(
+                    all(feature = "typed-builder", not(feature = "bon")),
+                    builder(field_defaults(setter(into))),
+                )]
+                #[cfg_attr(
+                    all(
+                        feature = "derive_builder",
+                        not(any(feature = "bon", feature = "typed-builder")),
+                    ),
+                    builder(setter(into)),
+                )]
+                pub struct #struct_name {
+                    #( #field_names: String, )*
+                }
+
+                #( #build_fns )*
+            }
+        })
+        .collect()
+}
@@ -11,6 +11,7 @@ macros=(
 suites=(
     structs_100_fields_10
     structs_10_fields_50
+    into_structs_10_fields_20
 )
 
 hyperfine \
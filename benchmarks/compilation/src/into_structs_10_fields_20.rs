@@ -0,0 +1,1500 @@
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct1 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct1_0() -> Struct1 {
+    build!(
+        Struct1,
+        Struct1Builder {
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct1_1() -> Struct1 {
+    build!(
+        Struct1,
+        Struct1Builder {
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct1_2() -> Struct1 {
+    build!(
+        Struct1,
+        Struct1Builder {
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct1_3() -> Struct1 {
+    build!(
+        Struct1,
+        Struct1Builder {
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct2 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct2_0() -> Struct2 {
+    build!(
+        Struct2,
+        Struct2Builder {
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct2_1() -> Struct2 {
+    build!(
+        Struct2,
+        Struct2Builder {
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct2_2() -> Struct2 {
+    build!(
+        Struct2,
+        Struct2Builder {
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct2_3() -> Struct2 {
+    build!(
+        Struct2,
+        Struct2Builder {
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct3 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct3_0() -> Struct3 {
+    build!(
+        Struct3,
+        Struct3Builder {
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct3_1() -> Struct3 {
+    build!(
+        Struct3,
+        Struct3Builder {
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct3_2() -> Struct3 {
+    build!(
+        Struct3,
+        Struct3Builder {
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct3_3() -> Struct3 {
+    build!(
+        Struct3,
+        Struct3Builder {
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct4 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct4_0() -> Struct4 {
+    build!(
+        Struct4,
+        Struct4Builder {
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct4_1() -> Struct4 {
+    build!(
+        Struct4,
+        Struct4Builder {
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct4_2() -> Struct4 {
+    build!(
+        Struct4,
+        Struct4Builder {
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct4_3() -> Struct4 {
+    build!(
+        Struct4,
+        Struct4Builder {
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct5 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct5_0() -> Struct5 {
+    build!(
+        Struct5,
+        Struct5Builder {
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct5_1() -> Struct5 {
+    build!(
+        Struct5,
+        Struct5Builder {
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct5_2() -> Struct5 {
+    build!(
+        Struct5,
+        Struct5Builder {
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct5_3() -> Struct5 {
+    build!(
+        Struct5,
+        Struct5Builder {
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct6 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct6_0() -> Struct6 {
+    build!(
+        Struct6,
+        Struct6Builder {
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct6_1() -> Struct6 {
+    build!(
+        Struct6,
+        Struct6Builder {
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct6_2() -> Struct6 {
+    build!(
+        Struct6,
+        Struct6Builder {
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct6_3() -> Struct6 {
+    build!(
+        Struct6,
+        Struct6Builder {
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct7 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct7_0() -> Struct7 {
+    build!(
+        Struct7,
+        Struct7Builder {
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct7_1() -> Struct7 {
+    build!(
+        Struct7,
+        Struct7Builder {
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct7_2() -> Struct7 {
+    build!(
+        Struct7,
+        Struct7Builder {
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct7_3() -> Struct7 {
+    build!(
+        Struct7,
+        Struct7Builder {
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct8 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct8_0() -> Struct8 {
+    build!(
+        Struct8,
+        Struct8Builder {
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct8_1() -> Struct8 {
+    build!(
+        Struct8,
+        Struct8Builder {
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct8_2() -> Struct8 {
+    build!(
+        Struct8,
+        Struct8Builder {
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct8_3() -> Struct8 {
+    build!(
+        Struct8,
+        Struct8Builder {
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct9 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct9_0() -> Struct9 {
+    build!(
+        Struct9,
+        Struct9Builder {
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct9_1() -> Struct9 {
+    build!(
+        Struct9,
+        Struct9Builder {
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct9_2() -> Struct9 {
+    build!(
+        Struct9,
+        Struct9Builder {
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct9_3() -> Struct9 {
+    build!(
+        Struct9,
+        Struct9Builder {
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+        }
+    )
+}
+#[cfg_attr(
+    any(feature = "bon", feature = "typed-builder", feature = "derive_builder",),
+    derive(crate::Builder)
+)]
+#[cfg_attr(feature = "bon", builder(on(String, into)))]
+#[cfg_attr(
+    all(feature = "typed-builder", not(feature = "bon")),
+    builder(field_defaults(setter(into)))
+)]
+#[cfg_attr(
+    all(
+        feature = "derive_builder",
+        not(any(feature = "bon", feature = "typed-builder")),
+    ),
+    builder(setter(into))
+)]
+pub struct Struct10 {
+    x1: String,
+    x2: String,
+    x3: String,
+    x4: String,
+    x5: String,
+    x6: String,
+    x7: String,
+    x8: String,
+    x9: String,
+    x10: String,
+    x11: String,
+    x12: String,
+    x13: String,
+    x14: String,
+    x15: String,
+    x16: String,
+    x17: String,
+    x18: String,
+    x19: String,
+    x20: String,
+}
+#[must_use]
+pub fn build_struct10_0() -> Struct10 {
+    build!(
+        Struct10,
+        Struct10Builder {
+            x11: "value",
+            x12: String::new(),
+            x13: 'v',
+            x14: Box::<str>::from("value"),
+            x15: "value",
+            x16: String::new(),
+            x17: 'v',
+            x18: Box::<str>::from("value"),
+            x19: "value",
+            x20: String::new(),
+            x1: 'v',
+            x2: Box::<str>::from("value"),
+            x3: "value",
+            x4: String::new(),
+            x5: 'v',
+            x6: Box::<str>::from("value"),
+            x7: "value",
+            x8: String::new(),
+            x9: 'v',
+            x10: Box::<str>::from("value"),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct10_1() -> Struct10 {
+    build!(
+        Struct10,
+        Struct10Builder {
+            x11: String::new(),
+            x12: 'v',
+            x13: Box::<str>::from("value"),
+            x14: "value",
+            x15: String::new(),
+            x16: 'v',
+            x17: Box::<str>::from("value"),
+            x18: "value",
+            x19: String::new(),
+            x20: 'v',
+            x1: Box::<str>::from("value"),
+            x2: "value",
+            x3: String::new(),
+            x4: 'v',
+            x5: Box::<str>::from("value"),
+            x6: "value",
+            x7: String::new(),
+            x8: 'v',
+            x9: Box::<str>::from("value"),
+            x10: "value",
+        }
+    )
+}
+#[must_use]
+pub fn build_struct10_2() -> Struct10 {
+    build!(
+        Struct10,
+        Struct10Builder {
+            x11: 'v',
+            x12: Box::<str>::from("value"),
+            x13: "value",
+            x14: String::new(),
+            x15: 'v',
+            x16: Box::<str>::from("value"),
+            x17: "value",
+            x18: String::new(),
+            x19: 'v',
+            x20: Box::<str>::from("value"),
+            x1: "value",
+            x2: String::new(),
+            x3: 'v',
+            x4: Box::<str>::from("value"),
+            x5: "value",
+            x6: String::new(),
+            x7: 'v',
+            x8: Box::<str>::from("value"),
+            x9: "value",
+            x10: String::new(),
+        }
+    )
+}
+#[must_use]
+pub fn build_struct10_3() -> Struct10 {
+    build!(
+        Struct10,
+        Struct10Builder {
+            x11: Box::<str>::from("value"),
+            x12: "value",
+            x13: String::new(),
+            x14: 'v',
+            x15: Box::<str>::from("value"),
+            x16: "value",
+            x17: String::new(),
+            x18: 'v',
+            x19: Box::<str>::from("value"),
+            x20: "value",
+            x1: String::new(),
+            x2: 'v',
+            x3: Box::<str>::from("value"),
+            x4: "value",
+            x5: String::new(),
+            x6: 'v',
+            x7: Box::<str>::from("value"),
+            x8: "value",
+            x9: String::new(),
+            x10: 'v',
+        }
+    )
+}
@@ -15,8 +15,28 @@ cfg_if::cfg_if! {
     }
 }
 
+/// Builds the struct using the API of the selected builder macro. If no macro
+/// is selected, the struct is constructed directly as a baseline.
+#[cfg(feature = "into_structs_10_fields_20")]
+macro_rules! build {
+    ($struct:ident, $builder:ident { $( $field:ident: $value:expr_2021, )* }) => {{
+        cfg_if::cfg_if! {
+            if #[cfg(any(feature = "bon", feature = "typed-builder"))] {
+                $struct::builder() $( .$field($value) )* .build()
+            } else if #[cfg(feature = "derive_builder")] {
+                $builder::default() $( .$field($value) )* .build().unwrap()
+            } else {
+                $struct { $( $field: $value.into(), )* }
+            }
+        }
+    }};
+}
+
 #[cfg(feature = "structs_100_fields_10")]
 pub mod structs_100_fields_10;
 
 #[cfg(feature = "structs_10_fields_50")]
 pub mod structs_10_fields_50;
+
+#[cfg(feature = "into_structs_10_fields_20")]
+pub mod into_structs_10_fields_20;
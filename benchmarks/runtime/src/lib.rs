@@ -32,9 +32,15 @@ cfg_if::cfg_if! {
     } else if #[cfg(feature = "args_10_alloc")] {
         pub mod args_10_alloc;
         pub use args_10_alloc as bench;
+    } else if #[cfg(feature = "args_10_maybe_uninit")] {
+        pub mod args_10_maybe_uninit;
+        pub use args_10_maybe_uninit as bench;
     } else if #[cfg(feature = "args_10_structs")] {
         pub mod args_10_structs;
         pub use args_10_structs as bench;
+    } else if #[cfg(feature = "args_20_maybe_uninit")] {
+        pub mod args_20_maybe_uninit;
+        pub use args_20_maybe_uninit as bench;
     } else {
         pub mod args_20;
         pub use args_20 as bench;
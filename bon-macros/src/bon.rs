@@ -1,5 +1,5 @@
 use crate::builder;
-use crate::normalization::{ExpandCfg, Expansion};
+use crate::normalization::{ExpandCfg, ExpandPresets, Expansion};
 use crate::util::prelude::*;
 use darling::ast::NestedMeta;
 use darling::FromMeta;
@@ -23,15 +23,30 @@ pub(crate) fn try_generate(params: TokenStream, item: TokenStream) -> Result<Tok
         Expansion::Recurse(output) => return Ok(output),
     };
 
+    let ctx = ExpandPresets {
+        current_macro: syn::parse_quote!(bon),
+        config: input.config,
+        item: input.item,
+    };
+
+    let input = match ctx.expand_presets()? {
+        Expansion::Expanded(input) => input,
+        Expansion::Recurse(output) => return Ok(output),
+    };
+
     let params = NestedMeta::parse_meta_list(input.config)?;
-    let params = FromMeta::from_list(&params)?;
 
     match *input.item {
-        syn::Item::Impl(item_impl) => builder::item_impl::generate(params, item_impl),
+        syn::Item::Impl(item_impl) => {
+            builder::item_impl::generate(FromMeta::from_list(&params)?, item_impl)
+        }
+        syn::Item::Trait(item_trait) => {
+            builder::item_trait::generate(FromMeta::from_list(&params)?, item_trait)
+        }
         _ => bail!(
             &input.item,
             "`#[bon]` attribute is expected to be placed on an `impl` block \
-             but it was placed on other syntax instead"
+             or a `trait` but it was placed on other syntax instead"
         ),
     }
 }
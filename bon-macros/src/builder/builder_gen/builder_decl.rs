@@ -1,4 +1,3 @@
-use crate::builder::builder_gen::NamedMember;
 use crate::util::prelude::*;
 
 impl super::BuilderGenCtx {
@@ -10,31 +9,7 @@ impl super::BuilderGenCtx {
         let phantom_data = self.phantom_data();
         let state_mod = &self.state_mod.ident;
 
-        // The fields can't be hidden using Rust's privacy syntax.
-        // The details about this are described in the blog post:
-        // https://bon-rs.com/blog/the-weird-of-function-local-types-in-rust.
-        //
-        // We could use `#[cfg(not(rust_analyzer))]` to hide the private fields in IDE.
-        // However, RA would then not be able to type-check the generated code, which
-        // may or may not be a problem, because the main thing is that the type signatures
-        // would still work in RA.
-        let private_field_attrs = {
-            // The message is defined separately to make it single-line in the
-            // generated code. This simplifies the task of removing unnecessary
-            // attributes from the generated code when preparing for demo purposes.
-            let deprecated_msg = "\
-                this field should not be used directly; it's an implementation detail, and \
-                if you access it directly, you may break some internal unsafe invariants; \
-                if you found yourself needing it, then you are probably doing something wrong; \
-                feel free to open an issue/discussion in our GitHub repository \
-                (https://github.com/elastio/bon) or ask for help in our Discord server \
-                (https://bon-rs.com/discord)";
-
-            quote! {
-                #[doc(hidden)]
-                #[deprecated = #deprecated_msg]
-            }
-        };
+        let private_field_attrs = private_field_attrs();
 
         let receiver_field = self.receiver().map(|receiver| {
             let ident = &receiver.field_ident;
@@ -45,10 +20,18 @@ impl super::BuilderGenCtx {
             }
         });
 
-        let must_use_message = format!(
-            "the builder does nothing until you call `{}()` on it to finish building",
-            self.finish_fn.ident
-        );
+        let docs = &self.builder_type.docs;
+        let attrs = &self.builder_type.attrs;
+
+        // The user may override the default `#[must_use]` message via `builder_type(attrs(...))`
+        let must_use = (!attrs.iter().any(|attr| attr.path().is_ident("must_use"))).then(|| {
+            let message = format!(
+                "the builder does nothing until you call `{}()` on it to finish building",
+                self.finish_fn.ident
+            );
+
+            quote!(#[must_use = #message])
+        });
 
         let allows = super::allow_warnings_on_member_types();
 
@@ -65,17 +48,21 @@ impl super::BuilderGenCtx {
             }
         });
 
-        let named_members_types = self.named_members().map(NamedMember::underlying_norm_ty);
+        let named_members_types = self
+            .named_members()
+            .map(|member| self.named_member_storage_ty(member));
 
-        let docs = &self.builder_type.docs;
-        let state_var = &self.state_var;
+        let state_param = self
+            .state_arg()
+            .map(|state_var| quote!(#state_var: #state_mod::State = #state_mod::Empty));
 
         let custom_fields_idents = self.custom_fields().map(|field| &field.ident);
         let custom_fields_types = self.custom_fields().map(|field| &field.norm_ty);
 
         quote! {
-            #[must_use = #must_use_message]
+            #must_use
             #(#docs)*
+            #(#attrs)*
             #allows
             #[allow(
                 // We use `__private` prefix for all fields intentionally to hide them
@@ -98,7 +85,7 @@ impl super::BuilderGenCtx {
                 // On the flip side, if we have a custom `Drop` impl, then partially moving
                 // the builder will be impossible. So.. it's a trade-off, and it's probably
                 // not a big deal to remove this bound from here if we feel like it.
-                #state_var: #state_mod::State = #state_mod::Empty
+                #state_param
             >
             #where_clause
             {
@@ -114,10 +101,36 @@ impl super::BuilderGenCtx {
                 #private_field_attrs
                 __unsafe_private_named: (
                     #(
-                        ::core::option::Option<#named_members_types>,
+                        #named_members_types,
                     )*
                 ),
             }
         }
     }
 }
+
+/// The fields can't be hidden using Rust's privacy syntax.
+/// The details about this are described in the blog post:
+/// <https://bon-rs.com/blog/the-weird-of-function-local-types-in-rust>.
+///
+/// We could use `#[cfg(not(rust_analyzer))]` to hide the private fields in IDE.
+/// However, RA would then not be able to type-check the generated code, which
+/// may or may not be a problem, because the main thing is that the type signatures
+/// would still work in RA.
+pub(super) fn private_field_attrs() -> TokenStream {
+    // The message is defined separately to make it single-line in the
+    // generated code. This simplifies the task of removing unnecessary
+    // attributes from the generated code when preparing for demo purposes.
+    let deprecated_msg = "\
+        this field should not be used directly; it's an implementation detail, and \
+        if you access it directly, you may break some internal unsafe invariants; \
+        if you found yourself needing it, then you are probably doing something wrong; \
+        feel free to open an issue/discussion in our GitHub repository \
+        (https://github.com/elastio/bon) or ask for help in our Discord server \
+        (https://bon-rs.com/discord)";
+
+    quote! {
+        #[doc(hidden)]
+        #[deprecated = #deprecated_msg]
+    }
+}
@@ -32,7 +32,6 @@ impl BuilderGenCtx {
         });
 
         let where_clause = self.where_clause_for_derive(&clone, derive);
-        let state_mod = &self.state_mod.ident;
 
         let clone_named_members = self.named_members().map(|member| {
             let member_index = &member.index;
@@ -42,8 +41,14 @@ impl BuilderGenCtx {
             // in the input code using the span info from the type hint.
             let ty = member.underlying_norm_ty();
 
+            let clone_member = if self.is_uninit_member(member) {
+                quote!(clone_uninit_member::<_, #ty>)
+            } else {
+                quote!(clone_member::<#ty>)
+            };
+
             quote! {
-                #bon::__::better_errors::clone_member::<#ty>(
+                #bon::__::better_errors::#clone_member(
                     &self.__unsafe_private_named.#member_index
                 )
             }
@@ -61,17 +66,18 @@ impl BuilderGenCtx {
             }
         });
 
-        let state_var = &self.state_var;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
 
         quote! {
             #[automatically_derived]
             impl<
                 #(#generics_decl,)*
-                #state_var: #state_mod::State
+                #state_param
             >
             #clone for #builder_ident<
                 #(#generic_args,)*
-                #state_var
+                #state_arg
             >
             #where_clause
             {
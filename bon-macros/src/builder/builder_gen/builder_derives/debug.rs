@@ -36,11 +36,11 @@ impl BuilderGenCtx {
                     })
                 }
                 Member::Named(member) => {
-                    let member_index = &member.index;
                     let member_ident_str = &member.name.snake_raw_str;
                     let member_ty = member.underlying_norm_ty();
+                    let value = self.named_member_as_option(member);
                     Some(quote! {
-                        if let Some(value) = &self.__unsafe_private_named.#member_index {
+                        if let Some(value) = #value {
                             output.field(
                                 #member_ident_str,
                                 #bon::__::better_errors::as_dyn_debug::<#member_ty>(value)
@@ -72,22 +72,22 @@ impl BuilderGenCtx {
 
         let debug = quote!(::core::fmt::Debug);
         let where_clause = self.where_clause_for_derive(&debug, derive);
-        let state_mod = &self.state_mod.ident;
         let generics_decl = &self.generics.decl_without_defaults;
         let generic_args = &self.generics.args;
         let builder_ident = &self.builder_type.ident;
-        let state_var = &self.state_var;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
         let builder_ident_str = builder_ident.to_string();
 
         quote! {
             #[automatically_derived]
             impl<
                 #(#generics_decl,)*
-                #state_var: #state_mod::State
+                #state_param
             >
             #debug for #builder_ident<
                 #(#generic_args,)*
-                #state_var
+                #state_arg
             >
             #where_clause
             {
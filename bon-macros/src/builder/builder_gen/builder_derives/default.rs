@@ -0,0 +1,49 @@
+use crate::builder::builder_gen::models::BuilderGenCtx;
+use crate::builder::builder_gen::top_level_config::DeriveConfig;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    pub(super) fn derive_default(&self, derive: &DeriveConfig) -> Result<TokenStream> {
+        if let Some(receiver) = self.receiver() {
+            bail!(
+                &receiver.with_self_keyword.self_token,
+                "`#[builder(derive(Default))]` is not supported for methods with a `self` \
+                receiver because `Default::default()` method has no value of `Self` to \
+                store in the builder"
+            )
+        }
+
+        if let Some(arg) = self.start_fn_args().next() {
+            bail!(
+                &arg.config.start_fn.span(),
+                "`#[builder(derive(Default))]` is incompatible with `#[builder(start_fn)]` members \
+                because `Default::default()` method accepts zero parameters"
+            )
+        }
+
+        let default = quote!(::core::default::Default);
+        let where_clause = self.where_clause_for_derive(&default, derive);
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let builder_ident = &self.builder_type.ident;
+        let builder_init = self.builder_init();
+
+        // The implementation is generated only for the initial state of the
+        // builder, which is the default value of the state generic parameter.
+        Ok(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+            >
+            #default for #builder_ident<
+                #(#generic_args,)*
+            >
+            #where_clause
+            {
+                fn default() -> Self {
+                    #builder_init
+                }
+            }
+        })
+    }
+}
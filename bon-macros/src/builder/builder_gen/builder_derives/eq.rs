@@ -0,0 +1,136 @@
+use crate::builder::builder_gen::models::BuilderGenCtx;
+use crate::builder::builder_gen::top_level_config::DeriveConfig;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    pub(super) fn derive_partial_eq(&self, derive: &DeriveConfig) -> TokenStream {
+        let bon = &self.bon;
+        let partial_eq = quote!(::core::cmp::PartialEq);
+
+        // The type hints here are necessary to get better error messages
+        // that point directly to the type that doesn't implement `PartialEq`
+        // in the input code using the span info from the type hint.
+        let eq_receiver = self.receiver().map(|receiver| {
+            let ident = &receiver.field_ident;
+            let ty = &receiver.without_self_keyword;
+            quote! {
+                <#ty as #partial_eq>::eq(&self.#ident, &other.#ident)
+            }
+        });
+
+        let eq_start_fn_args = self.start_fn_args().map(|member| {
+            let ident = &member.ident;
+            let ty = &member.ty.norm;
+            quote! {
+                <#ty as #partial_eq>::eq(&self.#ident, &other.#ident)
+            }
+        });
+
+        let eq_fields = self.custom_fields().map(|member| {
+            let ident = &member.ident;
+            let ty = &member.norm_ty;
+            quote! {
+                <#ty as #partial_eq>::eq(&self.#ident, &other.#ident)
+            }
+        });
+
+        // Comparing the values as `Option`s also compares the set/unset state
+        // of the members.
+        let eq_named_members = self.named_members().map(|member| {
+            let ty = member.underlying_norm_ty();
+            let this = self.named_member_as_option(member);
+            let other = self.named_member_as_option_in(&quote!(other), member);
+            quote! {
+                #bon::__::better_errors::eq_member::<#ty>(#this, #other)
+            }
+        });
+
+        let comparisons = eq_receiver
+            .into_iter()
+            .chain(eq_start_fn_args)
+            .chain(eq_fields)
+            .chain(eq_named_members);
+
+        let where_clause = self.where_clause_for_derive(&partial_eq, derive);
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let builder_ident = &self.builder_type.ident;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
+
+        quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #partial_eq for #builder_ident<
+                #(#generic_args,)*
+                #state_arg
+            >
+            #where_clause
+            {
+                fn eq(&self, other: &Self) -> bool {
+                    true #( && #comparisons )*
+                }
+            }
+        }
+    }
+
+    pub(super) fn derive_eq(&self, derive: &DeriveConfig) -> TokenStream {
+        let bon = &self.bon;
+        let eq = quote!(::core::cmp::Eq);
+
+        let member_types = std::iter::empty()
+            .chain(
+                self.receiver()
+                    .map(|receiver| receiver.without_self_keyword.to_token_stream()),
+            )
+            .chain(
+                self.start_fn_args()
+                    .map(|member| member.ty.norm.to_token_stream()),
+            )
+            .chain(
+                self.custom_fields()
+                    .map(|member| member.norm_ty.to_token_stream()),
+            )
+            .chain(
+                self.named_members()
+                    .map(|member| member.underlying_norm_ty().to_token_stream()),
+            );
+
+        let where_clause = self.where_clause_for_derive(&eq, derive);
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let builder_ident = &self.builder_type.ident;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
+
+        quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #eq for #builder_ident<
+                #(#generic_args,)*
+                #state_arg
+            >
+            #where_clause
+            {}
+
+            // The `Eq` trait has no methods, so this function checks that all
+            // members implement `Eq`, the same way the standard `#[derive(Eq)]`
+            // does. The type hints produce errors that point directly to the
+            // type that doesn't implement `Eq` in the input code.
+            const _: () = {
+                #[allow(dead_code, clippy::extra_unused_type_parameters)]
+                fn assert_members_eq<#(#generics_decl,)*>()
+                #where_clause
+                {
+                    #( #bon::__::better_errors::assert_eq::<#member_types>(); )*
+                }
+            };
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::builder::builder_gen::models::BuilderGenCtx;
+use crate::builder::builder_gen::top_level_config::DeriveConfig;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    pub(super) fn derive_hash(&self, derive: &DeriveConfig) -> TokenStream {
+        let bon = &self.bon;
+        let hash = quote!(::core::hash::Hash);
+
+        // The type hints here are necessary to get better error messages
+        // that point directly to the type that doesn't implement `Hash`
+        // in the input code using the span info from the type hint.
+        let hash_receiver = self.receiver().map(|receiver| {
+            let ident = &receiver.field_ident;
+            let ty = &receiver.without_self_keyword;
+            quote! {
+                <#ty as #hash>::hash(&self.#ident, state);
+            }
+        });
+
+        let hash_start_fn_args = self.start_fn_args().map(|member| {
+            let ident = &member.ident;
+            let ty = &member.ty.norm;
+            quote! {
+                <#ty as #hash>::hash(&self.#ident, state);
+            }
+        });
+
+        let hash_fields = self.custom_fields().map(|member| {
+            let ident = &member.ident;
+            let ty = &member.norm_ty;
+            quote! {
+                <#ty as #hash>::hash(&self.#ident, state);
+            }
+        });
+
+        // Hashing the values as `Option`s also hashes the set/unset state
+        // of the members.
+        let hash_named_members = self.named_members().map(|member| {
+            let ty = member.underlying_norm_ty();
+            let value = self.named_member_as_option(member);
+            quote! {
+                #bon::__::better_errors::hash_member::<#ty, _>(#value, state);
+            }
+        });
+
+        let where_clause = self.where_clause_for_derive(&hash, derive);
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let builder_ident = &self.builder_type.ident;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
+
+        quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #hash for #builder_ident<
+                #(#generic_args,)*
+                #state_arg
+            >
+            #where_clause
+            {
+                fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+                    #hash_receiver
+                    #( #hash_start_fn_args )*
+                    #( #hash_fields )*
+                    #( #hash_named_members )*
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use super::{find_assoc_type_binding, find_impl_trait};
 use crate::builder::builder_gen::models::BuilderGenCtx;
 use crate::builder::builder_gen::top_level_config::IntoFutureConfig;
 use crate::util::prelude::*;
@@ -7,19 +8,6 @@ use syn::visit_mut::VisitMut;
 
 impl BuilderGenCtx {
     pub(super) fn derive_into_future(&self, config: &IntoFutureConfig) -> Result<TokenStream> {
-        if self.finish_fn.asyncness.is_none() {
-            // While it is technically possible to call a synchronous function
-            // inside of the `IntoFuture::into_future()`, it's better force the
-            // user to mark the function as `async` explicitly. Otherwise it may
-            // indicate of some logic bug where the developer mistakenly marks
-            // a function that could be sync with `derive(IntoFuture)`.
-            bail!(
-                &self.finish_fn.ident,
-                "`#[builder(derive(IntoFuture(...)))` can only be used with async functions; \
-                using it with a synchronous function is likely a mistake"
-            );
-        }
-
         if let Some(unsafety) = &self.finish_fn.unsafety {
             bail!(
                 unsafety,
@@ -36,19 +24,132 @@ impl BuilderGenCtx {
             );
         }
 
+        // For synchronous functions the return type must be a future
+        let sync_output_ty = match (&self.finish_fn.asyncness, &self.finish_fn.output) {
+            (Some(_), _) => None,
+            (None, syn::ReturnType::Type(_, output_ty)) => Some(output_ty.as_ref()),
+            (None, syn::ReturnType::Default) => {
+                // While it is technically possible to call a synchronous function
+                // inside of the `IntoFuture::into_future()`, it's better force the
+                // user to mark the function as `async` explicitly. Otherwise it may
+                // indicate of some logic bug where the developer mistakenly marks
+                // a function that could be sync with `derive(IntoFuture)`.
+                bail!(
+                    &self.finish_fn.ident,
+                    "`#[builder(derive(IntoFuture(...)))` can only be used with async functions \
+                    or functions that return a future; using it with a synchronous function \
+                    that returns nothing is likely a mistake"
+                );
+            }
+        };
+
+        // The result of an async function is the output of the future, so it may
+        // be a `Result`, but the result of a synchronous function must be a future.
+        if sync_output_ty.is_some() && self.finish_fn_error_ty().is_some() {
+            bail!(
+                &self.finish_fn.ident,
+                "`#[builder(derive(IntoFuture(...)))` is incompatible with a fallible \
+                synchronous finishing function; it returns a `Result` because of the \
+                validation or the runtime check of required members, so the future \
+                can't be obtained from it without handling the error"
+            );
+        }
+
+        match (&config.box_ident, sync_output_ty) {
+            (Some(box_ident), _) => self.derive_into_future_boxed(box_ident, config.is_send),
+            (None, Some(output_ty)) => self.derive_into_future_unboxed(output_ty),
+            (None, None) => bail!(
+                &self.finish_fn.asyncness,
+                "`#[builder(derive(IntoFuture))]` requires a nameable future type, \
+                but the futures returned by async functions can't be named; \
+                use `#[builder(derive(IntoFuture(Box)))]` instead"
+            ),
+        }
+    }
+
+    /// The future returned by the synchronous finishing function is used as is.
+    /// This is possible only if its type is nameable.
+    fn derive_into_future_unboxed(&self, output_ty: &syn::Type) -> Result<TokenStream> {
+        if let Some(impl_trait) = find_impl_trait(output_ty) {
+            bail!(
+                impl_trait,
+                "`#[builder(derive(IntoFuture))]` requires a nameable return type, \
+                because it's used in the `IntoFuture::IntoFuture` associated type; \
+                use `#[builder(derive(IntoFuture(Box)))]` for `impl Trait` return types"
+            );
+        }
+
         let state_mod = &self.state_mod.ident;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
         let builder_ident = &self.builder_type.ident;
-        let state_var = &self.state_var;
         let finish_fn_ident = &self.finish_fn.ident;
-        let box_ = &config.box_ident;
 
-        let SignatureForIntoFuture {
+        let state_param = self
+            .state_arg()
+            .map(|state_var| quote!(#state_var: #state_mod::IsComplete));
+        let state_arg = self.state_arg();
+
+        let into_future = quote!(::core::future::IntoFuture);
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #into_future for #builder_ident<#(#generic_args,)* #state_arg>
+            #where_clause
+            {
+                type Output = <#output_ty as #into_future>::Output;
+                type IntoFuture = <#output_ty as #into_future>::IntoFuture;
+
+                fn into_future(self) -> Self::IntoFuture {
+                    #into_future::into_future(#builder_ident::#finish_fn_ident(self))
+                }
+            }
+        })
+    }
+
+    fn derive_into_future_boxed(
+        &self,
+        box_ident: &syn::Ident,
+        is_send: bool,
+    ) -> Result<TokenStream> {
+        let state_mod = &self.state_mod.ident;
+        let builder_ident = &self.builder_type.ident;
+        let finish_fn_ident = &self.finish_fn.ident;
+
+        let SignatureForBoxedDerive {
             generics_decl,
             generic_args,
             where_clause,
             builder_lifetime,
             output_ty,
-        } = self.signature_for_into_future();
+        } = self.signature_for_boxed_derive();
+
+        let into_future = quote!(::core::future::IntoFuture);
+
+        // For synchronous functions the return type is the future itself. The
+        // `Output` type of an `impl Trait` can't be named via a projection,
+        // so we take it from the `Output = T` binding in the trait bounds.
+        let output_ty = if self.finish_fn.asyncness.is_some() {
+            output_ty.to_token_stream()
+        } else {
+            match find_impl_trait(&output_ty) {
+                None => quote!(<#output_ty as #into_future>::Output),
+                Some(impl_trait) => match find_assoc_type_binding(impl_trait, "Output") {
+                    Some(output_ty) => output_ty.to_token_stream(),
+                    None => bail!(
+                        impl_trait,
+                        "`#[builder(derive(IntoFuture(Box)))]` requires the `impl Trait` \
+                        return type to specify the output type explicitly e.g. \
+                        `impl Future<Output = T>`"
+                    ),
+                },
+            }
+        };
 
         let state_lifetime = builder_lifetime
             .clone()
@@ -56,7 +157,12 @@ impl BuilderGenCtx {
 
         let builder_lifetime = Option::into_iter(builder_lifetime);
 
-        let send_bound = if config.is_send {
+        let state_param = self
+            .state_arg()
+            .map(|state_var| quote!(#state_var: #state_mod::IsComplete + #state_lifetime));
+        let state_arg = self.state_arg();
+
+        let send_bound = if is_send {
             quote! { + ::core::marker::Send }
         } else {
             quote! {}
@@ -70,7 +176,7 @@ impl BuilderGenCtx {
             quote!(#bon::__::alloc)
         } else {
             bail!(
-                &config.box_ident,
+                box_ident,
                 "`#[builder(derive(IntoFuture(Box)))]` requires either `std` or \
                 `alloc` feature to be enabled"
             )
@@ -80,14 +186,14 @@ impl BuilderGenCtx {
             #[automatically_derived]
             impl<
                 #(#generics_decl,)*
-                #state_var: #state_mod::IsComplete + #state_lifetime
+                #state_param
             >
-            ::core::future::IntoFuture for #builder_ident<#(#generic_args,)* #state_var>
+            #into_future for #builder_ident<#(#generic_args,)* #state_arg>
             #where_clause
             {
                 type Output = #output_ty;
                 type IntoFuture = ::core::pin::Pin<
-                    #alloc::boxed::#box_<
+                    #alloc::boxed::#box_ident<
                         dyn ::core::future::Future<Output = Self::Output>
                         #send_bound
                         #(+ #builder_lifetime)*
@@ -95,7 +201,9 @@ impl BuilderGenCtx {
                 >;
 
                 fn into_future(self) -> Self::IntoFuture {
-                    #alloc::boxed::#box_::pin(#builder_ident::#finish_fn_ident(self))
+                    #alloc::boxed::#box_ident::pin(
+                        #into_future::into_future(#builder_ident::#finish_fn_ident(self))
+                    )
                 }
             }
         };
@@ -107,7 +215,7 @@ impl BuilderGenCtx {
     ///
     /// Collapse all lifetimes into a single `'builder` lifetime. This is
     /// because `dyn Trait` supports only a single `+ 'lifetime` bound.
-    fn signature_for_into_future(&self) -> SignatureForIntoFuture<'_> {
+    pub(super) fn signature_for_boxed_derive(&self) -> SignatureForBoxedDerive<'_> {
         let generics_decl = &self.generics.decl_without_defaults;
         let generic_args = &self.generics.args;
         let where_clause = &self.generics.where_clause;
@@ -123,7 +231,7 @@ impl BuilderGenCtx {
         );
 
         if !contains_lifetimes {
-            return SignatureForIntoFuture {
+            return SignatureForBoxedDerive {
                 generics_decl: Cow::Borrowed(generics_decl),
                 generic_args: Cow::Borrowed(generic_args),
                 where_clause: where_clause.as_ref().map(Cow::Borrowed),
@@ -177,7 +285,7 @@ impl BuilderGenCtx {
 
         replace_lifetimes.visit_type_mut(&mut output_ty);
 
-        SignatureForIntoFuture {
+        SignatureForBoxedDerive {
             generics_decl: Cow::Owned(new_generics_decl),
             generic_args: Cow::Owned(new_generic_args),
             where_clause: new_where_clause.map(Cow::Owned),
@@ -187,12 +295,12 @@ impl BuilderGenCtx {
     }
 }
 
-struct SignatureForIntoFuture<'a> {
-    generics_decl: Cow<'a, [syn::GenericParam]>,
-    generic_args: Cow<'a, [syn::GenericArgument]>,
-    where_clause: Option<Cow<'a, syn::WhereClause>>,
-    builder_lifetime: Option<syn::Lifetime>,
-    output_ty: Cow<'a, syn::Type>,
+pub(super) struct SignatureForBoxedDerive<'a> {
+    pub(super) generics_decl: Cow<'a, [syn::GenericParam]>,
+    pub(super) generic_args: Cow<'a, [syn::GenericArgument]>,
+    pub(super) where_clause: Option<Cow<'a, syn::WhereClause>>,
+    pub(super) builder_lifetime: Option<syn::Lifetime>,
+    pub(super) output_ty: Cow<'a, syn::Type>,
 }
 
 struct ReplaceLifetimes<'a> {
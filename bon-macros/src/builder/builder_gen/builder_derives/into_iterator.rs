@@ -0,0 +1,191 @@
+use super::into_future::SignatureForBoxedDerive;
+use super::{find_assoc_type_binding, find_impl_trait};
+use crate::builder::builder_gen::models::BuilderGenCtx;
+use crate::builder::builder_gen::top_level_config::IntoIteratorConfig;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    pub(super) fn derive_into_iterator(&self, config: &IntoIteratorConfig) -> Result<TokenStream> {
+        if let Some(asyncness) = &self.finish_fn.asyncness {
+            bail!(
+                asyncness,
+                "`#[builder(derive(IntoIterator))]` is not supported for async functions \
+                because `IntoIterator::into_iter()` method is a synchronous method; \
+                use `#[builder(derive(IntoFuture(Box)))]` instead"
+            );
+        }
+
+        if let Some(unsafety) = &self.finish_fn.unsafety {
+            bail!(
+                unsafety,
+                "`#[builder(derive(IntoIterator))]` is not supported for unsafe functions \
+                because `IntoIterator::into_iter()` method is a safe method"
+            );
+        }
+
+        if let Some(arg) = self.finish_fn_args().next() {
+            bail!(
+                &arg.config.finish_fn.span(),
+                "`#[builder(derive(IntoIterator))]` is incompatible with `#[builder(finish_fn)]` \
+                members because `IntoIterator::into_iter()` method accepts zero parameters"
+            );
+        }
+
+        if self.finish_fn_error_ty().is_some() {
+            bail!(
+                &self.finish_fn.ident,
+                "`#[builder(derive(IntoIterator))]` is incompatible with a fallible \
+                finishing function; it returns a `Result` because of the validation \
+                or the runtime check of required members, so the iterator can't be \
+                obtained from it without handling the error"
+            );
+        }
+
+        let output_ty = match &self.finish_fn.output {
+            syn::ReturnType::Default => bail!(
+                &self.finish_fn.ident,
+                "`#[builder(derive(IntoIterator))]` requires the function to return \
+                an iterator, but it has the implicit unit return type"
+            ),
+            syn::ReturnType::Type(_, output_ty) => output_ty.as_ref(),
+        };
+
+        match &config.box_ident {
+            Some(box_ident) => self.derive_into_iterator_boxed(box_ident, config.is_send),
+            None => self.derive_into_iterator_unboxed(output_ty),
+        }
+    }
+
+    fn derive_into_iterator_unboxed(&self, output_ty: &syn::Type) -> Result<TokenStream> {
+        if let Some(impl_trait) = find_impl_trait(output_ty) {
+            bail!(
+                impl_trait,
+                "`#[builder(derive(IntoIterator))]` requires a nameable return type, \
+                because it's used in the `IntoIterator::IntoIter` associated type; \
+                use `#[builder(derive(IntoIterator(Box)))]` for `impl Trait` return types"
+            );
+        }
+
+        let state_mod = &self.state_mod.ident;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let finish_fn_ident = &self.finish_fn.ident;
+
+        let state_param = self
+            .state_arg()
+            .map(|state_var| quote!(#state_var: #state_mod::IsComplete));
+        let state_arg = self.state_arg();
+
+        let into_iterator = quote!(::core::iter::IntoIterator);
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #into_iterator for #builder_ident<#(#generic_args,)* #state_arg>
+            #where_clause
+            {
+                type Item = <#output_ty as #into_iterator>::Item;
+                type IntoIter = <#output_ty as #into_iterator>::IntoIter;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    #into_iterator::into_iter(#builder_ident::#finish_fn_ident(self))
+                }
+            }
+        })
+    }
+
+    fn derive_into_iterator_boxed(
+        &self,
+        box_ident: &syn::Ident,
+        is_send: bool,
+    ) -> Result<TokenStream> {
+        let state_mod = &self.state_mod.ident;
+        let builder_ident = &self.builder_type.ident;
+        let finish_fn_ident = &self.finish_fn.ident;
+
+        let SignatureForBoxedDerive {
+            generics_decl,
+            generic_args,
+            where_clause,
+            builder_lifetime,
+            output_ty,
+        } = self.signature_for_boxed_derive();
+
+        let into_iterator = quote!(::core::iter::IntoIterator);
+
+        // The `Item` type of an `impl Trait` can't be named via a projection,
+        // so we take it from the `Item = T` binding in the trait bounds
+        let item_ty = match find_impl_trait(&output_ty) {
+            None => quote!(<#output_ty as #into_iterator>::Item),
+            Some(impl_trait) => match find_assoc_type_binding(impl_trait, "Item") {
+                Some(item_ty) => item_ty.to_token_stream(),
+                None => bail!(
+                    impl_trait,
+                    "`#[builder(derive(IntoIterator(Box)))]` requires the `impl Trait` \
+                    return type to specify the type of the items explicitly e.g. \
+                    `impl Iterator<Item = T>`"
+                ),
+            },
+        };
+
+        let state_lifetime = builder_lifetime
+            .clone()
+            .unwrap_or_else(|| syn::Lifetime::new("'static", Span::call_site()));
+
+        let builder_lifetime = Option::into_iter(builder_lifetime);
+
+        let state_param = self
+            .state_arg()
+            .map(|state_var| quote!(#state_var: #state_mod::IsComplete + #state_lifetime));
+        let state_arg = self.state_arg();
+
+        let send_bound = if is_send {
+            quote! { + ::core::marker::Send }
+        } else {
+            quote! {}
+        };
+
+        let bon = &self.bon;
+
+        let alloc = if cfg!(feature = "std") {
+            quote!(::std)
+        } else if cfg!(feature = "alloc") {
+            quote!(#bon::__::alloc)
+        } else {
+            bail!(
+                box_ident,
+                "`#[builder(derive(IntoIterator(Box)))]` requires either `std` or \
+                `alloc` feature to be enabled"
+            )
+        };
+
+        Ok(quote! {
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_param
+            >
+            #into_iterator for #builder_ident<#(#generic_args,)* #state_arg>
+            #where_clause
+            {
+                type Item = #item_ty;
+                type IntoIter = #alloc::boxed::#box_ident<
+                    dyn ::core::iter::Iterator<Item = Self::Item>
+                    #send_bound
+                    #(+ #builder_lifetime)*
+                >;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    #alloc::boxed::#box_ident::new(
+                        #into_iterator::into_iter(#builder_ident::#finish_fn_ident(self))
+                    )
+                }
+            }
+        })
+    }
+}
@@ -1,20 +1,30 @@
 mod clone;
 mod debug;
+mod default;
+mod eq;
+mod hash;
 mod into;
 mod into_future;
+mod into_iterator;
 
 use super::top_level_config::{DeriveConfig, DerivesConfig};
 use super::BuilderGenCtx;
 use crate::util::prelude::*;
 use darling::ast::GenericParamExt;
+use syn::visit::Visit;
 
 impl BuilderGenCtx {
     pub(crate) fn builder_derives(&self) -> Result<TokenStream> {
         let DerivesConfig {
             clone,
             debug,
+            partial_eq,
+            eq,
+            hash,
+            default,
             into,
             into_future,
+            into_iterator,
         } = &self.builder_type.derives;
 
         let mut tokens = TokenStream::new();
@@ -27,6 +37,22 @@ impl BuilderGenCtx {
             tokens.extend(self.derive_debug(derive));
         }
 
+        if let Some(derive) = partial_eq {
+            tokens.extend(self.derive_partial_eq(derive));
+        }
+
+        if let Some(derive) = eq {
+            tokens.extend(self.derive_eq(derive));
+        }
+
+        if let Some(derive) = hash {
+            tokens.extend(self.derive_hash(derive));
+        }
+
+        if let Some(derive) = default {
+            tokens.extend(self.derive_default(derive)?);
+        }
+
         if into.is_present() {
             tokens.extend(self.derive_into()?);
         }
@@ -35,6 +61,10 @@ impl BuilderGenCtx {
             tokens.extend(self.derive_into_future(derive)?);
         }
 
+        if let Some(derive) = into_iterator {
+            tokens.extend(self.derive_into_iterator(derive)?);
+        }
+
         Ok(tokens)
     }
 
@@ -81,3 +111,44 @@ impl BuilderGenCtx {
         }
     }
 }
+
+/// Finds the first `impl Trait` type in the given type
+fn find_impl_trait(ty: &syn::Type) -> Option<&syn::TypeImplTrait> {
+    struct FindImplTrait<'ast> {
+        found: Option<&'ast syn::TypeImplTrait>,
+    }
+
+    impl<'ast> Visit<'ast> for FindImplTrait<'ast> {
+        fn visit_type_impl_trait(&mut self, impl_trait: &'ast syn::TypeImplTrait) {
+            self.found.get_or_insert(impl_trait);
+        }
+    }
+
+    let mut visitor = FindImplTrait { found: None };
+    visitor.visit_type(ty);
+    visitor.found
+}
+
+/// Finds the associated type binding with the given name in the bounds of
+/// `impl Trait`, e.g. `T` in `impl Iterator<Item = T>` for the name `Item`.
+fn find_assoc_type_binding<'a>(
+    impl_trait: &'a syn::TypeImplTrait,
+    name: &str,
+) -> Option<&'a syn::Type> {
+    impl_trait
+        .bounds
+        .iter()
+        .filter_map(|bound| match bound {
+            syn::TypeParamBound::Trait(bound) => bound.path.segments.last(),
+            _ => None,
+        })
+        .filter_map(|segment| match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => Some(&args.args),
+            _ => None,
+        })
+        .flatten()
+        .find_map(|arg| match arg {
+            syn::GenericArgument::AssocType(assoc) if assoc.ident == name => Some(&assoc.ty),
+            _ => None,
+        })
+}
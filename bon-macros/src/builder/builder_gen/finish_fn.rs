@@ -27,11 +27,7 @@ impl super::BuilderGenCtx {
             }
         };
 
-        let index = &member.index;
-
-        let member_field = quote! {
-            self.__unsafe_private_named.#index
-        };
+        let member_field = self.named_member_into_option(member);
 
         let default = member
             .config
@@ -77,7 +73,9 @@ impl super::BuilderGenCtx {
 
                 // SAFETY: we know that the member is set because we are in
                 // the `finish` function where this method uses the trait
-                // bound of `IsSet` for every required member. It's also
+                // bound of `IsSet` for every required member. If the builder
+                // has no type state, then the `missing_members_check()` returns
+                // early from the `finish` function if any of them is unset. It's also
                 // not possible to intervene with the builder's state from
                 // the outside because all members of the builder are considered
                 // private (we even generate random names for them to make it
@@ -127,8 +125,13 @@ impl super::BuilderGenCtx {
             // or `skip` expressions.
             let ty = member.norm_ty();
 
+            let validation = member
+                .as_named()
+                .and_then(|member| self.member_validation(member));
+
             quote! {
                 let #var_ident: #ty = #expr;
+                #validation
             }
         });
 
@@ -136,17 +139,37 @@ impl super::BuilderGenCtx {
 
         let finish_fn_params = self.finish_fn_args().map(PosFnMember::fn_input_param);
 
-        let body = &self.finish_fn.body.generate(self);
+        let mut body = self.finish_fn.body.generate(self);
+        let finish_fn_validation = self.finish_fn_validation();
+        let missing_members_check = self.missing_members_check();
+
+        if self.finish_fn_error_ty().is_some() {
+            body = quote!(::core::result::Result::Ok(#body));
+        }
+
         let asyncness = &self.finish_fn.asyncness;
         let unsafety = &self.finish_fn.unsafety;
-        let special_attrs = &self.finish_fn.special_attrs;
         let attrs = &self.finish_fn.attrs;
+
+        // The user may override the default `#[must_use]` via `finish_fn(attrs(...))`
+        let has_custom_must_use = attrs.iter().any(|attr| attr.path().is_ident("must_use"));
+        let special_attrs = self
+            .finish_fn
+            .special_attrs
+            .iter()
+            .filter(|attr| !(has_custom_must_use && attr.path().is_ident("must_use")));
         let finish_fn_vis = &self.finish_fn.vis;
         let finish_fn_ident = &self.finish_fn.ident;
         let output = &self.finish_fn.output;
-        let state_var = &self.state_var;
         let const_ = &self.const_;
 
+        let where_clause = self.state_arg().map(|state_var| {
+            quote! {
+                where
+                    #state_var: #state_mod::IsComplete
+            }
+        });
+
         // `#[target_feature]` is not compatible with `#[inline(always)]`,
         // so we need to downgrade it to `#[inline]
         let inline_attr = self
@@ -180,10 +203,11 @@ impl super::BuilderGenCtx {
                 self,
                 #(#finish_fn_params,)*
             ) #output
-            where
-                #state_var: #state_mod::IsComplete
+            #where_clause
             {
+                #missing_members_check
                 #(#members_vars_decls)*
+                #finish_fn_validation
                 #body
             }
         }
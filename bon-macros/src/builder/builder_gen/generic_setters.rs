@@ -129,6 +129,7 @@ impl<'a> GenericSettersCtx<'a> {
             .unwrap_or(&self.base.builder_type.vis);
 
         let docs = self.method_docs(param_ident);
+        let attrs = self.config.attrs().unwrap_or_default();
 
         // Build the generic arguments for the output type, where the current parameter
         // is replaced with a new type variable. Even though the `GenericsNamespace`
@@ -179,17 +180,21 @@ impl<'a> GenericSettersCtx<'a> {
                         #state_var::#field_pascal: #state_mod::IsUnset
                     });
 
-                    // Add runtime assert that this field is None
-                    let field_ident = &member.name.orig;
-                    let message = format!(
-                        "BUG: field `{field_ident}` should be None \
-                        when converting generic parameter `{param_ident}`"
-                    );
-                    runtime_asserts.push(quote! {
-                        ::core::assert!(named.#index.is_none(), #message);
-                    });
-                    // Field uses the generic parameter, so create a new None
-                    quote!(::core::option::Option::None)
+                    // Add runtime assert that this field is None. The type state
+                    // already guarantees that the `MaybeUninit` storage is unset.
+                    if !self.base.is_uninit_member(member) {
+                        let field_ident = &member.name.orig;
+                        let message = format!(
+                            "BUG: field `{field_ident}` should be None \
+                            when converting generic parameter `{param_ident}`"
+                        );
+                        runtime_asserts.push(quote! {
+                            ::core::assert!(named.#index.is_none(), #message);
+                        });
+                    }
+
+                    // Field uses the generic parameter, so create a new unset value
+                    self.base.named_member_unset(member)
                 } else {
                     // Field doesn't use the generic parameter, so move it from the tuple
                     quote!(named.#index)
@@ -232,6 +237,7 @@ impl<'a> GenericSettersCtx<'a> {
 
         quote! {
             #(#docs)*
+            #(#attrs)*
             #[inline(always)]
             #vis fn #method_name<#new_type_param>(
                 self
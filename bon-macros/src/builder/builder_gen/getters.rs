@@ -20,6 +20,12 @@ impl<'a> GettersCtx<'a> {
         })
     }
 
+    /// The value of a required member is guaranteed to be set only if the
+    /// builder has a type state. Otherwise the getter returns an `Option`.
+    fn is_guaranteed_set(&self) -> bool {
+        self.base.typestate && self.member.is_required()
+    }
+
     pub(crate) fn getter_methods(self) -> Result<TokenStream> {
         let name = self.config.name.as_deref().cloned().unwrap_or_else(|| {
             syn::Ident::new(
@@ -36,16 +42,35 @@ impl<'a> GettersCtx<'a> {
             .clone();
 
         let docs = self.config.docs.as_deref().cloned().unwrap_or_else(|| {
-            let header = format!(
-                "_**Getter.**_ Returns `{}`, which must be set before calling this method.\n\n",
-                self.member.name.snake,
-            );
+            let header = if self.base.typestate {
+                format!(
+                    "_**Getter.**_ Returns `{}`, which must be set before calling this method.\n\n",
+                    self.member.name.snake,
+                )
+            } else {
+                format!(
+                    "_**Getter.**_ Returns `{}` or `None` if it wasn't set.\n\n",
+                    self.member.name.snake,
+                )
+            };
 
             std::iter::once(syn::parse_quote!(#[doc = #header]))
                 .chain(self.member.docs.iter().cloned())
                 .collect()
         });
 
+        let attrs = self
+            .config
+            .attrs
+            .as_deref()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        // The user may override the default `#[must_use]` via `getter(attrs(...))`
+        let must_use = (!attrs.iter().any(|attr| attr.path().is_ident("must_use"))).then(
+            || quote!(#[must_use = "this method has no side effects; it only returns a value"]),
+        );
+
         let return_ty = self.return_ty()?;
         let body = self.body();
 
@@ -60,7 +85,7 @@ impl<'a> GettersCtx<'a> {
         // be treated as totally different symbols due to the hygiene rules.
         let self_ = quote!(self);
 
-        let where_clause = self.member.is_stateful().then(|| {
+        let where_clause = (self.base.typestate && self.member.is_stateful()).then(|| {
             quote! {
                 where
                     #state_var::#member_pascal: #state_mod::IsSet,
@@ -69,13 +94,14 @@ impl<'a> GettersCtx<'a> {
 
         Ok(quote_spanned! {self.member.span=>
             #( #docs )*
+            #( #attrs )*
             #[allow(
                 // This is intentional. We want the builder syntax to compile away
                 clippy::inline_always,
                 clippy::missing_const_for_fn,
             )]
             #[inline(always)]
-            #[must_use = "this method has no side effects; it only returns a value"]
+            #must_use
             #(#fn_modifiers)* fn #name(&#self_) -> #return_ty
             #where_clause
             {
@@ -85,6 +111,10 @@ impl<'a> GettersCtx<'a> {
     }
 
     fn body(&self) -> TokenStream {
+        if self.base.is_uninit_member(self.member) {
+            return self.uninit_member_body();
+        }
+
         let index = &self.member.index;
         let member = quote! {
             self.__unsafe_private_named.#index
@@ -104,7 +134,7 @@ impl<'a> GettersCtx<'a> {
                     #bon::__::better_errors::copy_member::<#ty>(&#member)
                 };
 
-                if !self.member.is_required() {
+                if !self.is_guaranteed_set() {
                     return copy;
                 }
                 quote! {
@@ -125,7 +155,7 @@ impl<'a> GettersCtx<'a> {
                     <#ty as ::core::clone::Clone>::clone
                 };
 
-                if !self.member.is_required() {
+                if !self.is_guaranteed_set() {
                     return quote! {
                         #clone(&#member)
                     };
@@ -147,7 +177,7 @@ impl<'a> GettersCtx<'a> {
                 let span = ty.span();
                 let value = quote_spanned!(span=> value);
 
-                if !self.member.is_required() {
+                if !self.is_guaranteed_set() {
                     return quote! {
                         // Explicit match is important to trigger an implicit deref coercion
                         // that can potentially do multiple derefs to the reach the target type.
@@ -171,7 +201,7 @@ impl<'a> GettersCtx<'a> {
                 }
             }
             None => {
-                if !self.member.is_required() {
+                if !self.is_guaranteed_set() {
                     return quote! {
                         ::core::option::Option::as_ref(&#member)
                     };
@@ -190,10 +220,51 @@ impl<'a> GettersCtx<'a> {
         }
     }
 
+    /// The member stored in `MaybeUninit` doesn't need to be unwrapped, because
+    /// the method requires `S::{Member}: IsSet`, so its value is initialized.
+    fn uninit_member_body(&self) -> TokenStream {
+        let index = &self.member.index;
+        let bon = &self.base.bon;
+
+        let value = quote! {
+            #bon::__::UninitMember::get(&self.__unsafe_private_named.#index)
+        };
+
+        match self.config.kind.as_deref() {
+            Some(GetterKind::Copy) => {
+                let span = self.member.underlying_orig_ty().span();
+                let ty = quote_spanned!(span=> _);
+
+                quote! {
+                    #bon::__::better_errors::copy_ref::<#ty>(#value)
+                }
+            }
+            Some(GetterKind::Clone) => {
+                let span = self.member.underlying_orig_ty().span();
+                let ty = quote_spanned!(span=> _);
+
+                quote! {
+                    <#ty as ::core::clone::Clone>::clone(#value)
+                }
+            }
+            Some(GetterKind::Deref(ty)) => {
+                let span = ty.span();
+                let var = quote_spanned!(span=> value);
+
+                quote! {
+                    match #value {
+                        #var => #var,
+                    }
+                }
+            }
+            None => value,
+        }
+    }
+
     fn return_ty(&self) -> Result<TokenStream> {
         let underlying_return_ty = self.underlying_return_ty()?;
 
-        Ok(if self.member.is_required() {
+        Ok(if self.is_guaranteed_set() {
             quote! { #underlying_return_ty }
         } else {
             // We are not using the fully qualified path to `Option` here
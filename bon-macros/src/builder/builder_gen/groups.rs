@@ -0,0 +1,108 @@
+use super::member::NamedMember;
+use super::top_level_config::GroupConfig;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    pub(super) fn member_group(&self, member: &NamedMember) -> Option<&GroupConfig> {
+        let name = member.config.group.as_ref()?;
+        self.groups.iter().find(|group| group.name == *name)
+    }
+
+    pub(super) fn group_members<'a>(
+        &'a self,
+        group: &'a GroupConfig,
+    ) -> impl Iterator<Item = &'a NamedMember> {
+        self.named_members()
+            .filter(move |member| member.config.group.as_ref() == Some(&group.name))
+    }
+
+    /// Name of the hidden trait in the state module that is implemented for
+    /// the tuple of the members' states if at least one member of the group is set.
+    pub(super) fn group_is_set_trait(group: &GroupConfig) -> syn::Ident {
+        group.name.snake_to_pascal_case()
+    }
+
+    pub(super) fn validate_groups(&self) -> Result {
+        for member in self.named_members() {
+            let name = match &member.config.group {
+                Some(name) => name,
+                None => continue,
+            };
+
+            if self.member_group(member).is_none() {
+                let name_str = name.raw_name();
+                bail!(
+                    name,
+                    "the group `{name_str}` is not declared; declare it at the top level \
+                    via `#[builder(groups({name_str} = exactly_one))]`; the available \
+                    group kinds are `exactly_one`, `at_most_one`, and `at_least_one`",
+                );
+            }
+
+            if !member.is_stateful() || member.is_required() {
+                bail!(
+                    name,
+                    "only optional members can be part of a group; the member must \
+                    be of type `Option<T>` or have #[builder(default)], and it must \
+                    not be `overwritable`",
+                );
+            }
+
+            let option_fn = member
+                .config
+                .setters
+                .as_ref()
+                .and_then(|setters| setters.fns.option_fn.as_ref());
+
+            if let Some(option_fn) = option_fn {
+                if self
+                    .member_group(member)
+                    .map_or(false, |group| group.kind.requires_one())
+                {
+                    bail!(
+                        &option_fn.key,
+                        "the `option_fn` setter can't be configured for members of \
+                        groups that require one of their members to be set; this setter \
+                        is private in this case, because it could set the member to `None`",
+                    );
+                }
+            }
+        }
+
+        for group in &self.groups {
+            if self.group_members(group).nth(1).is_none() {
+                let name_str = group.name.raw_name();
+                bail!(
+                    &group.name,
+                    "the group `{name_str}` must have at least two members; add \
+                    #[builder(group = {name_str})] to the members of the group",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// If the member belongs to a group where only one member can be set,
+    /// then its setters require the other members of the group to be unset.
+    pub(super) fn group_setter_predicates(&self, member: &NamedMember) -> Vec<TokenStream> {
+        let group = match self.member_group(member) {
+            Some(group) if group.kind.is_exclusive() => group,
+            _ => return vec![],
+        };
+
+        let state_var = &self.state_var;
+        let state_mod = &self.state_mod.ident;
+
+        self.group_members(group)
+            .filter(|other| !other.is(member))
+            .map(|other| {
+                let other_pascal = &other.name.pascal;
+                quote! {
+                    #state_var::#other_pascal: #state_mod::IsUnset
+                }
+            })
+            .collect()
+    }
+}
@@ -36,6 +36,16 @@ pub(crate) struct ImplCtx {
     pub(crate) self_ty: Box<syn::Type>,
     pub(crate) generics: syn::Generics,
 
+    /// Path to the trait if the impl block is a trait impl. The builder is
+    /// generated as an inherent item in this case, and the finishing function
+    /// calls the trait method using the fully qualified syntax.
+    pub(crate) trait_path: Option<syn::Path>,
+
+    /// Name of the trait if the builder is generated for a provided method of a
+    /// trait definition. The `self_ty` is a synthetic type parameter that stands
+    /// for the implementor of the trait in this case.
+    pub(crate) trait_def_ident: Option<syn::Ident>,
+
     /// Lint suppressions from the original item that will be inherited by all items
     /// generated by the macro. If the original syntax used `#[expect(...)]`,
     /// then it must be represented as `#[allow(...)]` here.
@@ -83,6 +93,11 @@ impl<'a> FnInputCtx<'a> {
                         .collect()
                 }),
 
+            attrs: start_fn
+                .attrs
+                .map(SpannedKey::into_value)
+                .unwrap_or_default(),
+
             // Override on the start fn to use the generics from the
             // target function itself. We must not duplicate the generics
             // from the impl block here
@@ -106,6 +121,10 @@ impl<'a> FnInputCtx<'a> {
         };
 
         let self_ty_prefix = params.impl_ctx.as_deref().and_then(|impl_ctx| {
+            if let Some(trait_ident) = &impl_ctx.trait_def_ident {
+                return Some(trait_ident.raw_name());
+            }
+
             let prefix = impl_ctx
                 .self_ty
                 .as_path()?
@@ -162,14 +181,18 @@ impl<'a> FnInputCtx<'a> {
             );
         }
 
-        let self_ty = match self.impl_ctx.as_deref() {
-            Some(impl_ctx) => &impl_ctx.self_ty,
+        let impl_ctx = match self.impl_ctx.as_deref() {
+            Some(impl_ctx) => impl_ctx,
             None => return Ok(None),
         };
 
         let mut without_self_keyword = receiver.ty.clone();
 
-        NormalizeSelfTy { self_ty }.visit_type_mut(&mut without_self_keyword);
+        NormalizeSelfTy {
+            self_ty: &impl_ctx.self_ty,
+            trait_path: impl_ctx.trait_path.as_ref(),
+        }
+        .visit_type_mut(&mut without_self_keyword);
 
         Ok(Some(AssocMethodReceiverCtxParams {
             with_self_keyword: receiver.clone(),
@@ -200,10 +223,25 @@ impl<'a> FnInputCtx<'a> {
         Generics::new(params, where_clause)
     }
 
+    fn is_trait_impl(&self) -> bool {
+        self.impl_ctx
+            .as_deref()
+            .map(|impl_ctx| impl_ctx.trait_path.is_some())
+            .unwrap_or(false)
+    }
+
     pub(crate) fn adapted_fn(&self) -> Result<syn::ItemFn> {
         let mut orig = self.fn_item.orig.clone();
 
-        if let Some(name) = self.config.start_fn.name.as_deref() {
+        if self.is_trait_impl() {
+            // The signature of the trait method must stay intact, so it can't be
+            // renamed or hidden. The starting function lives in a separate inherent
+            // impl block, so it doesn't conflict with the trait method's name.
+            //
+            // Visibility isn't allowed on trait impl items. It's only used to
+            // configure the visibility of the generated builder items.
+            orig.vis = syn::Visibility::Inherited;
+        } else if let Some(name) = self.config.start_fn.name.as_deref() {
             if *name == orig.sig.ident {
                 bail!(
                     &name,
@@ -271,6 +309,8 @@ impl<'a> FnInputCtx<'a> {
     }
 
     pub(crate) fn into_builder_gen_ctx(self) -> Result<BuilderGenCtx> {
+        let typestate = self.config.is_typestate();
+
         let assoc_method_ctx = self.assoc_method_ctx()?;
 
         let members = self
@@ -306,7 +346,7 @@ impl<'a> FnInputCtx<'a> {
         let generics = self.generics();
         let mut adapted_fn_sig = self.adapted_fn()?.sig;
 
-        if self.config.start_fn.name.is_none() {
+        if self.config.start_fn.name.is_none() && !self.is_trait_impl() {
             crate::privatize::privatize_fn_name(&mut adapted_fn_sig);
         }
 
@@ -319,7 +359,8 @@ impl<'a> FnInputCtx<'a> {
             name: finish_fn_ident,
             vis: finish_fn_vis,
             docs: finish_fn_docs,
-        } = self.config.finish_fn;
+            attrs: finish_fn_attrs,
+        } = self.config.finish_fn.sig;
 
         let is_special_builder_method = self.impl_ctx.is_some()
             && (self.fn_item.norm.sig.ident == "new" || self.fn_item.norm.sig.ident == "builder");
@@ -341,7 +382,14 @@ impl<'a> FnInputCtx<'a> {
                 vec![syn::parse_quote! {
                     /// Finishes building and performs the requested action.
                 }]
-            });
+            })
+            .into_iter()
+            .chain(
+                finish_fn_attrs
+                    .map(SpannedKey::into_value)
+                    .unwrap_or_default(),
+            )
+            .collect();
 
         let finish_fn = FinishFnParams {
             ident: finish_fn_ident,
@@ -352,6 +400,7 @@ impl<'a> FnInputCtx<'a> {
             body: Box::new(finish_fn_body),
             output: self.fn_item.norm.sig.output,
             attrs: finish_fn_docs,
+            validate: self.config.finish_fn.validate.map(SpannedKey::into_value),
         };
 
         let fn_allows = self
@@ -398,6 +447,12 @@ impl<'a> FnInputCtx<'a> {
             ident: builder_ident(),
             derives: self.config.derive,
             docs: self.config.builder_type.docs.map(SpannedKey::into_value),
+            attrs: self
+                .config
+                .builder_type
+                .attrs
+                .map(SpannedKey::into_value)
+                .unwrap_or_default(),
             vis: self.config.builder_type.vis.map(SpannedKey::into_value),
         };
 
@@ -412,6 +467,11 @@ impl<'a> FnInputCtx<'a> {
 
             const_: self.config.const_,
             on: self.config.on,
+            groups: self.config.groups.0,
+            to_builder: false,
+            update: None,
+            typestate,
+            maybe_uninit: self.config.maybe_uninit.is_present(),
 
             assoc_method_ctx,
             generics,
@@ -449,23 +509,40 @@ impl FinishFnBody for FnCallBody {
             .filter(|arg| !matches!(arg, syn::GenericParam::Lifetime(_)))
             .map(syn::GenericParam::to_generic_argument);
 
-        let prefix = ctx
-            .assoc_method_ctx
-            .as_ref()
-            .and_then(|ctx| {
-                let ident = &ctx.receiver.as_ref()?.field_ident;
-                Some(quote!(self.#ident.))
-            })
-            .or_else(|| {
-                let self_ty = &self.impl_ctx.as_deref()?.self_ty;
-                Some(quote!(<#self_ty>::))
-            });
+        let receiver_field = ctx.assoc_method_ctx.as_ref().and_then(|ctx| {
+            let ident = &ctx.receiver.as_ref()?.field_ident;
+            Some(quote!(self.#ident))
+        });
 
         let fn_ident = &self.sig.ident;
 
         // The variables with values of members are in scope for this expression.
         let member_vars = ctx.members.iter().map(Member::orig_ident);
 
+        let impl_ctx = self.impl_ctx.as_deref();
+
+        // Trait methods are called using the fully qualified syntax, because the
+        // inherent starting function may have the same name as the trait method,
+        // and the inherent method takes precedence in the method call syntax.
+        if let Some((self_ty, trait_path)) =
+            impl_ctx.and_then(|impl_ctx| Some((&impl_ctx.self_ty, impl_ctx.trait_path.as_ref()?)))
+        {
+            let receiver_field = receiver_field.into_iter();
+
+            return quote! {
+                <#self_ty as #trait_path>::#fn_ident::<#(#generic_args,)*>(
+                    #( #receiver_field, )*
+                    #( #member_vars ),*
+                )
+                #maybe_await
+            };
+        }
+
+        let prefix = receiver_field.map(|field| quote!(#field.)).or_else(|| {
+            let self_ty = &impl_ctx?.self_ty;
+            Some(quote!(<#self_ty>::))
+        });
+
         quote! {
             #prefix #fn_ident::<#(#generic_args,)*>(
                 #( #member_vars ),*
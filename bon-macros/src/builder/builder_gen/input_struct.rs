@@ -1,4 +1,4 @@
-use super::models::FinishFnParams;
+use super::models::{FinishFnParams, Update};
 use super::top_level_config::TopLevelConfig;
 use super::{
     AssocMethodCtxParams, BuilderGenCtx, FinishFnBody, Generics, Member, MemberOrigin, RawMember,
@@ -12,9 +12,8 @@ use syn::spanned::Spanned;
 use syn::visit::Visit;
 use syn::visit_mut::VisitMut;
 
-fn parse_top_level_config(item_struct: &syn::ItemStruct) -> Result<TopLevelConfig> {
-    let configs = item_struct
-        .attrs
+pub(crate) fn parse_top_level_config(attrs: &[syn::Attribute]) -> Result<TopLevelConfig> {
+    let configs = attrs
         .iter()
         .filter(|attr| attr.path().is_ident("builder"))
         .map(|attr| {
@@ -43,11 +42,50 @@ pub(crate) struct StructInputCtx {
     struct_item: SyntaxVariant<syn::ItemStruct>,
     config: TopLevelConfig,
     struct_ty: syn::Type,
+
+    /// Present if the builder is generated for a variant of an enum. In this
+    /// case the `struct_item` is synthesized from the enum's generics and the
+    /// variant's fields, and the finishing function constructs the variant.
+    variant: Option<syn::Ident>,
 }
 
 impl StructInputCtx {
     pub(crate) fn new(orig_struct: syn::ItemStruct) -> Result<Self> {
-        let params = parse_top_level_config(&orig_struct)?;
+        Self::new_impl(orig_struct, None)
+    }
+
+    pub(crate) fn new_for_variant(
+        orig_enum: &syn::ItemEnum,
+        variant: &syn::Variant,
+    ) -> Result<Self> {
+        // The config from the enum is shared by all variants, and the config
+        // on the variant itself is appended to it.
+        let attrs = orig_enum
+            .attrs
+            .iter()
+            .chain(&variant.attrs)
+            .cloned()
+            .collect();
+
+        let orig_struct = syn::ItemStruct {
+            attrs,
+            vis: orig_enum.vis.clone(),
+            struct_token: syn::Token![struct](orig_enum.enum_token.span),
+            ident: orig_enum.ident.clone(),
+            generics: orig_enum.generics.clone(),
+            fields: variant.fields.clone(),
+            semi_token: None,
+        };
+
+        Self::new_impl(orig_struct, Some(variant.ident.clone()))
+    }
+
+    fn new_impl(orig_struct: syn::ItemStruct, variant: Option<syn::Ident>) -> Result<Self> {
+        let params = parse_top_level_config(&orig_struct.attrs)?;
+
+        if variant.is_some() {
+            params.require_struct_only_configs_absent()?;
+        }
 
         let generic_args = orig_struct
             .generics
@@ -65,6 +103,7 @@ impl StructInputCtx {
         // references and replace them with the actual struct type.
         crate::normalization::NormalizeSelfTy {
             self_ty: &struct_ty,
+            trait_path: None,
         }
         .visit_item_struct_mut(&mut norm_struct);
 
@@ -77,46 +116,69 @@ impl StructInputCtx {
             struct_item,
             config: params,
             struct_ty,
+            variant,
         })
     }
 
     pub(crate) fn into_builder_gen_ctx(self) -> Result<BuilderGenCtx> {
+        let typestate = self.config.is_typestate();
+
         let fields = self
             .struct_item
             .apply_ref(|struct_item| match &struct_item.fields {
-                syn::Fields::Named(fields) => Ok(fields),
-                _ => {
-                    bail!(&struct_item, "Only structs with named fields are supported")
+                syn::Fields::Named(fields) => Ok(&fields.named),
+                syn::Fields::Unnamed(fields) => Ok(&fields.unnamed),
+                syn::Fields::Unit => {
+                    bail!(
+                        &struct_item,
+                        "unit structs are not supported; only structs with named \
+                        or unnamed fields are supported"
+                    )
                 }
             });
 
         let norm_fields = fields.norm?;
         let orig_fields = fields.orig?;
 
+        let is_tuple = matches!(self.struct_item.norm.fields, syn::Fields::Unnamed(_));
+
         let members = norm_fields
-            .named
             .iter()
-            .zip(&orig_fields.named)
-            .map(|(norm_field, orig_field)| {
-                let ident = norm_field.ident.clone().ok_or_else(|| {
-                    err!(norm_field, "only structs with named fields are supported")
-                })?;
-
+            .zip(orig_fields)
+            .enumerate()
+            .map(|(index, (norm_field, orig_field))| {
                 let ty = SyntaxVariant {
                     norm: Box::new(norm_field.ty.clone()),
                     orig: Box::new(orig_field.ty.clone()),
                 };
 
+                // Tuple fields have no names, so we generate a placeholder
+                // name for them based on their position in the struct
+                let (ident, span) = if let Some(ident) = &orig_field.ident {
+                    (ident.clone(), ident.span())
+                } else {
+                    let span = orig_field.ty.span();
+                    (format_ident!("_{index}", span = span), span)
+                };
+
                 Ok(RawMember {
                     attrs: &norm_field.attrs,
                     ident,
                     ty,
-                    span: orig_field.ident.span(),
+                    span,
                 })
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let members = Member::from_raw(&self.config, MemberOrigin::StructField, members)?;
+        let origin = if self.variant.is_some() {
+            MemberOrigin::EnumVariantField
+        } else if is_tuple {
+            MemberOrigin::TupleStructField
+        } else {
+            MemberOrigin::StructField
+        };
+
+        let members = Member::from_raw(&self.config, origin, members)?;
 
         let generics = Generics::new(
             self.struct_item
@@ -129,25 +191,41 @@ impl StructInputCtx {
             self.struct_item.norm.generics.where_clause.clone(),
         );
 
+        let struct_ident = &self.struct_item.norm.ident;
+
         let finish_fn_body = StructLiteralBody {
-            struct_ident: self.struct_item.norm.ident.clone(),
+            path: match &self.variant {
+                Some(variant) => syn::parse_quote!(#struct_ident::#variant),
+                None => syn::parse_quote!(#struct_ident),
+            },
+            is_tuple,
         };
 
         let ItemSigConfig {
             name: start_fn_ident,
             vis: start_fn_vis,
             docs: start_fn_docs,
+            attrs: start_fn_attrs,
         } = self.config.start_fn;
 
         let start_fn_ident = start_fn_ident
             .map(SpannedKey::into_value)
-            .unwrap_or_else(|| syn::Ident::new("builder", self.struct_item.norm.ident.span()));
+            .unwrap_or_else(|| match &self.variant {
+                // Enum variants are conventionally named in `PascalCase`, so
+                // the starting function is named after the variant in `snake_case`
+                Some(variant) => {
+                    let ident = variant.pascal_to_snake_case().to_string();
+                    syn::Ident::new_maybe_raw(&ident, variant.span())
+                }
+                None => syn::Ident::new("builder", self.struct_item.norm.ident.span()),
+            });
 
         let ItemSigConfig {
             name: finish_fn_ident,
             vis: finish_fn_vis,
             docs: finish_fn_docs,
-        } = self.config.finish_fn;
+            attrs: finish_fn_attrs,
+        } = self.config.finish_fn.sig;
 
         let finish_fn_ident = finish_fn_ident
             .map(SpannedKey::into_value)
@@ -170,16 +248,26 @@ impl StructInputCtx {
                     vec![syn::parse_quote! {
                         /// Finish building and return the requested object
                     }]
-                }),
+                })
+                .into_iter()
+                .chain(
+                    finish_fn_attrs
+                        .map(SpannedKey::into_value)
+                        .unwrap_or_default(),
+                )
+                .collect(),
+            validate: self.config.finish_fn.validate.map(SpannedKey::into_value),
         };
 
         let start_fn_docs = start_fn_docs
             .map(SpannedKey::into_value)
             .unwrap_or_else(|| {
-                let docs = format!(
-                    "Create an instance of [`{}`] using the builder syntax",
-                    self.struct_item.norm.ident
-                );
+                let target = match &self.variant {
+                    Some(variant) => format!("{struct_ident}::{variant}"),
+                    None => struct_ident.to_string(),
+                };
+
+                let docs = format!("Create an instance of [`{target}`] using the builder syntax");
 
                 vec![syn::parse_quote!(#[doc = #docs])]
             });
@@ -188,6 +276,9 @@ impl StructInputCtx {
             ident: start_fn_ident,
             vis: start_fn_vis.map(SpannedKey::into_value),
             docs: start_fn_docs,
+            attrs: start_fn_attrs
+                .map(SpannedKey::into_value)
+                .unwrap_or_default(),
             generics: None,
             span: None,
         };
@@ -206,16 +297,28 @@ impl StructInputCtx {
             .collect();
 
         let builder_type = {
-            let ItemSigConfig { name, vis, docs } = self.config.builder_type;
+            let ItemSigConfig {
+                name,
+                vis,
+                docs,
+                attrs,
+            } = self.config.builder_type;
 
             let builder_ident = name.map(SpannedKey::into_value).unwrap_or_else(|| {
-                format_ident!("{}Builder", self.struct_item.norm.ident.raw_name())
+                let variant = self
+                    .variant
+                    .as_ref()
+                    .map(IdentExt::raw_name)
+                    .unwrap_or_default();
+
+                format_ident!("{}{variant}Builder", self.struct_item.norm.ident.raw_name())
             });
 
             BuilderTypeParams {
                 derives: self.config.derive,
                 ident: builder_ident,
                 docs: docs.map(SpannedKey::into_value),
+                attrs: attrs.map(SpannedKey::into_value).unwrap_or_default(),
                 vis: vis.map(SpannedKey::into_value),
             }
         };
@@ -234,6 +337,11 @@ impl StructInputCtx {
 
             const_: self.config.const_,
             on: self.config.on,
+            groups: self.config.groups.0,
+            to_builder: self.config.to_builder.is_present(),
+            update: self.config.update.is_present().then(|| Update { is_tuple }),
+            typestate,
+            maybe_uninit: self.config.maybe_uninit.is_present(),
 
             assoc_method_ctx,
             generics,
@@ -249,18 +357,34 @@ impl StructInputCtx {
 }
 
 struct StructLiteralBody {
-    struct_ident: syn::Ident,
+    /// Path to the struct or to the enum variant to construct
+    path: syn::Path,
+
+    /// Whether the fields of the struct are unnamed (tuple struct)
+    is_tuple: bool,
 }
 
 impl FinishFnBody for StructLiteralBody {
     fn generate(&self, ctx: &BuilderGenCtx) -> TokenStream {
-        let Self { struct_ident } = self;
+        let Self { path, is_tuple } = self;
 
         // The variables with values of members are in scope for this expression.
         let member_vars = ctx.members.iter().map(Member::orig_ident);
 
+        if *is_tuple {
+            // Members preserve the order of the fields, so their indices
+            // correspond to the positions of the fields in the tuple struct
+            let indices = (0..ctx.members.len()).map(syn::Index::from);
+
+            return quote! {
+                #path {
+                    #(#indices: #member_vars,)*
+                }
+            };
+        }
+
         quote! {
-            #struct_ident {
+            #path {
                 #(#member_vars,)*
             }
         }
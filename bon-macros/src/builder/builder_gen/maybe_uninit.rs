@@ -0,0 +1,90 @@
+use super::member::NamedMember;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    /// With `#[builder(maybe_uninit)]` the values of required members are
+    /// stored in `MaybeUninit`, because the type state already tracks whether
+    /// they are set. The values of optional members are stored in `Option`
+    /// anyway, and the setters of `overwritable` members don't change the type
+    /// state, so they can't rely on it.
+    pub(super) fn is_uninit_member(&self, member: &NamedMember) -> bool {
+        self.maybe_uninit && member.is_required() && member.is_stateful()
+    }
+
+    /// The type of the member's value stored in the builder
+    pub(super) fn named_member_storage_ty(&self, member: &NamedMember) -> TokenStream {
+        let ty = member.underlying_norm_ty();
+
+        if !self.is_uninit_member(member) {
+            return quote!(::core::option::Option<#ty>);
+        }
+
+        let bon = &self.bon;
+        let state_var = &self.state_var;
+        let member_pascal = &member.name.pascal;
+
+        quote!(#bon::__::UninitMember<#state_var::#member_pascal, #ty>)
+    }
+
+    /// The initial value of the member stored in the builder
+    pub(super) fn named_member_unset(&self, member: &NamedMember) -> TokenStream {
+        if !self.is_uninit_member(member) {
+            return quote!(::core::option::Option::None);
+        }
+
+        let bon = &self.bon;
+        quote!(#bon::__::UninitMember::uninit())
+    }
+
+    /// Converts the value of the member that was set into its storage. The
+    /// member's state must be set at this point.
+    pub(super) fn named_member_set(
+        &self,
+        member: &NamedMember,
+        value: &TokenStream,
+    ) -> TokenStream {
+        if !self.is_uninit_member(member) {
+            return quote!(::core::option::Option::Some(#value));
+        }
+
+        let bon = &self.bon;
+        quote!(#bon::__::UninitMember::new(#value))
+    }
+
+    /// Moves the value of the member out of the builder as an `Option<T>`
+    pub(super) fn named_member_into_option(&self, member: &NamedMember) -> TokenStream {
+        let index = &member.index;
+        let storage = quote!(self.__unsafe_private_named.#index);
+
+        if !self.is_uninit_member(member) {
+            return storage;
+        }
+
+        let bon = &self.bon;
+        quote!(#bon::__::UninitMember::into_option(#storage))
+    }
+
+    /// Borrows the value of the member in the builder as an `Option<&T>`
+    pub(super) fn named_member_as_option(&self, member: &NamedMember) -> TokenStream {
+        self.named_member_as_option_in(&quote!(self), member)
+    }
+
+    /// Same as [`Self::named_member_as_option`], but borrows the value from
+    /// the given builder expression instead of `self`
+    pub(super) fn named_member_as_option_in(
+        &self,
+        builder: &TokenStream,
+        member: &NamedMember,
+    ) -> TokenStream {
+        let index = &member.index;
+        let storage = quote!(#builder.__unsafe_private_named.#index);
+
+        if !self.is_uninit_member(member) {
+            return quote!(::core::option::Option::as_ref(&#storage));
+        }
+
+        let bon = &self.bon;
+        quote!(#bon::__::UninitMember::as_option(&#storage))
+    }
+}
@@ -1,10 +1,11 @@
-use super::MemberConfig;
+use super::{MemberConfig, SettersConfig};
 use crate::builder::builder_gen::member::MemberOrigin;
 use crate::builder::builder_gen::top_level_config::OnConfig;
 use crate::util::prelude::*;
 use std::fmt;
 
 pub(crate) enum BlanketParamName {
+    Flag,
     Into,
     Overwritable,
     SettersDocDefaultSkip,
@@ -13,6 +14,7 @@ pub(crate) enum BlanketParamName {
 impl fmt::Display for BlanketParamName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Self::Flag => fmt::Display::fmt(&super::ParamName::Flag, f),
             Self::Into => fmt::Display::fmt(&super::ParamName::Into, f),
             Self::Overwritable => fmt::Display::fmt(&super::ParamName::Overwritable, f),
             Self::SettersDocDefaultSkip => f.write_str("setters(doc(default(skip)))"),
@@ -23,24 +25,28 @@ impl fmt::Display for BlanketParamName {
 impl BlanketParamName {
     fn value_in_on_config(&self, cfg: &OnConfig) -> darling::util::Flag {
         match self {
+            Self::Flag => cfg.flag,
             Self::Into => cfg.into,
             Self::Overwritable => cfg.overwritable,
-            Self::SettersDocDefaultSkip => cfg.setters.doc.default.skip,
+            Self::SettersDocDefaultSkip => Self::setters_doc_default_skip(cfg.setters.as_ref()),
         }
     }
 
     fn value_in_member_config(&self, cfg: &MemberConfig) -> darling::util::Flag {
         match self {
+            Self::Flag => cfg.flag,
             Self::Into => cfg.into,
             Self::Overwritable => cfg.overwritable,
-            Self::SettersDocDefaultSkip => cfg
-                .setters
-                .as_ref()
-                .and_then(|setters| setters.doc.default.as_ref())
-                .map(|default| default.skip)
-                .unwrap_or_default(),
+            Self::SettersDocDefaultSkip => Self::setters_doc_default_skip(cfg.setters.as_ref()),
         }
     }
+
+    fn setters_doc_default_skip(setters: Option<&SettersConfig>) -> darling::util::Flag {
+        setters
+            .and_then(|setters| setters.doc.default.as_ref())
+            .map(|default| default.skip)
+            .unwrap_or_default()
+    }
 }
 
 pub(crate) struct EvalBlanketFlagParam<'a> {
@@ -63,7 +69,7 @@ impl EvalBlanketFlagParam<'_> {
 
         let verdict_from_on = on
             .iter()
-            .map(|params| Ok((params, scrutinee.matches(&params.type_pattern)?)))
+            .map(|params| Ok((params, params.type_pattern.matches(scrutinee)?)))
             .collect::<Result<Vec<_>>>()?
             .into_iter()
             .filter(|(_, matched)| *matched)
@@ -88,3 +94,23 @@ impl EvalBlanketFlagParam<'_> {
         Ok(flag)
     }
 }
+
+/// Returns the value of a non-flag parameter from the first `on(...)` clause
+/// that matches the `scrutinee` and specifies this parameter.
+pub(crate) fn eval_blanket_param<'on, T>(
+    on: &'on [OnConfig],
+    scrutinee: &syn::Type,
+    value_in_on_config: impl Fn(&'on OnConfig) -> Option<T>,
+) -> Result<Option<T>> {
+    for on in on {
+        if !on.type_pattern.matches(scrutinee)? {
+            continue;
+        }
+
+        if let Some(value) = value_in_on_config(on) {
+            return Ok(Some(value));
+        }
+    }
+
+    Ok(None)
+}
@@ -2,17 +2,20 @@ use crate::parsing::SpannedKey;
 use crate::util::prelude::*;
 use darling::FromMeta;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(crate) struct GetterConfig {
     pub(crate) name: Option<SpannedKey<syn::Ident>>,
     pub(crate) vis: Option<SpannedKey<syn::Visibility>>,
     pub(crate) docs: Option<SpannedKey<Vec<syn::Attribute>>>,
 
+    /// Additional attributes forwarded to the getter verbatim
+    pub(crate) attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
+
     /// Returns `&T` if [`None`]
     pub(crate) kind: Option<SpannedKey<GetterKind>>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum GetterKind {
     /// Returns `T` via [`Copy`]
     Copy,
@@ -43,6 +46,9 @@ impl FromMeta for GetterConfig {
             #[darling(rename = "doc", default, with = parse_docs, map = Some)]
             docs: Option<SpannedKey<Vec<syn::Attribute>>>,
 
+            #[darling(default, with = crate::parsing::parse_attrs, map = Some)]
+            attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
+
             copy: Option<SpannedKey<()>>,
             clone: Option<SpannedKey<()>>,
 
@@ -54,6 +60,7 @@ impl FromMeta for GetterConfig {
             name,
             vis,
             docs,
+            attrs,
             copy,
             clone,
             deref,
@@ -82,6 +89,7 @@ impl FromMeta for GetterConfig {
             name,
             vis,
             docs,
+            attrs,
             kind,
         })
     }
@@ -5,17 +5,20 @@
 mod blanket;
 mod getter;
 mod setters;
+mod validate;
 mod with;
 
 pub(crate) use blanket::*;
 pub(crate) use getter::*;
 pub(crate) use setters::*;
+pub(crate) use validate::*;
 pub(crate) use with::*;
 
 use super::MemberOrigin;
 use crate::builder::builder_gen::TopLevelConfig;
-use crate::parsing::SpannedKey;
+use crate::parsing::{ItemSigConfig, ItemSigConfigParsing, SpannedKey};
 use crate::util::prelude::*;
+use darling::FromMeta;
 use std::fmt;
 
 #[derive(Debug, darling::FromAttributes)]
@@ -25,9 +28,22 @@ pub(crate) struct MemberConfig {
     ///
     /// An optional expression can be provided to set the value for the member,
     /// otherwise its [`Default`] trait impl will be used.
-    #[darling(with = parse_optional_expr, map = Some)]
+    #[darling(with = crate::parsing::parse_optional_expr, map = Some)]
     pub(crate) default: Option<SpannedKey<Option<syn::Expr>>>,
 
+    /// Generates an additional setter that adds a single item to the collection
+    /// stored in the member. The member defaults to an empty collection, and
+    /// the item setter can be called any number of times in any state of the
+    /// builder. [`ItemSigConfig`] specifies the signature of the item setter.
+    #[darling(default, with = parse_each, map = Some)]
+    pub(crate) each: Option<SpannedKey<ItemSigConfig>>,
+
+    /// Setters of this member can't be called if any of the referenced members
+    /// were set, and setters of the referenced members can't be called if this
+    /// member was set.
+    #[darling(multiple)]
+    pub(crate) conflicts_with: Vec<syn::Ident>,
+
     /// Make the member a private field in the builder struct.
     /// This is useful when the user needs to add custom fields to the builder,
     /// that they would use in the custom methods they add to the builder.
@@ -37,7 +53,7 @@ pub(crate) struct MemberConfig {
     /// expression thus has access to all `start_fn` parameters. It must be declared
     /// strictly after `#[builder(start_fn)]` members (if any) or right at the top of
     /// the members list.
-    #[darling(with = parse_optional_expr, map = Some)]
+    #[darling(with = crate::parsing::parse_optional_expr, map = Some)]
     pub(crate) field: Option<SpannedKey<Option<syn::Expr>>>,
 
     /// Make the member gettable. [`GetterConfig`] specifies the signature for
@@ -51,12 +67,27 @@ pub(crate) struct MemberConfig {
     /// Accept the value for the member in the finishing function parameters.
     pub(crate) finish_fn: darling::util::Flag,
 
+    /// Turns a member of type `bool` into a flag. It defaults to `false`, and
+    /// its setter doesn't accept any arguments and sets the member to `true`.
+    /// There is also a companion setter that accepts a `bool` value.
+    pub(crate) flag: darling::util::Flag,
+
+    /// Adds the member to a group declared via `#[builder(groups(...))]` at
+    /// the top level. The group restricts how many of its members can be set
+    /// at compile time.
+    pub(crate) group: Option<syn::Ident>,
+
     /// Enables an `Into` conversion for the setter method.
     pub(crate) into: darling::util::Flag,
 
     /// Rename the name exposed in the builder API.
     pub(crate) name: Option<syn::Ident>,
 
+    /// The type of the member has its own builder generated by `#[derive(Builder)]`.
+    /// The setter accepts a closure that receives that builder, and the setter
+    /// finishes building the value of the member itself.
+    pub(crate) nested: darling::util::Flag,
+
     /// Allows setting the value for the member repeatedly. This reduces the
     /// number of type states and thus increases the compilation performance.
     ///
@@ -65,6 +96,11 @@ pub(crate) struct MemberConfig {
     /// this option to see if it's worth it.
     pub(crate) overwritable: darling::util::Flag,
 
+    /// Setters of this member can only be called after all of the referenced
+    /// members were set.
+    #[darling(multiple)]
+    pub(crate) requires: Vec<syn::Ident>,
+
     /// Disables the special handling for a member of type `Option<T>`. The
     /// member no longer has the default of `None`. It also becomes a required
     /// member unless a separate `#[builder(default = ...)]` attribute is
@@ -79,12 +115,22 @@ pub(crate) struct MemberConfig {
     ///
     /// An optional expression can be provided to set the value for the member,
     /// otherwise its  [`Default`] trait impl will be used.
-    #[darling(with = parse_optional_expr, map = Some)]
+    #[darling(with = crate::parsing::parse_optional_expr, map = Some)]
     pub(crate) skip: Option<SpannedKey<Option<syn::Expr>>>,
 
     /// Accept the value for the member in the starting function parameters.
     pub(crate) start_fn: darling::util::Flag,
 
+    /// The setters of the member can only be called while no members of
+    /// later stages were set.
+    pub(crate) stage: Option<SpannedKey<u32>>,
+
+    /// Validate the final value of the member in the finishing function. This
+    /// includes the values set via setters as well as the default values.
+    /// The closure is either a predicate that returns a `bool`, or it returns
+    /// a `Result<(), E>`. The finishing function becomes fallible in this case.
+    pub(crate) validate: Option<SpannedKey<ValidateClosure>>,
+
     /// Customize the setter signature and body with a custom closure or a well-known
     /// function. The closure/function must return the value of the type of the member,
     /// or optionally a `Result<_>` type where `_` is used to mark the type of
@@ -95,34 +141,50 @@ pub(crate) struct MemberConfig {
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum ParamName {
+    ConflictsWith,
     Default,
+    Each,
     Field,
     Getter,
     FinishFn,
+    Flag,
+    Group,
     Into,
     Name,
+    Nested,
     Overwritable,
     Required,
+    Requires,
     Setters,
     Skip,
+    Stage,
     StartFn,
+    Validate,
     With,
 }
 
 impl fmt::Display for ParamName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let str = match self {
+            Self::ConflictsWith => "conflicts_with",
             Self::Default => "default",
+            Self::Each => "each",
             Self::Field => "field",
             Self::Getter => "getter",
             Self::FinishFn => "finish_fn",
+            Self::Flag => "flag",
+            Self::Group => "group",
             Self::Into => "into",
             Self::Name => "name",
+            Self::Nested => "nested",
             Self::Overwritable => "overwritable",
             Self::Required => "required",
+            Self::Requires => "requires",
             Self::Setters => "setters",
             Self::Skip => "skip",
+            Self::Stage => "stage",
             Self::StartFn => "start_fn",
+            Self::Validate => "validate",
             Self::With => "with",
         };
         f.write_str(str)
@@ -177,32 +239,48 @@ impl MemberConfig {
 
     fn specified_param_names(&self) -> impl Iterator<Item = ParamName> {
         let Self {
+            conflicts_with,
             default,
+            each,
             field,
             getter,
             finish_fn,
+            flag,
+            group,
             into,
             name,
+            nested,
             overwritable,
             required,
+            requires,
             setters,
             skip,
+            stage,
             start_fn,
+            validate,
             with,
         } = self;
 
         let attrs = [
+            (!conflicts_with.is_empty(), ParamName::ConflictsWith),
             (default.is_some(), ParamName::Default),
+            (each.is_some(), ParamName::Each),
             (field.is_some(), ParamName::Field),
             (getter.is_some(), ParamName::Getter),
             (finish_fn.is_present(), ParamName::FinishFn),
+            (flag.is_present(), ParamName::Flag),
+            (group.is_some(), ParamName::Group),
             (into.is_present(), ParamName::Into),
             (name.is_some(), ParamName::Name),
+            (nested.is_present(), ParamName::Nested),
             (overwritable.is_present(), ParamName::Overwritable),
             (required.is_present(), ParamName::Required),
+            (!requires.is_empty(), ParamName::Requires),
             (setters.is_some(), ParamName::Setters),
             (skip.is_some(), ParamName::Skip),
+            (stage.is_some(), ParamName::Stage),
             (start_fn.is_present(), ParamName::StartFn),
+            (validate.is_some(), ParamName::Validate),
             (with.is_some(), ParamName::With),
         ];
 
@@ -252,6 +330,92 @@ impl MemberConfig {
             self.validate_mutually_allowed(ParamName::Field, field.key.span(), &[])?;
         }
 
+        if self.flag.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::Flag,
+                self.flag.span(),
+                &[
+                    ParamName::ConflictsWith,
+                    ParamName::Getter,
+                    ParamName::Name,
+                    ParamName::Overwritable,
+                    ParamName::Requires,
+                    ParamName::Setters,
+                    ParamName::Stage,
+                ],
+            )?;
+        }
+
+        if let Some(each) = &self.each {
+            self.validate_mutually_allowed(
+                ParamName::Each,
+                each.key.span(),
+                &[
+                    ParamName::Getter,
+                    ParamName::Into,
+                    ParamName::Name,
+                    ParamName::Overwritable,
+                    ParamName::Setters,
+                    ParamName::Validate,
+                ],
+            )?;
+        }
+
+        if self.nested.is_present() {
+            self.validate_mutually_allowed(
+                ParamName::Nested,
+                self.nested.span(),
+                &[
+                    ParamName::ConflictsWith,
+                    ParamName::Default,
+                    ParamName::Getter,
+                    ParamName::Group,
+                    ParamName::Name,
+                    ParamName::Overwritable,
+                    ParamName::Required,
+                    ParamName::Requires,
+                    ParamName::Setters,
+                    ParamName::Stage,
+                    ParamName::Validate,
+                ],
+            )?;
+        }
+
+        if let Some(group) = &self.group {
+            self.validate_mutually_exclusive(
+                ParamName::Group,
+                group.span(),
+                &[
+                    ParamName::Each,
+                    ParamName::Overwritable,
+                    ParamName::Required,
+                ],
+            )?;
+        }
+
+        let relations = [
+            (ParamName::Requires, &self.requires),
+            (ParamName::ConflictsWith, &self.conflicts_with),
+        ];
+
+        for (attr_name, others) in relations {
+            if let Some(other) = others.first() {
+                self.validate_mutually_exclusive(
+                    attr_name,
+                    other.span(),
+                    &[ParamName::Each, ParamName::Overwritable],
+                )?;
+            }
+        }
+
+        if let Some(stage) = &self.stage {
+            self.validate_mutually_exclusive(
+                ParamName::Stage,
+                stage.key.span(),
+                &[ParamName::Each, ParamName::Overwritable],
+            )?;
+        }
+
         if let Some(skip) = &self.skip {
             match origin {
                 MemberOrigin::FnArg => {
@@ -261,7 +425,9 @@ impl MemberConfig {
                         use a local variable instead.",
                     );
                 }
-                MemberOrigin::StructField => {}
+                MemberOrigin::StructField
+                | MemberOrigin::TupleStructField
+                | MemberOrigin::EnumVariantField => {}
             }
 
             if let Some(Some(_expr)) = self.default.as_deref() {
@@ -282,11 +448,12 @@ impl MemberConfig {
 
         if let Some(setters) = &self.setters {
             if let Some(default) = &setters.doc.default {
-                if self.default.is_none() {
+                if self.default.is_none() && !self.flag.is_present() && self.each.is_none() {
                     bail!(
                         &default.key,
                         "`#[builder(setters(doc(default(...)))]` may only be specified \
-                        when #[builder(default)] is also specified",
+                        when #[builder(default)], #[builder(flag)] or #[builder(each)] \
+                        is also specified",
                     );
                 }
             }
@@ -295,7 +462,43 @@ impl MemberConfig {
         Ok(())
     }
 
-    fn require_const_compat(&self) -> Result {
+    /// Tuple struct fields have no names, so unless the user assigns a name to
+    /// the field via `#[builder(name = ...)]` or uses it for a special kind of
+    /// member, the field becomes a positional member of the starting function.
+    pub(crate) fn apply_tuple_field_defaults(&mut self, field_span: Span) -> Result {
+        if self.name.is_some()
+            || self.start_fn.is_present()
+            || self.finish_fn.is_present()
+            || self.skip.is_some()
+            || self.field.is_some()
+        {
+            return Ok(());
+        }
+
+        let conflicting = self
+            .specified_param_names()
+            .filter(|name| *name != ParamName::Into)
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>();
+
+        if !conflicting.is_empty() {
+            bail!(
+                &field_span,
+                "this tuple struct field is a positional member of the starting \
+                function by default because it has no name; specify a name for it \
+                via #[builder(name = ...)] to make it a named member that can be \
+                used with {}",
+                conflicting.join(", "),
+            );
+        }
+
+        let start_fn: syn::Meta = syn::parse_quote_spanned!(field_span=> start_fn);
+        self.start_fn = darling::util::Flag::from_meta(&start_fn)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn require_const_compat(&self) -> Result {
         fn validate_default_trait_or_expr(attr: &Option<SpannedKey<Option<syn::Expr>>>) -> Result {
             let attr = match attr {
                 Some(attr) => attr,
@@ -320,6 +523,23 @@ impl MemberConfig {
         validate_default_trait_or_expr(&self.skip)?;
         validate_default_trait_or_expr(&self.field)?;
 
+        if let Some(each) = &self.each {
+            bail!(
+                &each.key,
+                "#[builder(each)] is incompatible with #[builder(const)] \
+                because Extend::extend() can not be called in const context",
+            );
+        }
+
+        if self.nested.is_present() {
+            bail!(
+                &self.nested.span(),
+                "#[builder(nested)] is incompatible with #[builder(const)] \
+                because the nested builder's methods can not be called \
+                in const context",
+            );
+        }
+
         if self.into.is_present() {
             bail!(
                 &self.into.span(),
@@ -350,6 +570,10 @@ impl MemberConfig {
             }
         }
 
+        if let Some(validate) = &self.validate {
+            crate::parsing::require_embeddable_const_expr(&validate.body)?;
+        }
+
         if let Some(with) = &self.with {
             match &with.value {
                 WithConfig::Closure(closure) => {
@@ -370,10 +594,24 @@ impl MemberConfig {
     }
 }
 
-fn parse_optional_expr(meta: &syn::Meta) -> Result<SpannedKey<Option<syn::Expr>>> {
-    match meta {
-        syn::Meta::Path(path) => SpannedKey::new(path, None),
-        syn::Meta::List(_) => Err(Error::unsupported_format("list").with_span(meta)),
-        syn::Meta::NameValue(meta) => SpannedKey::new(&meta.path, Some(meta.value.clone())),
+fn parse_each(meta: &syn::Meta) -> Result<SpannedKey<ItemSigConfig>> {
+    if let syn::Meta::Path(path) = meta {
+        bail!(
+            path,
+            "expected a name for the item setter e.g. `#[builder(each = item)]`"
+        );
     }
+
+    let config: ItemSigConfig =
+        ItemSigConfigParsing::new(meta, Some("builder struct's impl block")).parse()?;
+
+    if config.name.is_none() {
+        bail!(
+            meta,
+            "the name of the item setter must be specified explicitly \
+            e.g. `#[builder(each(name = item))]`"
+        );
+    }
+
+    SpannedKey::new(meta.path(), config)
 }
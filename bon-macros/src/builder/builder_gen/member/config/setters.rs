@@ -15,11 +15,15 @@ fn parse_docs(meta: &syn::Meta) -> Result<SpannedKey<Vec<syn::Attribute>>> {
     crate::parsing::parse_docs_without_self_mentions(DOCS_CONTEXT, meta)
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(crate) struct SettersConfig {
     pub(crate) name: Option<SpannedKey<syn::Ident>>,
     pub(crate) vis: Option<SpannedKey<syn::Visibility>>,
     pub(crate) doc: SettersDocConfig,
+
+    /// Additional attributes forwarded to all setters verbatim
+    pub(crate) attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
+
     pub(crate) fns: SettersFnsConfig,
 }
 
@@ -39,6 +43,9 @@ impl FromMeta for SettersConfig {
             name: Option<SpannedKey<syn::Ident>>,
             vis: Option<SpannedKey<syn::Visibility>>,
 
+            #[darling(default, with = crate::parsing::parse_attrs, map = Some)]
+            attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
+
             #[darling(flatten)]
             fns: SettersFnsConfig,
         }
@@ -53,13 +60,14 @@ impl FromMeta for SettersConfig {
         Ok(Self {
             name: parsed.name,
             vis: parsed.vis,
+            attrs: parsed.attrs,
             fns: parsed.fns,
             doc,
         })
     }
 }
 
-#[derive(Debug, Default, FromMeta)]
+#[derive(Debug, Clone, Default, FromMeta)]
 pub(crate) struct SettersFnsConfig {
     /// Config for the setter that accepts the value of type T for a member of
     /// type `Option<T>` or with `#[builder(default)]`.
@@ -76,7 +84,7 @@ pub(crate) struct SettersFnsConfig {
     pub(crate) option_fn: Option<SpannedKey<ItemSigConfig>>,
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub(crate) struct SettersDocConfig {
     /// Overrides the content of the doc comments.
     pub(crate) content: Option<SpannedKey<Vec<syn::Attribute>>>,
@@ -137,7 +145,7 @@ impl SettersDocConfig {
     }
 }
 
-#[derive(Debug, Default, FromMeta)]
+#[derive(Debug, Clone, Default, FromMeta)]
 pub(crate) struct SettersDocDefaultConfig {
     /// If `true`, the default value showcase in the docs header will be skipped.
     pub(crate) skip: darling::util::Flag,
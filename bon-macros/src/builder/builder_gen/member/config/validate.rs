@@ -0,0 +1,80 @@
+use crate::parsing::{ClosureResultTy, SimpleClosure};
+use crate::util::prelude::*;
+use darling::FromMeta;
+
+const INVALID_RETURN_TYPE_ERROR: &str = "\
+expected one of the following:
+
+(1) no return type annotation;
+    this means the closure is a predicate that must return a `bool`;
+    if it returns `false` then the finishing function returns an error
+    that doesn't carry any payload;
+
+(2) `-> *Result<(), ErrorType>` return type annotation;
+    this means the closure returns a `Result` with the unit type in
+    the `Ok` variant; the error is propagated as the payload of the
+    error returned by the finishing function, so the error type must
+    be specified explicitly;
+
+    the return type doesn't have to be named `Result` exactly, the only requirement is
+    that it must have the `Result` suffix; for example if you have a type alias
+    `ApiResult<(), E>`, then it'll work fine";
+
+/// Closure that validates the final value of a member. It accepts a reference
+/// to the member's underlying type.
+#[derive(Debug)]
+pub(crate) struct ValidateClosure {
+    pub(crate) pat: syn::PatIdent,
+
+    /// Explicit type annotation for the input parameter, if any.
+    pub(crate) ty: Option<Box<syn::Type>>,
+
+    pub(crate) body: Box<syn::Expr>,
+
+    /// If [`None`] then the closure is a predicate that returns a `bool`.
+    pub(crate) output: Option<ValidateClosureOutput>,
+}
+
+#[derive(Debug)]
+pub(crate) struct ValidateClosureOutput {
+    /// The full return type as it was written by the user
+    pub(crate) result_ty: Box<syn::Type>,
+    pub(crate) err_ty: syn::Type,
+}
+
+impl FromMeta for ValidateClosure {
+    fn from_meta(meta: &syn::Meta) -> Result<Self> {
+        let closure = SimpleClosure::from_meta(meta)?;
+
+        if closure.inputs.len() != 1 {
+            bail!(
+                &closure.body,
+                "expected exactly one input parameter in the validation closure \
+                that accepts a reference to the value of the member"
+            );
+        }
+
+        let input = closure
+            .inputs
+            .into_iter()
+            .next()
+            .expect("BUG: checked the length of the inputs above");
+
+        let output = match closure.output {
+            syn::ReturnType::Default => None,
+            syn::ReturnType::Type(_, result_ty) => {
+                let err_ty =
+                    ClosureResultTy::parse_unit_ok_err_ty(&result_ty, INVALID_RETURN_TYPE_ERROR)?;
+
+                Some(ValidateClosureOutput { result_ty, err_ty })
+            }
+        };
+
+        Ok(Self {
+            pat: input.pat,
+            ty: input.ty,
+            body: closure.body,
+            output,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::parsing::SimpleClosure;
+use crate::parsing::{ClosureResultOkTy, ClosureResultTy, SimpleClosure};
 use crate::util::prelude::*;
 use darling::FromMeta;
 
@@ -25,20 +25,20 @@ expected one of the following:
     if this member is of `Option<T>` type and no `#[builder(required)]` annotation
     is present";
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SetterClosure {
     pub(crate) inputs: Vec<SetterClosureInput>,
     pub(crate) body: Box<syn::Expr>,
     pub(crate) output: Option<SetterClosureOutput>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SetterClosureOutput {
     pub(crate) result_path: syn::Path,
     pub(crate) err_ty: Option<syn::Type>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) struct SetterClosureInput {
     pub(crate) pat: syn::PatIdent,
     pub(crate) ty: Box<syn::Type>,
@@ -64,49 +64,15 @@ impl FromMeta for SetterClosure {
         let return_type = match closure.output {
             syn::ReturnType::Default => None,
             syn::ReturnType::Type(_, ty) => {
-                let err = || err!(&ty, "{INVALID_RETURN_TYPE_ERROR}");
-
-                let ty = ty
-                    .as_generic_angle_bracketed_path(|last_segment| {
-                        // We allow for arbitrary `Result` type variations
-                        // including custom type aliases like `ApiResult<_>`
-                        last_segment.to_string().ends_with("Result")
-                    })
-                    .ok_or_else(err)?;
-
-                if !(1..=2).contains(&ty.args.len()) {
-                    return Err(err());
-                }
-
-                let mut args = ty.args.iter();
-                let ok_ty = args.next().ok_or_else(err)?;
-
-                if !matches!(ok_ty, syn::GenericArgument::Type(syn::Type::Infer(_))) {
-                    return Err(err());
-                }
-
-                let err_ty = args
-                    .next()
-                    .map(|arg| match arg {
-                        syn::GenericArgument::Type(ty) => Ok(ty.clone()),
-                        _ => Err(err()),
-                    })
-                    .transpose()?;
-
-                let mut result_path = ty.path.clone();
-
-                // We store the error type of the result separately.
-                // Strip the generic arguments, because we only care
-                // about the path of the `Result` in `result_path` field.
-                result_path
-                    .segments
-                    .last_mut()
-                    .expect("BUG: segments can't be empty")
-                    .arguments = syn::PathArguments::None;
+                let ty = ClosureResultTy::parse(
+                    &ty,
+                    ClosureResultOkTy::Infer,
+                    INVALID_RETURN_TYPE_ERROR,
+                )?;
 
                 Some(SetterClosureOutput {
-                    result_path,
-                    err_ty,
+                    result_path: ty.result_path,
+                    err_ty: ty.err_ty,
                 })
             }
         };
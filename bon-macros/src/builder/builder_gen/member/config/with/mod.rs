@@ -5,7 +5,7 @@ pub(crate) use closure::*;
 use crate::util::prelude::*;
 use darling::FromMeta;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub(crate) enum WithConfig {
     /// Closure syntax e.g. `#[builder(with = |param: Type| body)]`
     Closure(SetterClosure),
@@ -5,9 +5,12 @@ use crate::util::prelude::*;
 
 impl NamedMember {
     pub(super) fn merge_config_into(&mut self, on: &[OnConfig]) -> Result {
-        // `with` is mutually exclusive with `into`. So there is nothing to merge here
-        // if `with` is present.
-        if self.config.with.is_some() {
+        // `with`, `flag` and `nested` are mutually exclusive with `into`. So there
+        // is nothing to merge here if any of them is present.
+        if self.config.with.is_some()
+            || self.config.flag.is_present()
+            || self.config.nested.is_present()
+        {
             return Ok(());
         }
 
@@ -17,6 +17,8 @@ use std::fmt;
 pub(crate) enum MemberOrigin {
     FnArg,
     StructField,
+    TupleStructField,
+    EnumVariantField,
 }
 
 impl fmt::Display for MemberOrigin {
@@ -24,6 +26,8 @@ impl fmt::Display for MemberOrigin {
         match self {
             Self::FnArg => write!(f, "function argument"),
             Self::StructField => write!(f, "struct field"),
+            Self::TupleStructField => write!(f, "tuple struct field"),
+            Self::EnumVariantField => write!(f, "enum variant field"),
         }
     }
 }
@@ -32,7 +36,8 @@ impl MemberOrigin {
     fn parent_construct(self) -> &'static str {
         match self {
             Self::FnArg => "function",
-            Self::StructField => "struct",
+            Self::StructField | Self::TupleStructField => "struct",
+            Self::EnumVariantField => "enum",
         }
     }
 }
@@ -113,7 +118,7 @@ impl Member {
 
         let mut members = members
             .into_iter()
-            .map(|member| {
+            .map(|mut member| {
                 for attr in member.attrs {
                     if attr.meta.path().is_ident("builder") {
                         crate::parsing::require_non_empty_paren_meta_list_or_name_value(
@@ -122,7 +127,18 @@ impl Member {
                     }
                 }
 
-                let config = MemberConfig::from_attributes(member.attrs)?;
+                let mut config = MemberConfig::from_attributes(member.attrs)?;
+
+                if matches!(origin, MemberOrigin::TupleStructField) {
+                    config.apply_tuple_field_defaults(member.span)?;
+
+                    // Tuple fields have no names, so the name specified by the
+                    // user is the only one we can use for the member's variable
+                    if let Some(name) = &config.name {
+                        member.ident = name.clone();
+                    }
+                }
+
                 config.validate(top_config, origin)?;
                 Ok((member, config))
             })
@@ -182,13 +198,20 @@ impl Member {
                 .or_else(|| active_flag(config.finish_fn));
 
             if let Some(span) = incorrect_order {
+                let tuple_field_note = if matches!(origin, MemberOrigin::TupleStructField) {
+                    ";\nnote: tuple struct fields without #[builder(name = ...)] \
+                    are implicitly annotated with #[builder(start_fn)]"
+                } else {
+                    ""
+                };
+
                 bail!(
                     &span,
                     "incorrect members ordering; expected ordering:\n\
                     (1) members annotated with #[builder(start_fn)]\n\
                     (2) members annotated with #[builder(field)]\n\
                     (3) members annotated with #[builder(finish_fn)]\n\
-                    (4) all other members in any order",
+                    (4) all other members in any order{tuple_field_note}",
                 );
             }
 
@@ -216,6 +239,13 @@ impl Member {
             };
 
             member.merge_on_config(on)?;
+
+            // The parameters merged from `on(...)` weren't checked for the
+            // `const` compatibility in `MemberConfig::validate()`
+            if top_config.const_.is_some() {
+                member.config.require_const_compat()?;
+            }
+
             member.validate()?;
 
             output.push(Self::Named(member));
@@ -98,6 +98,17 @@ pub(crate) struct NamedMember {
 
 impl NamedMember {
     pub(super) fn validate(&self) -> Result {
+        if let Some(each) = &self.config.each {
+            if self.ty.norm.is_option() {
+                bail!(
+                    &each.key,
+                    "`#[builder(each)]` can't be applied to members of type `Option<T>`; \
+                    the member already defaults to an empty collection, so use the \
+                    collection type without the `Option` wrapper instead",
+                );
+            }
+        }
+
         if let Some(default) = &self.config.default {
             if self.is_special_option_ty() {
                 bail!(
@@ -181,6 +192,7 @@ impl NamedMember {
             Self::validate_unused_setters_cfg(setter_fns, &setters.doc.content, |config| {
                 &config.docs
             })?;
+            Self::validate_unused_setters_cfg(setter_fns, &setters.attrs, |config| &config.attrs)?;
         }
 
         Ok(())
@@ -263,16 +275,18 @@ impl NamedMember {
     }
 
     pub(crate) fn merge_on_config(&mut self, on: &[OnConfig]) -> Result {
-        // This is a temporary hack. We only allow `on(_, required)` as the
-        // first `on(...)` clause. Instead we should implement the extended design:
-        // https://github.com/elastio/bon/issues/152
-        if let Some(on) = on.first().filter(|on| on.required.is_present()) {
-            if self.is_special_option_ty() {
-                self.config.required = on.required;
-            }
-        }
-
+        // The order of merging matters. `required` changes the underlying type
+        // of the member that the rest of the parameters are matched against.
+        // `each` and `flag` imply a default value, so they go before `default`.
+        // `with` goes before `into` to take precedence over it.
+        self.merge_config_required(on)?;
+        self.merge_config_each();
+        self.merge_config_flag(on)?;
+        self.merge_config_default(on)?;
+        self.merge_config_with(on)?;
         self.merge_config_into(on)?;
+        self.merge_config_getter(on)?;
+        self.merge_config_setters(on)?;
         self.merge_setters_doc_skip(on)?;
 
         // FIXME: refactor this to make it more consistent with `into`
@@ -290,6 +304,221 @@ impl NamedMember {
         Ok(())
     }
 
+    fn merge_config_required(&mut self, on: &[OnConfig]) -> Result {
+        // `required` only disables the special handling of `Option<_>`, so it's
+        // silently ignored for members of other types. The `Option<_>` wrapper
+        // is part of the scrutinee to allow for `on(Option<_>, required)`.
+        if !self.is_special_option_ty() {
+            return Ok(());
+        }
+
+        let required = config::eval_blanket_param(on, &self.ty.norm, |on| {
+            Some(on.required).filter(darling::util::Flag::is_present)
+        })?;
+
+        if let Some(required) = required {
+            self.config.required = required;
+        }
+
+        Ok(())
+    }
+
+    fn merge_config_default(&mut self, on: &[OnConfig]) -> Result {
+        // Members of `Option<_>` type already default to `None`
+        if self.config.default.is_some() || self.is_special_option_ty() {
+            return Ok(());
+        }
+
+        self.config.default =
+            config::eval_blanket_param(on, self.underlying_norm_ty(), |on| on.default.clone())?;
+
+        Ok(())
+    }
+
+    fn merge_config_with(&mut self, on: &[OnConfig]) -> Result {
+        // The member's own `into`, `flag`, `nested` and `each` are mutually
+        // exclusive with `with`, so they take precedence over `on(..., with)`.
+        let config = &self.config;
+        if config.with.is_some()
+            || config.into.is_present()
+            || config.flag.is_present()
+            || config.nested.is_present()
+            || config.each.is_some()
+        {
+            return Ok(());
+        }
+
+        self.config.with =
+            config::eval_blanket_param(on, self.underlying_norm_ty(), |on| on.with.clone())?;
+
+        Ok(())
+    }
+
+    fn merge_config_getter(&mut self, on: &[OnConfig]) -> Result {
+        if self.config.getter.is_some() {
+            return Ok(());
+        }
+
+        self.config.getter =
+            config::eval_blanket_param(on, self.underlying_norm_ty(), |on| on.getter.clone())?;
+
+        Ok(())
+    }
+
+    /// The `setters(...)` config from `on(...)` is merged field by field. The
+    /// fields specified on the member itself take precedence. The fields that
+    /// would be overridden by the member's own config are not merged at all
+    /// to avoid reporting them as unused in [`Self::validate_setters_config`].
+    fn merge_config_setters(&mut self, on: &[OnConfig]) -> Result {
+        let scrutinee = self.underlying_norm_ty();
+
+        let vis = config::eval_blanket_param(on, scrutinee, |on| on.setters.as_ref()?.vis.clone())?;
+        let content = config::eval_blanket_param(on, scrutinee, |on| {
+            on.setters.as_ref()?.doc.content.clone()
+        })?;
+        let attrs =
+            config::eval_blanket_param(on, scrutinee, |on| on.setters.as_ref()?.attrs.clone())?;
+
+        // `some_fn` and `option_fn` are only allowed for optional members
+        let (some_fn, option_fn) = if self.is_required() {
+            (None, None)
+        } else {
+            (
+                config::eval_blanket_param(on, scrutinee, |on| {
+                    on.setters.as_ref()?.fns.some_fn.clone()
+                })?,
+                config::eval_blanket_param(on, scrutinee, |on| {
+                    on.setters.as_ref()?.fns.option_fn.clone()
+                })?,
+            )
+        };
+
+        if vis.is_none()
+            && content.is_none()
+            && attrs.is_none()
+            && some_fn.is_none()
+            && option_fn.is_none()
+        {
+            return Ok(());
+        }
+
+        let setters = self.config.setters.get_or_insert_with(Default::default);
+
+        for (member_fn, on_fn) in [
+            (&mut setters.fns.some_fn, some_fn),
+            (&mut setters.fns.option_fn, option_fn),
+        ] {
+            let on_fn = match on_fn {
+                Some(on_fn) => on_fn,
+                None => continue,
+            };
+
+            let member_fn = member_fn.get_or_insert_with(|| SpannedKey {
+                key: on_fn.key.clone(),
+                value: ItemSigConfig::default(),
+            });
+
+            if member_fn.vis.is_none() && setters.vis.is_none() {
+                member_fn.vis = on_fn.value.vis;
+            }
+
+            if member_fn.docs.is_none() && setters.doc.content.is_none() {
+                member_fn.docs = on_fn.value.docs;
+            }
+
+            if member_fn.attrs.is_none() && setters.attrs.is_none() {
+                member_fn.attrs = on_fn.value.attrs;
+            }
+        }
+
+        let SettersFnsConfig { some_fn, option_fn } = &setters.fns;
+        let fns = [some_fn, option_fn];
+        let overridden_by_all_fns = |get: fn(&ItemSigConfig) -> bool| {
+            fns.iter()
+                .all(|setter_fn| setter_fn.as_ref().map_or(false, |setter_fn| get(setter_fn)))
+        };
+
+        if setters.vis.is_none() && !overridden_by_all_fns(|setter_fn| setter_fn.vis.is_some()) {
+            setters.vis = vis;
+        }
+
+        if setters.doc.content.is_none()
+            && !overridden_by_all_fns(|setter_fn| setter_fn.docs.is_some())
+        {
+            setters.doc.content = content;
+        }
+
+        if setters.attrs.is_none() && !overridden_by_all_fns(|setter_fn| setter_fn.attrs.is_some())
+        {
+            setters.attrs = attrs;
+        }
+
+        Ok(())
+    }
+
+    fn merge_config_each(&mut self) {
+        let each = match &self.config.each {
+            Some(each) => each,
+            None => return,
+        };
+
+        // Collections are empty by default, which is their `Default` value
+        self.config.default = Some(SpannedKey {
+            key: each.key.clone(),
+            value: None,
+        });
+    }
+
+    fn merge_config_flag(&mut self, on: &[OnConfig]) -> Result {
+        // Members that have their own value customizations aren't affected by
+        // `on(..., flag)`. If `flag` is specified on such a member explicitly,
+        // then the conflict is already reported by the member config validation.
+        let config = &self.config;
+        if config.default.is_some()
+            || config.with.is_some()
+            || config.into.is_present()
+            || config.required.is_present()
+        {
+            return Ok(());
+        }
+
+        self.config.flag = config::EvalBlanketFlagParam {
+            on,
+            param_name: config::BlanketParamName::Flag,
+            member_config: &self.config,
+            // Flags aren't optional members, so the type isn't unwrapped from `Option`
+            scrutinee: &self.ty.norm,
+            origin: self.origin,
+        }
+        .eval()?;
+
+        if !self.config.flag.is_present() {
+            return Ok(());
+        }
+
+        let is_bool = self
+            .ty
+            .norm
+            .as_path_no_qself()
+            .map(|path| path.is_ident("bool"))
+            .unwrap_or(false);
+
+        if !is_bool {
+            bail!(
+                &self.config.flag.span(),
+                "`#[builder(flag)]` can only be applied to members of type `bool`",
+            );
+        }
+
+        // Flags are `false` by default, which is the `Default` value of `bool`
+        self.config.default = Some(SpannedKey {
+            key: syn::Ident::new("flag", self.config.flag.span()),
+            value: None,
+        });
+
+        Ok(())
+    }
+
     fn merge_setters_doc_skip(&mut self, on: &[OnConfig]) -> Result {
         let skip = config::EvalBlanketFlagParam {
             on,
@@ -0,0 +1,106 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    /// The generic parameter for the builder's type state. It's absent with
+    /// `#[builder(typestate = false)]`.
+    pub(super) fn state_param(&self) -> Option<TokenStream> {
+        let state_var = &self.state_var;
+        let state_mod = &self.state_mod.ident;
+
+        self.typestate
+            .then(|| quote!(#state_var: #state_mod::State))
+    }
+
+    /// The generic argument for the builder's type state. It's absent with
+    /// `#[builder(typestate = false)]`.
+    pub(super) fn state_arg(&self) -> Option<&syn::Ident> {
+        self.typestate.then(|| &self.state_var)
+    }
+
+    /// Rejects the member configs that are enforced by the builder's type state
+    pub(super) fn validate_typestate_members(&self) -> Result {
+        if self.typestate {
+            return Ok(());
+        }
+
+        for member in self.named_members() {
+            let config = &member.config;
+
+            let mut spans = std::iter::empty()
+                .chain(config.group.iter().map(|group| ("group", group.span())))
+                .chain(
+                    config
+                        .requires
+                        .iter()
+                        .map(|other| ("requires", other.span())),
+                )
+                .chain(
+                    config
+                        .conflicts_with
+                        .iter()
+                        .map(|other| ("conflicts_with", other.span())),
+                )
+                .chain(config.stage.iter().map(|stage| ("stage", stage.key.span())));
+
+            if let Some((attr, span)) = spans.next() {
+                bail!(
+                    &span,
+                    "`{attr}` can't be used with `typestate = false`, because it's \
+                    enforced by the builder's type state",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Generates the code that returns an error from the finishing function
+    /// if any of the required members weren't set. This is only needed with
+    /// `#[builder(typestate = false)]`, otherwise the type state guarantees
+    /// that all required members are set.
+    pub(super) fn missing_members_check(&self) -> Option<TokenStream> {
+        if self.typestate {
+            return None;
+        }
+
+        let required_members = self
+            .named_members()
+            .filter(|member| member.is_required())
+            .map(|member| {
+                let index = &member.index;
+                let name = &member.name.snake_raw_str;
+                quote! {
+                    (
+                        #name,
+                        ::core::option::Option::is_some(&self.__unsafe_private_named.#index),
+                    )
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if required_members.is_empty() {
+            return None;
+        }
+
+        let bon = &self.bon;
+
+        // The error is converted into the error type of the finishing function
+        // the same way as the errors of members' validations.
+        let err = if self.finish_fn.validate.is_some() {
+            quote!(::core::convert::From::from(err))
+        } else if let Some(error_ident) = self.validation_error_ident() {
+            quote!(#error_ident::MissingMembers(err))
+        } else {
+            quote!(err)
+        };
+
+        Some(quote! {
+            if let ::core::result::Result::Err(err) = #bon::__::check_missing_members(&[
+                #( #required_members, )*
+            ]) {
+                return ::core::result::Result::Err(#err);
+            }
+        })
+    }
+}
@@ -3,15 +3,25 @@ mod builder_derives;
 mod finish_fn;
 mod generic_setters;
 mod getters;
+mod groups;
+mod maybe_uninit;
 mod member;
+mod missing_members;
 mod models;
+mod nested;
+mod relations;
 mod setters;
 mod start_fn;
 mod state_mod;
+mod to_builder;
 mod top_level_config;
+mod update;
+mod validate;
 
 pub(crate) mod input_fn;
 pub(crate) mod input_struct;
+#[cfg(feature = "manifest-config")]
+pub(crate) use top_level_config::manifests_tracking;
 pub(crate) use top_level_config::TopLevelConfig;
 
 use crate::util::prelude::*;
@@ -19,7 +29,7 @@ use generic_setters::GenericSettersCtx;
 use getters::GettersCtx;
 use member::{CustomField, Member, MemberOrigin, NamedMember, PosFnMember, RawMember};
 use models::{AssocMethodCtxParams, AssocMethodReceiverCtx, BuilderGenCtx, FinishFnBody, Generics};
-use setters::SettersCtx;
+use setters::{SettersCtx, SettersTarget};
 
 pub(crate) struct MacroOutput {
     pub(crate) start_fn: syn::ItemFn,
@@ -53,10 +63,14 @@ impl BuilderGenCtx {
 
     pub(crate) fn output(self) -> Result<MacroOutput> {
         let mut start_fn = self.start_fn();
-        let state_mod = state_mod::StateModGenCtx::new(&self).state_mod();
+        let state_mod = self
+            .typestate
+            .then(|| state_mod::StateModGenCtx::new(&self).state_mod());
         let builder_decl = self.builder_decl();
         let builder_impl = self.builder_impl()?;
         let builder_derives = self.builder_derives()?;
+        let validation_error_decl = self.validation_error_decl();
+        let update_items = self.update_items()?;
 
         let default_allows = syn::parse_quote!(#[allow(
             // We have a `deprecated` lint on all `bon::__` items which we
@@ -72,6 +86,8 @@ impl BuilderGenCtx {
             #builder_decl
             #builder_impl
             #builder_derives
+            #validation_error_decl
+            #update_items
             #state_mod
         };
 
@@ -112,7 +128,8 @@ impl BuilderGenCtx {
         let accessor_methods = self
             .named_members()
             .map(|member| {
-                let setters = SettersCtx::new(self, member).setter_methods()?;
+                let setters =
+                    SettersCtx::new(self, member, SettersTarget::Builder).setter_methods()?;
                 let getters = GettersCtx::new(self, member)
                     .map(GettersCtx::getter_methods)
                     .transpose()?
@@ -139,8 +156,8 @@ impl BuilderGenCtx {
         let generic_args = &self.generics.args;
         let where_clause = &self.generics.where_clause;
         let builder_ident = &self.builder_type.ident;
-        let state_mod = &self.state_mod.ident;
-        let state_var = &self.state_var;
+        let state_param = self.state_param();
+        let state_arg = self.state_arg();
 
         let allows = allow_warnings_on_member_types();
 
@@ -152,9 +169,9 @@ impl BuilderGenCtx {
             #[automatically_derived]
             impl<
                 #(#generics_decl,)*
-                #state_var: #state_mod::State
+                #state_param
             >
-            #builder_ident<#(#generic_args,)* #state_var>
+            #builder_ident<#(#generic_args,)* #state_arg>
             #where_clause
             {
                 #finish_fn
@@ -171,7 +188,7 @@ impl BuilderGenCtx {
         let type_patterns = self
             .on
             .iter()
-            .map(|params| &params.type_pattern)
+            .flat_map(|params| params.type_pattern.types())
             .collect::<Vec<_>>();
 
         if type_patterns.is_empty() {
@@ -243,7 +260,9 @@ impl BuilderGenCtx {
             _ => None,
         });
 
-        let state_var = &self.state_var;
+        let state_var = self
+            .state_arg()
+            .map(|state_var| quote!(fn() -> #state_var,));
 
         quote! {
             ::core::marker::PhantomData<(
@@ -254,7 +273,7 @@ impl BuilderGenCtx {
                 // that the builder doesn't "own" an instance of the given type.
                 // This removes unnecessary requirements when evaluating the
                 // applicability of the auto traits.
-                fn() -> #state_var,
+                #state_var
 
                 // Even though lifetimes will most likely be used somewhere in
                 // member types, it is not guaranteed in case of functions/methods,
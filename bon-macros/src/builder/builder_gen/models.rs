@@ -1,5 +1,7 @@
 use super::member::Member;
-use super::top_level_config::{DerivesConfig, GenericsConfig, OnConfig};
+use super::top_level_config::{
+    DerivesConfig, FinishFnValidateConfig, GenericsConfig, GroupConfig, OnConfig,
+};
 use crate::normalization::GenericsNamespace;
 use crate::parsing::{BonCratePath, ItemSigConfig, SpannedKey};
 use crate::util::prelude::*;
@@ -45,6 +47,11 @@ pub(super) struct AssocMethodCtxParams {
     pub(super) receiver: Option<AssocMethodReceiverCtxParams>,
 }
 
+pub(super) struct Update {
+    /// The fields of a tuple struct are accessed by their positions
+    pub(super) is_tuple: bool,
+}
+
 pub(super) struct FinishFn {
     pub(super) ident: syn::Ident,
 
@@ -61,6 +68,9 @@ pub(super) struct FinishFn {
     pub(super) special_attrs: Vec<syn::Attribute>,
     pub(super) body: Box<dyn FinishFnBody>,
     pub(super) output: syn::ReturnType,
+
+    /// Cross-member validation that runs before the body of the function
+    pub(super) validate: Option<FinishFnValidateConfig>,
 }
 
 pub(super) struct FinishFnParams {
@@ -75,6 +85,7 @@ pub(super) struct FinishFnParams {
     pub(super) special_attrs: Vec<syn::Attribute>,
     pub(super) body: Box<dyn FinishFnBody>,
     pub(super) output: syn::ReturnType,
+    pub(super) validate: Option<FinishFnValidateConfig>,
 }
 
 pub(super) struct StartFn {
@@ -83,6 +94,9 @@ pub(super) struct StartFn {
 
     pub(super) docs: Vec<syn::Attribute>,
 
+    /// Additional attributes specified by the user via `start_fn(attrs(...))`
+    pub(super) attrs: Vec<syn::Attribute>,
+
     /// Overrides the default generics
     pub(super) generics: Option<Generics>,
 
@@ -97,6 +111,7 @@ pub(super) struct StartFnParams {
     pub(super) vis: Option<syn::Visibility>,
 
     pub(super) docs: Vec<syn::Attribute>,
+    pub(super) attrs: Vec<syn::Attribute>,
 
     /// Overrides the default generics
     pub(super) generics: Option<Generics>,
@@ -113,6 +128,9 @@ pub(super) struct BuilderType {
 
     pub(super) derives: DerivesConfig,
     pub(super) docs: Vec<syn::Attribute>,
+
+    /// Additional attributes specified by the user via `builder_type(attrs(...))`
+    pub(super) attrs: Vec<syn::Attribute>,
 }
 
 pub(super) struct BuilderTypeParams {
@@ -120,6 +138,7 @@ pub(super) struct BuilderTypeParams {
     pub(super) vis: Option<syn::Visibility>,
     pub(super) derives: DerivesConfig,
     pub(super) docs: Option<Vec<syn::Attribute>>,
+    pub(super) attrs: Vec<syn::Attribute>,
 }
 
 pub(super) struct StateMod {
@@ -137,6 +156,9 @@ pub(super) struct StateMod {
     pub(super) vis_child_child: syn::Visibility,
 
     pub(super) docs: Vec<syn::Attribute>,
+
+    /// Additional attributes specified by the user via `state_mod(attrs(...))`
+    pub(super) attrs: Vec<syn::Attribute>,
 }
 
 pub(super) struct Generics {
@@ -156,6 +178,8 @@ pub(super) struct Generics {
     pub(super) args: Vec<syn::GenericArgument>,
 }
 
+// The flags are independent switches from the top-level config
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct BuilderGenCtx {
     pub(super) bon: BonCratePath,
 
@@ -173,6 +197,20 @@ pub(crate) struct BuilderGenCtx {
     pub(super) allow_attrs: Vec<syn::Attribute>,
     pub(super) const_: Option<syn::Token![const]>,
     pub(super) on: Vec<OnConfig>,
+    pub(super) groups: Vec<GroupConfig>,
+
+    /// Generate methods that convert the struct back into a builder
+    pub(super) to_builder: bool,
+
+    /// Generate the `update()` method and the type for in-place updates
+    pub(super) update: Option<Update>,
+
+    /// If `false`, then the builder has no type state generic parameter and
+    /// the required members are checked at runtime by the finishing function
+    pub(super) typestate: bool,
+
+    /// Store the values of required members in `MaybeUninit` instead of `Option`
+    pub(super) maybe_uninit: bool,
 
     pub(super) generics: Generics,
     pub(super) generics_config: Option<GenericsConfig>,
@@ -185,6 +223,7 @@ pub(crate) struct BuilderGenCtx {
     pub(super) finish_fn: FinishFn,
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub(super) struct BuilderGenCtxParams<'a> {
     pub(crate) bon: BonCratePath,
     pub(super) namespace: Cow<'a, GenericsNamespace>,
@@ -193,6 +232,11 @@ pub(super) struct BuilderGenCtxParams<'a> {
     pub(super) allow_attrs: Vec<syn::Attribute>,
     pub(super) const_: Option<syn::Token![const]>,
     pub(super) on: Vec<OnConfig>,
+    pub(super) groups: Vec<GroupConfig>,
+    pub(super) to_builder: bool,
+    pub(super) update: Option<Update>,
+    pub(super) typestate: bool,
+    pub(super) maybe_uninit: bool,
 
     /// This is the visibility of the original item that the builder is generated for.
     /// For example, the `struct` or `fn` item visibility that the `#[builder]` or
@@ -223,6 +267,11 @@ impl BuilderGenCtx {
             allow_attrs,
             const_,
             on,
+            groups,
+            to_builder,
+            update,
+            typestate,
+            maybe_uninit,
             generics,
             generics_config,
             orig_item_vis,
@@ -237,6 +286,7 @@ impl BuilderGenCtx {
             ident: builder_type.ident,
             vis: builder_type.vis.unwrap_or(orig_item_vis),
             derives: builder_type.derives,
+            attrs: builder_type.attrs,
             docs: builder_type.docs.unwrap_or_else(|| {
                 let doc = format!(
                     "Use builder syntax to set the inputs and finish with [`{0}()`](Self::{0}()).",
@@ -313,6 +363,11 @@ impl BuilderGenCtx {
 
                         vec![syn::parse_quote!(#[doc = #docs])]
                     }),
+
+                attrs: state_mod
+                    .attrs
+                    .map(SpannedKey::into_value)
+                    .unwrap_or_default(),
             }
         };
 
@@ -320,6 +375,7 @@ impl BuilderGenCtx {
             ident: start_fn.ident,
             vis: start_fn.vis.unwrap_or_else(|| builder_type.vis.clone()),
             docs: start_fn.docs,
+            attrs: start_fn.attrs,
             generics: start_fn.generics,
             span: start_fn.span.unwrap_or_else(Span::call_site),
         };
@@ -333,6 +389,7 @@ impl BuilderGenCtx {
             special_attrs: finish_fn.special_attrs,
             body: finish_fn.body,
             output: finish_fn.output,
+            validate: finish_fn.validate,
         };
 
         let state_var = {
@@ -370,7 +427,7 @@ impl BuilderGenCtx {
             }
         });
 
-        Ok(Self {
+        let mut ctx = Self {
             bon,
             state_var,
             namespace: namespace.into_owned(),
@@ -378,6 +435,11 @@ impl BuilderGenCtx {
             allow_attrs,
             const_,
             on,
+            groups,
+            to_builder,
+            update,
+            typestate,
+            maybe_uninit,
             generics,
             generics_config,
             assoc_method_ctx,
@@ -385,7 +447,19 @@ impl BuilderGenCtx {
             state_mod,
             start_fn,
             finish_fn,
-        })
+        };
+
+        ctx.validate_finish_fn_validate_config()?;
+        ctx.validate_groups()?;
+        ctx.validate_typestate_members()?;
+        ctx.relations()?;
+        ctx.validate_update()?;
+
+        if let Some(output) = ctx.validated_finish_fn_output() {
+            ctx.finish_fn.output = output;
+        }
+
+        Ok(ctx)
     }
 }
 
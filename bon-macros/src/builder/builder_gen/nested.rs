@@ -0,0 +1,82 @@
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    /// Generates the impls of the traits that make the builder usable in the
+    /// setters of `#[builder(nested)]` members of other builders.
+    ///
+    /// Returns [`None`] if the builder can't be started or finished without
+    /// additional inputs, or if the finishing function is fallible. The
+    /// nested setters have nowhere to get these inputs from or to return
+    /// the errors to. The same applies if the visibility of the builder API
+    /// was customized.
+    pub(crate) fn nested_builder_impls(&self, struct_vis: &syn::Visibility) -> Option<TokenStream> {
+        let is_supported = self.start_fn_args().next().is_none()
+            && self.finish_fn_args().next().is_none()
+            && self.finish_fn_error_ty().is_none()
+            && self.finish_fn.asyncness.is_none()
+            && self.finish_fn.unsafety.is_none()
+            && self.const_.is_none();
+
+        if !is_supported {
+            return None;
+        }
+
+        // Don't let the traits expose the builder, its starting or finishing
+        // functions if their visibility is lower than the visibility of the struct
+        let is_default_vis = [
+            &self.builder_type.vis,
+            &self.start_fn.vis,
+            &self.finish_fn.vis,
+        ]
+        .into_iter()
+        .all(|vis| vis == struct_vis);
+
+        if !is_default_vis {
+            return None;
+        }
+
+        let self_ty = &self.assoc_method_ctx.as_ref()?.self_ty;
+
+        let bon = &self.bon;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let builder_ident = &self.builder_type.ident;
+        let state_mod = &self.state_mod.ident;
+        let state_var = &self.state_var;
+        let start_fn = &self.start_fn.ident;
+        let finish_fn = &self.finish_fn.ident;
+
+        Some(quote! {
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<#(#generics_decl,)*> #bon::__::NestedBuilder for #self_ty
+                #where_clause
+            {
+                type Builder = #builder_ident<#(#generic_args,)* #state_mod::Empty>;
+
+                #[inline(always)]
+                fn nested_builder() -> Self::Builder {
+                    Self::#start_fn()
+                }
+            }
+
+            #[allow(deprecated)]
+            #[automatically_derived]
+            impl<
+                #(#generics_decl,)*
+                #state_var: #state_mod::IsComplete
+            >
+            #bon::__::FinishNested<#self_ty>
+            for #builder_ident<#(#generic_args,)* #state_var>
+                #where_clause
+            {
+                #[inline(always)]
+                fn finish_nested(self) -> #self_ty {
+                    self.#finish_fn()
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,251 @@
+use super::member::NamedMember;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+/// A restriction on the setters of the `member` that depends on the state
+/// of the `other` member. It comes from `#[builder(requires = other)]`,
+/// `#[builder(conflicts_with = other)]` or `#[builder(stage = N)]`.
+pub(super) struct Relation<'a> {
+    pub(super) member: &'a NamedMember,
+    pub(super) other: &'a NamedMember,
+    pub(super) kind: RelationKind,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(super) enum RelationKind {
+    /// The `other` member must be set before the `member`
+    Requires,
+
+    /// The `other` member must not be set together with the `member`
+    ConflictsWith,
+
+    /// The `other` member belongs to a later stage, so it must not be set
+    /// before the `member`
+    Stage,
+}
+
+impl Relation<'_> {
+    /// Name of the hidden trait in the state module that is implemented only
+    /// for the allowed state of the `other` member.
+    pub(super) fn trait_ident(&self) -> syn::Ident {
+        let kind = match self.kind {
+            RelationKind::Requires => "Requires",
+            RelationKind::ConflictsWith => "ConflictsWith",
+            RelationKind::Stage => "StageBefore",
+        };
+
+        format_ident!(
+            "{}{kind}{}",
+            self.member.name.pascal_str,
+            self.other.name.pascal_str
+        )
+    }
+
+    pub(super) fn diagnostic_label(&self) -> String {
+        let other = &self.other.name.snake_raw_str;
+
+        match self.kind {
+            RelationKind::Requires => format!("the member `{other}` must be set first"),
+            RelationKind::ConflictsWith | RelationKind::Stage => {
+                format!("the member `{other}` was already set")
+            }
+        }
+    }
+
+    pub(super) fn diagnostic_message(&self) -> String {
+        let member = &self.member.name.snake_raw_str;
+        let other = &self.other.name.snake_raw_str;
+
+        match self.kind {
+            RelationKind::Requires => format!(
+                "the member `{member}` requires the member `{other}` to be set; \
+                call the setter for `{other}` before the setter for `{member}`"
+            ),
+            RelationKind::ConflictsWith => format!(
+                "the member `{member}` conflicts with the member `{other}`; \
+                they can't be set together"
+            ),
+            RelationKind::Stage => {
+                let stage = |member: &NamedMember| {
+                    member
+                        .config
+                        .stage
+                        .as_ref()
+                        .map(|stage| stage.value)
+                        .unwrap_or_default()
+                };
+
+                format!(
+                    "the member `{member}` of stage {} can't be set after \
+                    the member `{other}` of a later stage {} was set",
+                    stage(self.member),
+                    stage(self.other),
+                )
+            }
+        }
+    }
+}
+
+impl BuilderGenCtx {
+    fn find_named_member(&self, name: &syn::Ident) -> Result<&NamedMember> {
+        self.named_members()
+            .find(|member| member.name.snake == *name)
+            .ok_or_else(|| {
+                let available = self
+                    .stateful_members()
+                    .map(|member| format!("`{}`", member.name.snake_raw_str))
+                    .join(", ");
+
+                err!(
+                    name,
+                    "there is no member named `{}` in the builder; \
+                    the available members are: {available}",
+                    name.raw_name()
+                )
+            })
+    }
+
+    /// All relations between members. The `conflicts_with` relations are
+    /// symmetric, so they are mirrored for the other member.
+    pub(super) fn relations(&self) -> Result<Vec<Relation<'_>>> {
+        fn push<'a>(relations: &mut Vec<Relation<'a>>, relation: Relation<'a>) {
+            let is_duplicate = relations.iter().any(|existing| {
+                existing.kind == relation.kind
+                    && existing.member.is(relation.member)
+                    && existing.other.is(relation.other)
+            });
+
+            if !is_duplicate {
+                relations.push(relation);
+            }
+        }
+
+        let mut relations = vec![];
+
+        for member in self.named_members() {
+            let config = &member.config;
+
+            let others = std::iter::empty()
+                .chain(
+                    config
+                        .requires
+                        .iter()
+                        .map(|name| (name, RelationKind::Requires)),
+                )
+                .chain(
+                    config
+                        .conflicts_with
+                        .iter()
+                        .map(|name| (name, RelationKind::ConflictsWith)),
+                );
+
+            for (name, kind) in others {
+                let other = self.find_named_member(name)?;
+
+                if other.is(member) {
+                    bail!(name, "the member can't reference itself");
+                }
+
+                if !other.is_stateful() || other.config.each.is_some() {
+                    bail!(
+                        name,
+                        "the referenced member must not be `overwritable` and must \
+                        not use `each`, because its setters don't change the \
+                        builder's type state",
+                    );
+                }
+
+                if kind == RelationKind::ConflictsWith {
+                    if !member.is_stateful() {
+                        bail!(
+                            name,
+                            "`conflicts_with` can't be used on an `overwritable` member, \
+                            because its setters don't change the builder's type state",
+                        );
+                    }
+
+                    if let Some(required) = [member, other]
+                        .into_iter()
+                        .find(|required| required.is_required())
+                    {
+                        bail!(
+                            name,
+                            "`conflicts_with` can only be used between optional members; \
+                            the member `{}` is required, so the other member could \
+                            never be set",
+                            required.name.snake_raw_str,
+                        );
+                    }
+
+                    push(
+                        &mut relations,
+                        Relation {
+                            member: other,
+                            other: member,
+                            kind,
+                        },
+                    );
+                }
+
+                push(
+                    &mut relations,
+                    Relation {
+                        member,
+                        other,
+                        kind,
+                    },
+                );
+            }
+        }
+
+        let staged_members = self
+            .named_members()
+            .filter_map(|member| Some((member, member.config.stage.as_ref()?)))
+            .collect::<Vec<_>>();
+
+        for &(member, stage) in &staged_members {
+            if !member.is_stateful() {
+                bail!(
+                    &stage.key,
+                    "`stage` can't be used on an `overwritable` member, \
+                    because its setters don't change the builder's type state",
+                );
+            }
+
+            let later_members = staged_members
+                .iter()
+                .filter(|(_, other_stage)| other_stage.value > stage.value);
+
+            for &(other, _) in later_members {
+                relations.push(Relation {
+                    member,
+                    other,
+                    kind: RelationKind::Stage,
+                });
+            }
+        }
+
+        Ok(relations)
+    }
+
+    /// Predicates on the state of other members that the setters of the
+    /// given member must satisfy.
+    pub(super) fn relation_setter_predicates(&self, member: &NamedMember) -> Vec<TokenStream> {
+        let state_var = &self.state_var;
+        let state_mod = &self.state_mod.ident;
+
+        // The relations are validated when the context is created
+        self.relations()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|relation| relation.member.is(member))
+            .map(|relation| {
+                let other_pascal = &relation.other.name.pascal;
+                let trait_ident = relation.trait_ident();
+                quote! {
+                    #state_var::#other_pascal: #state_mod::#trait_ident
+                }
+            })
+            .collect()
+    }
+}
@@ -4,30 +4,161 @@ use crate::parsing::ItemSigConfig;
 use crate::util::prelude::*;
 use std::iter;
 
+/// The type that the setters are generated for
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SettersTarget {
+    /// The builder, where the setters transition its type state
+    Builder,
+
+    /// The type returned by the `update()` method generated with
+    /// `#[builder(update)]`. Its setters don't have any type state, so
+    /// all members can be set in any order and none of them are required.
+    Update,
+}
+
 pub(crate) struct SettersCtx<'a> {
     base: &'a BuilderGenCtx,
     member: &'a NamedMember,
+    target: SettersTarget,
 }
 
 impl<'a> SettersCtx<'a> {
-    pub(crate) fn new(base: &'a BuilderGenCtx, member: &'a NamedMember) -> Self {
-        Self { base, member }
+    pub(crate) fn new(
+        base: &'a BuilderGenCtx,
+        member: &'a NamedMember,
+        target: SettersTarget,
+    ) -> Self {
+        Self {
+            base,
+            member,
+            target,
+        }
     }
 
     pub(crate) fn setter_methods(&self) -> Result<TokenStream> {
-        match SettersItems::new(self) {
-            SettersItems::Required(item) => self.setter_for_required_member(item),
-            SettersItems::Optional(setters) => self.setters_for_optional_member(setters),
-        }
+        let setters = match SettersItems::new(self) {
+            SettersItems::Required(item) => self.setter_for_required_member(item)?,
+            SettersItems::Optional(setters) if self.member.config.flag.is_present() => {
+                self.setters_for_flag_member(setters)
+            }
+            SettersItems::Optional(setters) => self.setters_for_optional_member(setters)?,
+        };
+
+        let each_setter = self.each_setter()?;
+
+        Ok(quote! {
+            #setters
+            #each_setter
+        })
+    }
+
+    /// The setter generated by `#[builder(each = name)]` adds a single item to
+    /// the collection. It doesn't transition the builder state, so it can be
+    /// called any number of times regardless of the other setters.
+    fn each_setter(&self) -> Result<Option<TokenStream>> {
+        let each = match &self.member.config.each {
+            Some(each) => each,
+            None => return Ok(None),
+        };
+
+        let collection_ty = self.member.underlying_norm_ty();
+
+        let item_ty = collection_item_ty(collection_ty)
+            .ok_or_else(|| unknown_collection_err(collection_ty, "each"))?;
+
+        let is_into = self.member.config.into.is_present();
+
+        let input = |name: &'static str, ty: &syn::GenericArgument| -> (syn::PatIdent, syn::Type) {
+            let ty = if is_into {
+                syn::parse_quote!(impl Into<#ty>)
+            } else {
+                syn::parse_quote!(#ty)
+            };
+            (pat_ident(name), ty)
+        };
+
+        let conversion = |ident: &syn::Ident| {
+            if is_into {
+                quote!(Into::into(#ident))
+            } else {
+                quote!(#ident)
+            }
+        };
+
+        let (inputs, item_expr) = match item_ty {
+            CollectionItemTy::Single(item_ty) => {
+                let (pat, ty) = input("item", item_ty);
+                let item_expr = conversion(&pat.ident);
+                (vec![(pat, ty)], item_expr)
+            }
+            CollectionItemTy::Pair(key_ty, value_ty) => {
+                let key = input("key", key_ty);
+                let value = input("value", value_ty);
+                let item_key = conversion(&key.0.ident);
+                let item_value = conversion(&value.0.ident);
+                (vec![key, value], quote!((#item_key, #item_value)))
+            }
+        };
+
+        let item = SettersItems::each_item(self, each)?;
+
+        Ok(Some(self.setter_method(Setter {
+            item,
+            imp: SetterImpl {
+                generics: None,
+                inputs,
+                body: SetterBody::ExtendMember { item: item_expr },
+            },
+        })))
+    }
+
+    /// Flags reuse the pair of setters of optional members, but the `some_fn`
+    /// setter accepts no arguments and sets the flag to `true`, and the `option_fn`
+    /// setter accepts a `bool` value.
+    fn setters_for_flag_member(&self, items: OptionalSettersItems) -> TokenStream {
+        let some_fn = Setter {
+            item: items.some_fn,
+            imp: SetterImpl {
+                generics: None,
+                inputs: vec![],
+                body: SetterBody::Forward {
+                    body: {
+                        let option_fn_name = &items.option_fn.name;
+                        quote! {
+                            self.#option_fn_name(true)
+                        }
+                    },
+                },
+            },
+        };
+
+        let option_fn = Setter {
+            item: items.option_fn,
+            imp: SetterImpl {
+                generics: None,
+                inputs: vec![(pat_ident("value"), syn::parse_quote!(bool))],
+                body: SetterBody::SetMember {
+                    expr: quote!(::core::option::Option::Some(value)),
+                },
+            },
+        };
+
+        [self.setter_method(some_fn), self.setter_method(option_fn)].concat()
     }
 
     fn setter_for_required_member(&self, item: SetterItem) -> Result<TokenStream> {
+        let mut generics = None;
         let inputs;
         let expr;
 
         let member_type = self.member.ty.norm.as_ref();
 
-        if let Some(with) = &self.member.config.with {
+        if self.member.config.nested.is_present() {
+            let nested = self.nested_setter_impl();
+            generics = Some(nested.generics);
+            inputs = nested.inputs;
+            expr = nested.expr;
+        } else if let Some(with) = &self.member.config.with {
             inputs = self.underlying_inputs_from_with(with)?;
             expr = self.member_expr_from_with(with);
         } else if self.member.config.into.is_present() {
@@ -41,21 +172,69 @@ impl<'a> SettersCtx<'a> {
             expr = quote!(value);
         }
 
-        let body = SetterBody::SetMember {
-            expr: quote!(::core::option::Option::Some(#expr)),
+        let expr = match self.target {
+            SettersTarget::Builder => self.base.named_member_set(self.member, &expr),
+            SettersTarget::Update => quote!(::core::option::Option::Some(#expr)),
         };
 
+        let body = SetterBody::SetMember { expr };
+
         Ok(self.setter_method(Setter {
             item,
-            imp: SetterImpl { inputs, body },
+            imp: SetterImpl {
+                generics,
+                inputs,
+                body,
+            },
         }))
     }
 
+    /// The setter for a `#[builder(nested)]` member accepts a closure that
+    /// receives the builder of the member's type. The closure must return
+    /// that builder in a state where all of its required members are set.
+    fn nested_setter_impl(&self) -> NestedSetterImpl {
+        let bon = &self.base.bon;
+        let ty = self.member.underlying_norm_ty();
+
+        let build_fn_ty = self.base.namespace.unique_ident("F".to_owned());
+        let builder_ty = self.base.namespace.unique_ident("B".to_owned());
+
+        let generics = SetterGenerics {
+            params: vec![build_fn_ty.clone(), builder_ty.clone()],
+            predicates: vec![
+                syn::parse_quote! {
+                    #build_fn_ty: ::core::ops::FnOnce(
+                        <#ty as #bon::__::NestedBuilder>::Builder
+                    ) -> #builder_ty
+                },
+                syn::parse_quote! {
+                    #builder_ty: #bon::__::FinishNested<#ty>
+                },
+            ],
+        };
+
+        let expr = quote! {
+            #bon::__::FinishNested::finish_nested(
+                build(<#ty as #bon::__::NestedBuilder>::nested_builder())
+            )
+        };
+
+        NestedSetterImpl {
+            generics,
+            inputs: vec![(pat_ident("build"), syn::parse_quote!(#build_fn_ty))],
+            expr,
+        }
+    }
+
     fn setters_for_optional_member(&self, items: OptionalSettersItems) -> Result<TokenStream> {
         if let Some(with) = &self.member.config.with {
             return self.setters_for_optional_member_having_with(with, items);
         }
 
+        if self.member.config.nested.is_present() {
+            return Ok(self.setters_for_optional_nested_member(items));
+        }
+
         let underlying_ty = self.member.underlying_norm_ty();
         let underlying_ty: syn::Type = if self.member.config.into.is_present() {
             syn::parse_quote!(impl Into<#underlying_ty>)
@@ -66,6 +245,7 @@ impl<'a> SettersCtx<'a> {
         let some_fn = Setter {
             item: items.some_fn,
             imp: SetterImpl {
+                generics: None,
                 inputs: vec![(pat_ident("value"), underlying_ty.clone())],
                 body: SetterBody::Forward {
                     body: {
@@ -81,23 +261,100 @@ impl<'a> SettersCtx<'a> {
         let option_fn = Setter {
             item: items.option_fn,
             imp: SetterImpl {
+                generics: None,
                 inputs: vec![(
                     pat_ident("value"),
                     syn::parse_quote!(Option<#underlying_ty>),
                 )],
                 body: SetterBody::SetMember {
-                    expr: if self.member.config.into.is_present() {
+                    expr: {
+                        let value = if self.member.config.into.is_present() {
+                            quote! {
+                                Option::map(value, Into::into)
+                            }
+                        } else {
+                            quote!(value)
+                        };
+
+                        self.extend_each_items(value)
+                    },
+                },
+            },
+        };
+
+        Ok([self.setter_method(some_fn), self.setter_method(option_fn)].concat())
+    }
+
+    /// The `some_fn` setter of an optional nested member accepts a closure, while
+    /// the `option_fn` setter accepts an already built value of the member.
+    fn setters_for_optional_nested_member(&self, items: OptionalSettersItems) -> TokenStream {
+        let NestedSetterImpl {
+            generics,
+            inputs,
+            expr,
+        } = self.nested_setter_impl();
+
+        let some_fn = Setter {
+            item: items.some_fn,
+            imp: SetterImpl {
+                generics: Some(generics),
+                inputs,
+                body: SetterBody::Forward {
+                    body: {
+                        let option_fn_name = &items.option_fn.name;
                         quote! {
-                            Option::map(value, Into::into)
+                            self.#option_fn_name(Some(#expr))
                         }
-                    } else {
-                        quote!(value)
                     },
                 },
             },
         };
 
-        Ok([self.setter_method(some_fn), self.setter_method(option_fn)].concat())
+        let underlying_ty = self.member.underlying_norm_ty();
+
+        let option_fn = Setter {
+            item: items.option_fn,
+            imp: SetterImpl {
+                generics: None,
+                inputs: vec![(
+                    pat_ident("value"),
+                    syn::parse_quote!(Option<#underlying_ty>),
+                )],
+                body: SetterBody::SetMember {
+                    expr: quote!(value),
+                },
+            },
+        };
+
+        [self.setter_method(some_fn), self.setter_method(option_fn)].concat()
+    }
+
+    /// If the member has an item setter, then the items added via that setter
+    /// must not be lost when the whole collection is set. The new collection
+    /// is appended to the existing items in this case.
+    fn extend_each_items(&self, value: TokenStream) -> TokenStream {
+        if self.member.config.each.is_none() {
+            return value;
+        }
+
+        let index = &self.member.index;
+
+        quote! {
+            match (
+                ::core::option::Option::take(&mut self.__unsafe_private_named.#index),
+                #value,
+            ) {
+                (
+                    ::core::option::Option::Some(mut items),
+                    ::core::option::Option::Some(value),
+                ) => {
+                    ::core::iter::Extend::extend(&mut items, value);
+                    ::core::option::Option::Some(items)
+                }
+                (items, ::core::option::Option::None) => items,
+                (::core::option::Option::None, value) => value,
+            }
+        }
     }
 
     fn setters_for_optional_member_having_with(
@@ -124,6 +381,7 @@ impl<'a> SettersCtx<'a> {
         let some_fn = Setter {
             item: items.some_fn,
             imp: SetterImpl {
+                generics: None,
                 inputs: inputs.clone(),
                 body: SetterBody::Forward {
                     body: {
@@ -137,6 +395,7 @@ impl<'a> SettersCtx<'a> {
         };
 
         let option_fn_impl = SetterImpl {
+            generics: None,
             inputs: {
                 let input_types = inputs.iter().map(|(_, ty)| ty);
                 let input_types = tuple_if_many(quote!(#( #input_types, )*));
@@ -214,64 +473,16 @@ impl<'a> SettersCtx<'a> {
             WithConfig::FromIter(from_iter) => {
                 let collection_ty = self.member.underlying_norm_ty();
 
-                let well_known_single_arg_suffixes = ["Vec", "Set", "Deque", "Heap", "List"];
-
-                let err = || {
+                let item_ty = collection_item_ty(collection_ty).ok_or_else(|| {
                     let mut from_iter_path = quote!(#from_iter).to_string();
                     from_iter_path.retain(|c| !c.is_whitespace());
 
-                    err!(
-                        collection_ty,
-                        "the underlying type of this member is not a known collection type; \
-                        only a collection type that matches the following patterns will be \
-                        accepted by `#[builder(with = {from_iter_path})], where * at \
-                        the beginning means the collection type may start with any prefix:\n\
-                        - *Map<K, V>\n\
-                        {}",
-                        well_known_single_arg_suffixes
-                            .iter()
-                            .map(|suffix| { format!("- *{suffix}<T>") })
-                            .join("\n")
-                    )
-                };
-
-                let path = collection_ty.as_path_no_qself().ok_or_else(err)?;
-
-                let last_segment = path.segments.last().ok_or_else(err)?;
-                let args = match &last_segment.arguments {
-                    syn::PathArguments::AngleBracketed(args) => &args.args,
-                    _ => return Err(err()),
-                };
-
-                let last_segment_ident_str = last_segment.ident.to_string();
+                    unknown_collection_err(collection_ty, &format!("with = {from_iter_path}"))
+                })?;
 
-                let item_ty = if well_known_single_arg_suffixes
-                    .iter()
-                    .any(|suffix| last_segment_ident_str.ends_with(suffix))
-                {
-                    // We don't compare for `len == 1` because there may be an optional last
-                    // type argument for the allocator
-                    if args.is_empty() {
-                        return Err(err());
-                    }
-
-                    let arg = args.first().ok_or_else(err)?;
-
-                    quote!(#arg)
-                } else if last_segment_ident_str.ends_with("Map") {
-                    // We don't compare for `len == 2` because there may be an optional last
-                    // type argument for the allocator
-                    if args.len() < 2 {
-                        return Err(err());
-                    }
-
-                    let mut args = args.iter();
-                    let key = args.next().ok_or_else(err)?;
-                    let value = args.next().ok_or_else(err)?;
-
-                    quote!((#key, #value))
-                } else {
-                    return Err(err());
+                let item_ty = match item_ty {
+                    CollectionItemTy::Single(item) => quote!(#item),
+                    CollectionItemTy::Pair(key, value) => quote!((#key, #value)),
                 };
 
                 vec![(
@@ -362,15 +573,34 @@ impl<'a> SettersCtx<'a> {
     fn setter_method(&self, setter: Setter) -> TokenStream {
         let Setter { item, imp } = setter;
 
+        // The `MaybeUninit` storage of the member changes its type when the
+        // member is set, so the value is moved into the new builder instead
+        // of assigning it to the field of the old one.
+        let is_uninit =
+            self.target == SettersTarget::Builder && self.base.is_uninit_member(self.member);
+
         let maybe_mut = match imp.body {
             SetterBody::Forward { .. } => None,
-            SetterBody::SetMember { .. } => Some(syn::Token![mut](Span::call_site())),
+            SetterBody::SetMember { .. } if is_uninit => None,
+            SetterBody::SetMember { .. } | SetterBody::ExtendMember { .. } => {
+                Some(syn::Token![mut](Span::call_site()))
+            }
+        };
+
+        // Setters that extend the collection don't change the type state
+        let is_state_transition = match imp.body {
+            SetterBody::Forward { .. } | SetterBody::SetMember { .. } => {
+                self.target == SettersTarget::Builder
+                    && self.base.typestate
+                    && self.member.is_stateful()
+            }
+            SetterBody::ExtendMember { .. } => false,
         };
 
         let body = match imp.body {
             SetterBody::Forward { body } => body,
             SetterBody::SetMember { expr } => {
-                let mut output = if !self.member.is_stateful() {
+                let mut output = if !is_state_transition {
                     quote! {
                         self
                     }
@@ -387,13 +617,28 @@ impl<'a> SettersCtx<'a> {
 
                     let custom_fields_idents = self.base.custom_fields().map(|field| &field.ident);
 
+                    let named = if is_uninit {
+                        let members = self.base.named_members().map(|member| {
+                            let index = &member.index;
+                            if member.is(self.member) {
+                                expr.clone()
+                            } else {
+                                quote!(self.__unsafe_private_named.#index)
+                            }
+                        });
+
+                        quote!(( #( #members, )* ))
+                    } else {
+                        quote!(self.__unsafe_private_named)
+                    };
+
                     quote! {
                         #builder_ident {
                             __unsafe_private_phantom: ::core::marker::PhantomData,
                             #( #custom_fields_idents: self.#custom_fields_idents, )*
                             #maybe_receiver_field
                             #( #start_fn_args_fields_idents: self.#start_fn_args_fields_idents, )*
-                            __unsafe_private_named: self.__unsafe_private_named,
+                            __unsafe_private_named: #named,
                         }
                     }
                 };
@@ -410,17 +655,44 @@ impl<'a> SettersCtx<'a> {
                     output = quote!(#result_path::Ok(#output));
                 }
 
+                // The update stores the value of an `Option<T>` member as
+                // `Option<Option<T>>`, where the outer `None` means the member
+                // wasn't set, so that `maybe_{member}(None)` clears the member.
+                let expr =
+                    if self.target == SettersTarget::Update && self.member.is_special_option_ty() {
+                        quote!(::core::option::Option::Some(#expr))
+                    } else {
+                        expr
+                    };
+
+                if is_uninit {
+                    output
+                } else {
+                    let index = &self.member.index;
+                    quote! {
+                        self.__unsafe_private_named.#index = #expr;
+                        #output
+                    }
+                }
+            }
+            SetterBody::ExtendMember { item } => {
                 let index = &self.member.index;
                 quote! {
-                    self.__unsafe_private_named.#index = #expr;
-                    #output
+                    ::core::iter::Extend::extend(
+                        ::core::option::Option::get_or_insert_with(
+                            &mut self.__unsafe_private_named.#index,
+                            ::core::default::Default::default,
+                        ),
+                        ::core::iter::once(#item),
+                    );
+                    self
                 }
             }
         };
 
         let state_mod = &self.base.state_mod.ident;
 
-        let mut return_type = if !self.member.is_stateful() {
+        let mut return_type = if !is_state_transition {
             quote! { Self }
         } else {
             let state_transition = format_ident!("Set{}", self.member.name.pascal_str);
@@ -437,18 +709,58 @@ impl<'a> SettersCtx<'a> {
             return_type = Self::maybe_wrap_in_result(with, return_type);
         }
 
-        let where_clause = (!self.member.config.overwritable.is_present()).then(|| {
-            let state_var = &self.base.state_var;
-            let member_pascal = &self.member.name.pascal;
+        let state_predicate =
+            (is_state_transition && !self.member.config.overwritable.is_present()).then(|| {
+                let state_var = &self.base.state_var;
+                let member_pascal = &self.member.name.pascal;
+                quote! {
+                    #state_var::#member_pascal: #state_mod::IsUnset
+                }
+            });
+
+        let (generic_params, generic_predicates) = match &imp.generics {
+            Some(generics) => (generics.params.as_slice(), generics.predicates.as_slice()),
+            None => (&[][..], &[][..]),
+        };
+
+        let generics_decl = (!generic_params.is_empty()).then(|| quote!(<#(#generic_params),*>));
+
+        let group_predicates = if is_state_transition {
+            self.base.group_setter_predicates(self.member)
+        } else {
+            vec![]
+        };
+
+        let relation_predicates = match self.target {
+            SettersTarget::Builder => self.base.relation_setter_predicates(self.member),
+            SettersTarget::Update => vec![],
+        };
+
+        let predicates = state_predicate
+            .into_iter()
+            .chain(group_predicates)
+            .chain(relation_predicates)
+            .chain(generic_predicates.iter().map(ToTokens::to_token_stream))
+            .collect::<Vec<_>>();
+
+        let where_clause = (!predicates.is_empty()).then(|| {
             quote! {
-                where #state_var::#member_pascal: #state_mod::IsUnset,
+                where #( #predicates, )*
             }
         });
 
-        let SetterItem { name, vis, docs } = item;
+        let SetterItem {
+            name,
+            vis,
+            docs,
+            attrs,
+        } = item;
         let pats = imp.inputs.iter().map(|(pat, _)| pat);
         let types = imp.inputs.iter().map(|(_, ty)| ty);
-        let const_ = &self.base.const_;
+        let const_ = match self.target {
+            SettersTarget::Builder => self.base.const_.as_ref(),
+            SettersTarget::Update => None,
+        };
         let fn_modifiers = self.member.respan(quote!(#vis #const_));
 
         // It's important to keep the span of `self` the same across all
@@ -458,6 +770,7 @@ impl<'a> SettersCtx<'a> {
 
         quote_spanned! {self.member.span=>
             #( #docs )*
+            #( #attrs )*
             #[allow(
                 // This is intentional. We want the builder syntax to compile away
                 clippy::inline_always,
@@ -473,7 +786,7 @@ impl<'a> SettersCtx<'a> {
                 clippy::wrong_self_convention,
             )]
             #[inline(always)]
-            #(#fn_modifiers)* fn #name(#maybe_mut #self_, #( #pats: #types ),*) -> #return_type
+            #(#fn_modifiers)* fn #name #generics_decl (#maybe_mut #self_, #( #pats: #types ),*) -> #return_type
             #where_clause
             {
                 #body
@@ -488,16 +801,33 @@ struct Setter {
 }
 
 struct SetterImpl {
+    generics: Option<SetterGenerics>,
     inputs: Vec<(syn::PatIdent, syn::Type)>,
     body: SetterBody,
 }
 
+struct NestedSetterImpl {
+    generics: SetterGenerics,
+    inputs: Vec<(syn::PatIdent, syn::Type)>,
+    expr: TokenStream,
+}
+
+/// Additional generic parameters of the setter method itself
+struct SetterGenerics {
+    params: Vec<syn::Ident>,
+    predicates: Vec<syn::WherePredicate>,
+}
+
 enum SetterBody {
     /// The setter forwards the call to another method.
     Forward { body: TokenStream },
 
     /// The setter sets the member as usual and transitions the builder state.
     SetMember { expr: TokenStream },
+
+    /// The setter adds an item to the collection stored in the member
+    /// without transitioning the builder state.
+    ExtendMember { item: TokenStream },
 }
 
 enum SettersItems {
@@ -514,11 +844,16 @@ struct SetterItem {
     name: syn::Ident,
     vis: syn::Visibility,
     docs: Vec<syn::Attribute>,
+    attrs: Vec<syn::Attribute>,
 }
 
 impl SettersItems {
     fn new(ctx: &SettersCtx<'_>) -> Self {
-        let SettersCtx { member, base } = ctx;
+        let SettersCtx {
+            member,
+            base,
+            target,
+        } = ctx;
         let builder_type = &base.builder_type;
 
         let config = member.config.setters.as_ref();
@@ -527,10 +862,16 @@ impl SettersItems {
         let common_vis = config.and_then(|config| config.vis.as_deref());
         let common_docs =
             config.and_then(|config| config.doc.content.as_deref().map(Vec::as_slice));
+        let common_attrs = config
+            .and_then(|config| config.attrs.as_deref())
+            .map(Vec::as_slice)
+            .unwrap_or_default();
 
         let doc = |docs: &str| iter::once(syn::parse_quote!(#[doc = #docs]));
 
-        if member.is_required() {
+        // None of the members are required when updating an existing value,
+        // so all of them get the pair of setters for optional members
+        if member.is_required() && *target == SettersTarget::Builder {
             let docs = common_docs.unwrap_or(&member.docs);
 
             let header = "_**Required.**_\n\n";
@@ -541,9 +882,12 @@ impl SettersItems {
                 name: common_name.unwrap_or(&member.name.snake).clone(),
                 vis: common_vis.unwrap_or(&builder_type.vis).clone(),
                 docs,
+                attrs: common_attrs.to_vec(),
             });
         }
 
+        let is_flag = member.config.flag.is_present();
+
         let some_fn = config.and_then(|config| config.fns.some_fn.as_deref());
         let some_fn_name = some_fn
             .and_then(ItemSigConfig::name)
@@ -557,11 +901,15 @@ impl SettersItems {
             .cloned()
             .unwrap_or_else(|| {
                 let base_name = common_name.unwrap_or(&member.name.snake);
+                let prefix = if is_flag { "with" } else { "maybe" };
                 // It's important to preserve the original identifier span
                 // to make IDE's "go to definition" work correctly. It's so
                 // important that this doesn't use `format_ident!`, but rather
                 // `syn::Ident::new` to set the span of the `Ident` explicitly.
-                syn::Ident::new(&format!("maybe_{}", base_name.raw_name()), base_name.span())
+                syn::Ident::new(
+                    &format!("{prefix}_{}", base_name.raw_name()),
+                    base_name.span(),
+                )
             });
 
         let default = member.config.default.as_deref().and_then(|default| {
@@ -600,16 +948,44 @@ impl SettersItems {
             .or(common_docs)
             .unwrap_or(&member.docs);
 
-        let some_fn_docs =
-            optional_setter_docs(default, &some_fn_name, &option_fn_name, some_fn_docs);
+        // The `option_fn` setter of a member of a group that requires one of its
+        // members to be set is private, because it could mark the member as set
+        // with a `None` value.
+        let required_group = base
+            .member_group(member)
+            .filter(|group| *target == SettersTarget::Builder && group.kind.requires_one());
+
+        let header = if let Some(group) = required_group {
+            let members = base
+                .group_members(group)
+                .map(|member| format!("`{}`", member.name.snake_raw_str))
+                .join(", ");
+
+            format!(
+                "_**Group `{}`** ({} of: {members})._",
+                group.name.raw_name(),
+                group.kind.as_str().replace('_', " "),
+            )
+        } else if is_flag {
+            format!(
+                "_**Flag** ([true](Self::{some_fn_name}()) / \
+                [bool](Self::{option_fn_name}()) setters)._"
+            )
+        } else {
+            format!(
+                "_**Optional** ([Some](Self::{some_fn_name}()) / \
+                [Option](Self::{option_fn_name}()) setters)._"
+            )
+        };
+
+        let some_fn_docs = optional_setter_docs(&header, default, some_fn_docs);
 
         let option_fn_docs = option_fn
             .and_then(ItemSigConfig::docs)
             .or(common_docs)
             .unwrap_or(&member.docs);
 
-        let option_fn_docs =
-            optional_setter_docs(default, &some_fn_name, &option_fn_name, option_fn_docs);
+        let option_fn_docs = optional_setter_docs(&header, default, option_fn_docs);
 
         let some_fn = SetterItem {
             name: some_fn_name,
@@ -620,35 +996,106 @@ impl SettersItems {
                 .clone(),
 
             docs: some_fn_docs,
+
+            attrs: some_fn
+                .and_then(ItemSigConfig::attrs)
+                .unwrap_or(common_attrs)
+                .to_vec(),
         };
 
         let option_fn = config.and_then(|config| config.fns.option_fn.as_deref());
-        let option_fn = SetterItem {
-            name: option_fn_name,
+        let option_fn = if required_group.is_some() {
+            SetterItem {
+                name: option_fn_name,
+                vis: syn::Visibility::Inherited,
+                docs: vec![syn::parse_quote!(#[doc(hidden)])],
+                attrs: vec![],
+            }
+        } else {
+            SetterItem {
+                name: option_fn_name,
 
-            vis: option_fn
-                .and_then(ItemSigConfig::vis)
-                .or(common_vis)
-                .unwrap_or(&builder_type.vis)
-                .clone(),
+                vis: option_fn
+                    .and_then(ItemSigConfig::vis)
+                    .or(common_vis)
+                    .unwrap_or(&builder_type.vis)
+                    .clone(),
+
+                docs: option_fn_docs,
 
-            docs: option_fn_docs,
+                attrs: option_fn
+                    .and_then(ItemSigConfig::attrs)
+                    .unwrap_or(common_attrs)
+                    .to_vec(),
+            }
         };
 
         Self::Optional(OptionalSettersItems { some_fn, option_fn })
     }
 }
 
+impl SettersItems {
+    fn each_item(ctx: &SettersCtx<'_>, each: &ItemSigConfig) -> Result<SetterItem> {
+        let SettersCtx { member, base, .. } = ctx;
+
+        let name = each
+            .name()
+            .expect("BUG: the name of the item setter is validated during parsing")
+            .clone();
+
+        let config = member.config.setters.as_ref();
+        let common_name = config
+            .and_then(|config| config.name.as_deref())
+            .unwrap_or(&member.name.snake);
+
+        if name == *common_name {
+            bail!(
+                &name,
+                "the name of the item setter must be different from the name \
+                of the setter for the whole collection"
+            );
+        }
+
+        let header = format!(
+            "_**Collection item** setter; adds a single item to the \
+            [`{common_name}`](Self::{common_name}()) collection._\n\n"
+        );
+
+        let docs = each
+            .docs()
+            .or_else(|| config.and_then(|config| config.doc.content.as_deref().map(Vec::as_slice)))
+            .unwrap_or(&member.docs);
+
+        let docs = iter::once(syn::parse_quote!(#[doc = #header]))
+            .chain(docs.iter().cloned())
+            .collect();
+
+        let vis = each
+            .vis()
+            .or_else(|| config.and_then(|config| config.vis.as_deref()))
+            .unwrap_or(&base.builder_type.vis)
+            .clone();
+
+        let attrs = each
+            .attrs()
+            .or_else(|| config.and_then(|config| config.attrs.as_deref().map(Vec::as_slice)))
+            .unwrap_or_default()
+            .to_vec();
+
+        Ok(SetterItem {
+            name,
+            vis,
+            docs,
+            attrs,
+        })
+    }
+}
+
 fn optional_setter_docs(
+    header: &str,
     default: Option<&str>,
-    some_fn: &syn::Ident,
-    option_fn: &syn::Ident,
     doc_comments: &[syn::Attribute],
 ) -> Vec<syn::Attribute> {
-    let header = format!(
-        "_**Optional** ([Some](Self::{some_fn}()) / [Option](Self::{option_fn}()) setters)._"
-    );
-
     let mut attrs = vec![syn::parse_quote!(#[doc = #header])];
 
     if let Some(default) = default {
@@ -699,6 +1146,64 @@ fn well_known_default(ty: &syn::Type) -> Option<syn::Expr> {
     Some(value)
 }
 
+enum CollectionItemTy<'a> {
+    /// The collection stores single values e.g. `Vec<T>`, `HashSet<T>`
+    Single(&'a syn::GenericArgument),
+
+    /// The collection stores key-value pairs e.g. `HashMap<K, V>`
+    Pair(&'a syn::GenericArgument, &'a syn::GenericArgument),
+}
+
+const WELL_KNOWN_SINGLE_ARG_SUFFIXES: &[&str] = &["Vec", "Set", "Deque", "Heap", "List"];
+
+/// Infers the type of the item of a well-known collection type based on its name.
+fn collection_item_ty(collection_ty: &syn::Type) -> Option<CollectionItemTy<'_>> {
+    let path = collection_ty.as_path_no_qself()?;
+
+    let last_segment = path.segments.last()?;
+    let args = match &last_segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => &args.args,
+        _ => return None,
+    };
+
+    let last_segment_ident_str = last_segment.ident.to_string();
+
+    // We don't compare the number of type arguments exactly because there
+    // may be optional trailing type arguments for the allocator or the hasher
+    let mut args = args.iter();
+
+    if WELL_KNOWN_SINGLE_ARG_SUFFIXES
+        .iter()
+        .any(|suffix| last_segment_ident_str.ends_with(suffix))
+    {
+        return Some(CollectionItemTy::Single(args.next()?));
+    }
+
+    if last_segment_ident_str.ends_with("Map") {
+        let key = args.next()?;
+        let value = args.next()?;
+        return Some(CollectionItemTy::Pair(key, value));
+    }
+
+    None
+}
+
+fn unknown_collection_err(collection_ty: &syn::Type, attr: &str) -> Error {
+    err!(
+        collection_ty,
+        "the underlying type of this member is not a known collection type; \
+        only a collection type that matches the following patterns will be \
+        accepted by `#[builder({attr})], where * at \
+        the beginning means the collection type may start with any prefix:\n\
+        - *Map<K, V>\n\
+        {}",
+        WELL_KNOWN_SINGLE_ARG_SUFFIXES
+            .iter()
+            .map(|suffix| { format!("- *{suffix}<T>") })
+            .join("\n")
+    )
+}
+
 /// Unfortunately there is no `syn::Parse` impl for `PatIdent` directly,
 /// so we use this workaround instead.
 fn pat_ident(ident_name: &'static str) -> syn::PatIdent {
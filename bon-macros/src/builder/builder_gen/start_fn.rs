@@ -5,6 +5,7 @@ impl super::BuilderGenCtx {
     pub(super) fn start_fn(&self) -> syn::ItemFn {
         let builder_ident = &self.builder_type.ident;
         let docs = &self.start_fn.docs;
+        let attrs = &self.start_fn.attrs;
         let vis = &self.start_fn.vis;
 
         let start_fn_ident = &self.start_fn.ident;
@@ -24,14 +25,6 @@ impl super::BuilderGenCtx {
 
         let receiver = self.receiver();
 
-        let receiver_field_init = receiver.map(|receiver| {
-            let ident = &receiver.field_ident;
-            let self_token = &receiver.with_self_keyword.self_token;
-            quote! {
-                #ident: #self_token,
-            }
-        });
-
         let receiver = receiver.map(|receiver| {
             let mut receiver = receiver.with_self_keyword.clone();
 
@@ -44,56 +37,8 @@ impl super::BuilderGenCtx {
 
         let start_fn_params = self.start_fn_args().map(PosFnMember::fn_input_param);
 
-        // Assign `start_fn_args` to intermediate variables, which may be used
-        // by custom fields init expressions. This is needed only if there is
-        // a conversion configured for the `start_fn` members, otherwise these
-        // are already available in scope as function arguments directly.
-        let start_fn_vars = self.start_fn_args().filter_map(|member| {
-            let ident = &member.ident;
-            let ty = &member.ty.orig;
-            let conversion = member.conversion()?;
-
-            Some(quote! {
-                let #ident: #ty = #conversion;
-            })
-        });
-
-        let start_fn_args_fields_idents = self.start_fn_args().map(|member| &member.ident);
-
-        // Create custom fields in separate variables. This way custom fields
-        // declared lower in the struct definition can reference custom fields
-        // declared higher in their init expressions.
-        let custom_fields_vars = self.custom_fields().map(|field| {
-            let ident = &field.ident;
-            let ty = &field.norm_ty;
-            let init = field
-                .init
-                .as_ref()
-                .map(|init| self.sanitize_expr(init))
-                .unwrap_or_else(|| quote! { ::core::default::Default::default() });
-
-            quote! {
-                let #ident: #ty = #init;
-            }
-        });
-
-        let custom_fields_idents = self.custom_fields().map(|field| &field.ident);
-
         let ide_hints = self.ide_hints();
-
-        // `Default` trait implementation is provided only for tuples up to 12
-        // elements in the standard library 😳:
-        // https://github.com/rust-lang/rust/blob/67bb749c2e1cf503fee64842963dd3e72a417a3f/library/core/src/tuple.rs#L213
-        let named_members_field_init =
-            if self.named_members().take(13).count() <= 12 && self.const_.is_none() {
-                quote!(::core::default::Default::default())
-            } else {
-                let none = format_ident!("None");
-                let nones = self.named_members().map(|_| &none);
-                quote! {
-                    (#(#nones,)*)
-                }
-            };
+        let builder_init = self.builder_init();
 
         let const_ = &self.const_;
         // add the `clippy::needless_lifetimes` lint if before rust version 1.87
@@ -111,6 +56,7 @@ impl super::BuilderGenCtx {
 
         let mut start_fn: syn::ItemFn = syn::parse_quote! {
             #(#docs)*
+            #(#attrs)*
             #[inline(always)]
             #[allow(
                 // This is intentional. We want the builder syntax to compile away
@@ -129,16 +75,7 @@ impl super::BuilderGenCtx {
             #where_clause
             {
                 #ide_hints
-                #( #start_fn_vars )*
-                #( #custom_fields_vars )*
-
-                #builder_ident {
-                    __unsafe_private_phantom: ::core::marker::PhantomData,
-                    #( #custom_fields_idents, )*
-                    #receiver_field_init
-                    #( #start_fn_args_fields_idents, )*
-                    __unsafe_private_named: #named_members_field_init,
-                }
+                #builder_init
             }
         };
 
@@ -149,4 +86,85 @@ impl super::BuilderGenCtx {
 
         start_fn
     }
+
+    /// Statements that initialize the builder in the starting function. The
+    /// receiver and `start_fn` members are expected to be in scope.
+    pub(super) fn builder_init(&self) -> TokenStream {
+        let builder_ident = &self.builder_type.ident;
+
+        let receiver_field_init = self.receiver().map(|receiver| {
+            let ident = &receiver.field_ident;
+            let self_token = &receiver.with_self_keyword.self_token;
+            quote! {
+                #ident: #self_token,
+            }
+        });
+
+        // Assign `start_fn_args` to intermediate variables, which may be used
+        // by custom fields init expressions. This is needed only if there is
+        // a conversion configured for the `start_fn` members, otherwise these
+        // are already available in scope as function arguments directly.
+        let start_fn_vars = self.start_fn_args().filter_map(|member| {
+            let ident = &member.ident;
+            let ty = &member.ty.orig;
+            let conversion = member.conversion()?;
+
+            Some(quote! {
+                let #ident: #ty = #conversion;
+            })
+        });
+
+        let start_fn_args_fields_idents = self.start_fn_args().map(|member| &member.ident);
+
+        // Create custom fields in separate variables. This way custom fields
+        // declared lower in the struct definition can reference custom fields
+        // declared higher in their init expressions.
+        let custom_fields_vars = self.custom_fields().map(|field| {
+            let ident = &field.ident;
+            let ty = &field.norm_ty;
+            let init = field
+                .init
+                .as_ref()
+                .map(|init| self.sanitize_expr(init))
+                .unwrap_or_else(|| quote! { ::core::default::Default::default() });
+
+            quote! {
+                let #ident: #ty = #init;
+            }
+        });
+
+        let custom_fields_idents = self.custom_fields().map(|field| &field.ident);
+
+        // `Default` trait implementation is provided only for tuples up to 12
+        // elements in the standard library 😳:
+        // https://github.com/rust-lang/rust/blob/67bb749c2e1cf503fee64842963dd3e72a417a3f/library/core/src/tuple.rs#L213
+        //
+        // The `MaybeUninit` storage of members doesn't implement `Default` at all.
+        let named_members_field_init = if self.named_members().take(13).count() <= 12
+            && self.const_.is_none()
+            && !self.maybe_uninit
+        {
+            quote!(::core::default::Default::default())
+        } else {
+            let members = self
+                .named_members()
+                .map(|member| self.named_member_unset(member));
+            quote! {
+                (#(#members,)*)
+            }
+        };
+
+        quote! {
+            #( #start_fn_vars )*
+            #( #custom_fields_vars )*
+
+            #builder_ident {
+                __unsafe_private_phantom: ::core::marker::PhantomData,
+                #( #custom_fields_idents, )*
+                #receiver_field_init
+                #( #start_fn_args_fields_idents, )*
+                __unsafe_private_named: #named_members_field_init,
+            }
+        }
+    }
 }
@@ -1,5 +1,8 @@
+use super::relations::RelationKind;
+use super::top_level_config::GroupConfig;
 use super::BuilderGenCtx;
 use crate::util::prelude::*;
+use std::cmp::Ordering;
 
 pub(super) struct StateModGenCtx<'a> {
     base: &'a BuilderGenCtx,
@@ -44,11 +47,14 @@ impl<'a> StateModGenCtx<'a> {
         let vis_child_child = &self.base.state_mod.vis_child_child;
 
         let state_mod_docs = &self.base.state_mod.docs;
+        let state_mod_attrs = &self.base.state_mod.attrs;
         let state_mod_ident = &self.base.state_mod.ident;
 
         let state_trait = self.state_trait();
         let is_complete_trait = self.is_complete_trait();
         let members_names_mod = self.members_names_mod();
+        let groups_mod = self.groups_mod();
+        let relations_traits = self.relations_traits();
         let state_transitions = self.state_transitions();
 
         quote! {
@@ -67,6 +73,7 @@ impl<'a> StateModGenCtx<'a> {
                 unnameable_types, unreachable_pub, clippy::redundant_pub_crate
             )]
             #( #state_mod_docs )*
+            #( #state_mod_attrs )*
             #vis mod #state_mod_ident {
                 #[doc(inline)]
                 #vis_child use #bon::__::{IsSet, IsUnset};
@@ -79,6 +86,8 @@ impl<'a> StateModGenCtx<'a> {
                 #state_trait
                 #is_complete_trait
                 #members_names_mod
+                #groups_mod
+                #relations_traits
                 #state_transitions
             }
         }
@@ -145,12 +154,33 @@ impl<'a> StateModGenCtx<'a> {
         let stateful_members_snake = &self.stateful_members_snake;
         let stateful_members_pascal = &self.stateful_members_pascal;
 
+        let all_set = self.base.to_builder.then(|| {
+            let all_set =
+                stateful_members_pascal
+                    .iter()
+                    .rev()
+                    .fold(quote!(Empty), |state, member_pascal| {
+                        let set_member = format_ident!("Set{member_pascal}");
+                        quote!(#set_member<#state>)
+                    });
+
+            quote! {
+                /// Represents a [`State`] that has [`IsSet`] implemented for all members.
+                ///
+                /// This is the state of the builder returned by the `to_builder()`
+                /// and `into_builder()` methods.
+                #vis_child type AllSet = #all_set;
+            }
+        });
+
         quote! {
             /// Represents a [`State`] that has [`IsUnset`] implemented for all members.
             ///
             /// This is the initial state of the builder before any setters are called.
             #vis_child struct Empty(());
 
+            #all_set
+
             #( #set_members_structs )*
 
             #[doc(hidden)]
@@ -192,12 +222,19 @@ impl<'a> StateModGenCtx<'a> {
             "Builder's type state specifies if members are set or not (unset).{docs_suffix}"
         );
 
+        // The builder's storage of members uses the type state to figure out
+        // if the values are initialized
+        let assoc_types_bounds = self.base.maybe_uninit.then(|| {
+            let bon = &self.base.bon;
+            quote!(: #bon::__::MemberState)
+        });
+
         quote! {
             #[doc = #docs]
             #vis_child trait State: ::core::marker::Sized {
                 #(
                     #[doc = #assoc_types_docs]
-                    type #stateful_members_pascal;
+                    type #stateful_members_pascal #assoc_types_bounds;
                 )*
                 #sealed_item_decl
             }
@@ -222,6 +259,21 @@ impl<'a> StateModGenCtx<'a> {
             }
         });
 
+        let groups_predicates = self
+            .required_groups()
+            .map(|group| {
+                let trait_ident = BuilderGenCtx::group_is_set_trait(group);
+                let members_pascal = self
+                    .base
+                    .group_members(group)
+                    .map(|member| &member.name.pascal);
+
+                quote! {
+                    ( #( S::#members_pascal, )* ): groups::#trait_ident
+                }
+            })
+            .collect::<Vec<_>>();
+
         let vis_child = &self.base.state_mod.vis_child;
         let sealed_item_decl = &self.sealed_item_decl;
         let sealed_item_impl = &self.sealed_item_impl;
@@ -247,12 +299,133 @@ impl<'a> StateModGenCtx<'a> {
                 #(
                     S::#required_members_pascal: IsSet,
                 )*
+                #(
+                    #groups_predicates,
+                )*
             {
                 #sealed_item_impl
             }
         }
     }
 
+    fn required_groups(&self) -> impl Iterator<Item = &'a GroupConfig> {
+        self.base
+            .groups
+            .iter()
+            .filter(|group| group.kind.requires_one())
+    }
+
+    /// Generates a trait for every group that requires at least one of its
+    /// members to be set. The trait is implemented for the tuple of the type
+    /// states of the group's members. There is one impl per member, where the
+    /// member is set, all members before it are unset, and all members after it
+    /// can be in any state. This way the impls don't overlap.
+    fn groups_mod(&self) -> Option<TokenStream> {
+        self.required_groups().next()?;
+
+        let vis_child_child = &self.base.state_mod.vis_child_child;
+
+        let traits = self.required_groups().map(|group| {
+            let trait_ident = BuilderGenCtx::group_is_set_trait(group);
+            let members: Vec<_> = self.base.group_members(group).collect();
+
+            let group_name = group.name.raw_name();
+            let members_list = members
+                .iter()
+                .map(|member| format!("`{}`", member.name.snake_raw_str))
+                .join(", ");
+
+            let message = format!(
+                "at least one of the members of the group `{group_name}` must be set: {members_list}"
+            );
+            let label = format!("none of the members of the group `{group_name}` were set");
+
+            let impls = (0..members.len()).map(|set_index| {
+                let generics = (set_index + 1..members.len())
+                    .map(|index| format_ident!("T{index}"))
+                    .collect::<Vec<_>>();
+
+                let states = members.iter().enumerate().map(|(index, member)| {
+                    let member_snake = &member.name.snake;
+                    match index.cmp(&set_index) {
+                        Ordering::Less => quote!(Unset<members::#member_snake>),
+                        Ordering::Equal => quote!(Set<members::#member_snake>),
+                        Ordering::Greater => {
+                            let var = format_ident!("T{index}");
+                            quote!(#var)
+                        }
+                    }
+                });
+
+                quote! {
+                    impl<#(#generics,)*> #trait_ident for ( #( #states, )* ) {}
+                }
+            });
+
+            let bon = &self.base.bon;
+
+            quote! {
+                #[#bon::__::rustversion::attr(
+                    since(1.78.0),
+                    diagnostic::on_unimplemented(message = #message, label = #label)
+                )]
+                #vis_child_child trait #trait_ident {}
+
+                #( #impls )*
+            }
+        });
+
+        Some(quote! {
+            #[doc(hidden)]
+            mod groups {
+                use super::{members, Set, Unset};
+
+                #( #traits )*
+            }
+        })
+    }
+
+    /// Generates a trait for every `requires` or `conflicts_with` relation
+    /// between members. The trait is implemented only for the allowed state of
+    /// the other member, and it's used in the bounds of the member's setters
+    /// to report an error that names both members.
+    fn relations_traits(&self) -> TokenStream {
+        let vis_child = &self.base.state_mod.vis_child;
+        let bon = &self.base.bon;
+
+        // The relations are validated when the context is created
+        let relations = self.base.relations().unwrap_or_default();
+
+        let traits = relations.iter().map(|relation| {
+            let trait_ident = relation.trait_ident();
+            let message = relation.diagnostic_message();
+            let label = relation.diagnostic_label();
+            let other_snake = &relation.other.name.snake;
+
+            let allowed_state = match relation.kind {
+                RelationKind::Requires => quote!(Set<members::#other_snake>),
+                RelationKind::ConflictsWith | RelationKind::Stage => {
+                    quote!(Unset<members::#other_snake>)
+                }
+            };
+
+            quote! {
+                #[doc(hidden)]
+                #[#bon::__::rustversion::attr(
+                    since(1.78.0),
+                    diagnostic::on_unimplemented(message = #message, label = #label)
+                )]
+                #vis_child trait #trait_ident {}
+
+                impl #trait_ident for #allowed_state {}
+            }
+        });
+
+        quote! {
+            #( #traits )*
+        }
+    }
+
     fn members_names_mod(&self) -> TokenStream {
         let vis_child_child = &self.base.state_mod.vis_child_child;
         let stateful_members_snake = &self.stateful_members_snake;
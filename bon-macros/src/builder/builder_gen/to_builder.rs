@@ -0,0 +1,154 @@
+use super::member::Member;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    /// Generates the `into_builder()` and `to_builder()` methods for the struct
+    /// if `#[builder(to_builder)]` is enabled. They are placed in the same impl
+    /// block as the starting function.
+    pub(crate) fn to_builder_methods(&self, is_tuple: bool) -> Option<TokenStream> {
+        if !self.to_builder {
+            return None;
+        }
+
+        let vis = &self.builder_type.vis;
+        let builder_ident = &self.builder_type.ident;
+        let generic_args = &self.generics.args;
+        let state_mod = &self.state_mod.ident;
+        let state_arg = self.typestate.then(|| quote!(#state_mod::AllSet));
+
+        let return_type = quote! {
+            #builder_ident<#(#generic_args,)* #state_arg>
+        };
+
+        let pattern = self.to_builder_pattern(is_tuple);
+
+        // The bounds are higher-ranked to postpone their check until the method
+        // is called. This way `into_builder()` is still available if some of
+        // the members aren't `Clone`.
+        let clone_bounds = self
+            .members
+            .iter()
+            .filter(|member| Self::is_stored_in_builder(member))
+            .map(|member| {
+                let ty = member.norm_ty();
+                quote!(for<'__clone> #ty: ::core::clone::Clone)
+            });
+
+        let into_builder = self.to_builder_body(|var| quote!(#var));
+        let to_builder = self.to_builder_body(|var| quote!(::core::clone::Clone::clone(#var)));
+
+        let into_builder_docs = format!(
+            "Converts the value back into a [`{builder_ident}`] with all members set.\n\n\
+            Use it together with `overwritable` members to change some of the \
+            values and call the finishing function again."
+        );
+
+        let to_builder_docs = format!(
+            "Clones the values of the members into a [`{builder_ident}`] \
+            with all members set.\n\n\
+            See [`Self::into_builder()`] for details."
+        );
+
+        Some(quote! {
+            #[doc = #into_builder_docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+                clippy::missing_const_for_fn,
+            )]
+            #vis fn into_builder(self) -> #return_type {
+                let Self #pattern = self;
+                #into_builder
+            }
+
+            #[doc = #to_builder_docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+            )]
+            #vis fn to_builder(&self) -> #return_type
+            where
+                #( #clone_bounds, )*
+            {
+                let Self #pattern = self;
+                #to_builder
+            }
+        })
+    }
+
+    /// The pattern that destructures the struct into variables named after
+    /// the members. Members that aren't stored in the builder are ignored.
+    fn to_builder_pattern(&self, is_tuple: bool) -> TokenStream {
+        if is_tuple {
+            // Members preserve the order of the fields
+            let fields = self.members.iter().map(|member| {
+                if Self::is_stored_in_builder(member) {
+                    let var = member.orig_ident();
+                    quote!(#var)
+                } else {
+                    quote!(_)
+                }
+            });
+
+            return quote! {
+                ( #( #fields, )* )
+            };
+        }
+
+        let fields = self.members.iter().map(|member| {
+            let ident = member.orig_ident();
+            if Self::is_stored_in_builder(member) {
+                quote!(#ident)
+            } else {
+                quote!(#ident: _)
+            }
+        });
+
+        quote! {
+            { #( #fields, )* }
+        }
+    }
+
+    fn is_stored_in_builder(member: &Member) -> bool {
+        !matches!(member, Member::FinishFn(_) | Member::Skip(_))
+    }
+
+    fn to_builder_body(&self, value: impl Fn(&syn::Ident) -> TokenStream) -> TokenStream {
+        let builder_ident = &self.builder_type.ident;
+
+        let custom_fields = self.custom_fields().map(|field| {
+            let ident = &field.ident;
+            let value = value(ident);
+            quote!(#ident: #value)
+        });
+
+        let start_fn_args = self.start_fn_args().map(|member| {
+            let ident = &member.ident;
+            let value = value(ident);
+            quote!(#ident: #value)
+        });
+
+        let named = self.named_members().map(|member| {
+            let value = value(&member.name.orig);
+
+            // The builder stores the value of an `Option<T>` member as is
+            if member.is_special_option_ty() {
+                value
+            } else {
+                self.named_member_set(member, &value)
+            }
+        });
+
+        quote! {
+            #builder_ident {
+                __unsafe_private_phantom: ::core::marker::PhantomData,
+                #( #custom_fields, )*
+                #( #start_fn_args, )*
+                __unsafe_private_named: ( #( #named, )* ),
+            }
+        }
+    }
+}
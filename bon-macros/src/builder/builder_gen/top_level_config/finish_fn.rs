@@ -0,0 +1,183 @@
+use crate::parsing::{
+    ClosureResultTy, ItemSigConfig, ItemSigConfigParsing, SimpleClosure, SpannedKey,
+};
+use crate::util::prelude::*;
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+
+const INVALID_RETURN_TYPE_ERROR: &str = "\
+expected a `-> *Result<(), ErrorType>` return type annotation on the validation
+closure; the error type is used in the return type of the finishing function, so
+it must be specified explicitly;
+
+the return type doesn't have to be named `Result` exactly, the only requirement is
+that it must have the `Result` suffix; for example if you have a type alias
+`ApiResult<(), E>`, then it'll work fine";
+
+#[derive(Debug, Default)]
+pub(crate) struct FinishFnConfig {
+    pub(crate) sig: ItemSigConfig,
+
+    /// Cross-member validation of the final values of all members
+    pub(crate) validate: Option<SpannedKey<FinishFnValidateConfig>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct FinishFnValidateConfig {
+    pub(crate) func: FinishFnValidateFn,
+
+    /// The error type returned by the validation function
+    pub(crate) err_ty: syn::Type,
+}
+
+#[derive(Debug)]
+pub(crate) enum FinishFnValidateFn {
+    /// Closure syntax e.g. `validate = |a, b| -> Result<(), E> { ... }`
+    Closure(SimpleClosure),
+
+    /// Path to a function e.g. `validate = path::to::func`
+    Path(syn::Path),
+}
+
+pub(super) fn parse_finish_fn(meta: &syn::Meta) -> Result<FinishFnConfig> {
+    let list = match meta {
+        syn::Meta::List(list) => list,
+        _ => {
+            return Ok(FinishFnConfig {
+                sig: parse_sig(meta)?,
+                validate: None,
+            })
+        }
+    };
+
+    let nested = NestedMeta::parse_meta_list(list.tokens.clone())?;
+
+    let mut validate = None;
+    let mut error = None;
+    let mut rest = vec![];
+
+    for item in nested {
+        match &item {
+            NestedMeta::Meta(meta) if meta.path().is_ident("validate") => {
+                if validate.is_some() {
+                    bail!(meta.path(), "duplicate `validate` parameter");
+                }
+                validate = Some(meta.clone());
+            }
+            NestedMeta::Meta(meta) if meta.path().is_ident("error") => {
+                if error.is_some() {
+                    bail!(meta.path(), "duplicate `error` parameter");
+                }
+                error = Some(meta.clone());
+            }
+            _ => rest.push(item),
+        }
+    }
+
+    let validate = match (validate, error) {
+        (Some(validate), error) => Some(parse_validate(&validate, error.as_ref())?),
+        (None, Some(error)) => bail!(
+            error.path(),
+            "`error` parameter can only be specified together with `validate`"
+        ),
+        (None, None) => None,
+    };
+
+    if validate.is_none() || !rest.is_empty() {
+        let rest = syn::Meta::List(syn::MetaList {
+            path: list.path.clone(),
+            delimiter: list.delimiter.clone(),
+            tokens: quote!(#(#rest),*),
+        });
+
+        return Ok(FinishFnConfig {
+            sig: parse_sig(&rest)?,
+            validate,
+        });
+    }
+
+    Ok(FinishFnConfig {
+        sig: ItemSigConfig::default(),
+        validate,
+    })
+}
+
+fn parse_sig(meta: &syn::Meta) -> Result<ItemSigConfig> {
+    ItemSigConfigParsing::new(meta, Some("builder struct's impl block")).parse()
+}
+
+fn parse_validate(
+    meta: &syn::Meta,
+    error: Option<&syn::Meta>,
+) -> Result<SpannedKey<FinishFnValidateConfig>> {
+    let value = match meta {
+        syn::Meta::NameValue(meta) => &meta.value,
+        _ => bail!(
+            meta,
+            "expected a closure or a path to a function e.g. \
+            `validate = |a, b| -> Result<(), E> {{ ... }}` or \
+            `validate = path::to::func, error = E`"
+        ),
+    };
+
+    let error = error
+        .map(|error| match error {
+            syn::Meta::NameValue(error) => Ok(syn::parse2(error.value.to_token_stream())?),
+            _ => bail!(error, "expected a type e.g. `error = ErrorType`"),
+        })
+        .transpose()?;
+
+    let config = match value {
+        syn::Expr::Closure(_) => {
+            let closure = SimpleClosure::from_meta(meta)?;
+
+            if let Some(error) = &error {
+                bail!(
+                    error,
+                    "`error` parameter is redundant when a closure is used \
+                    for `validate`; the error type is taken from the closure's \
+                    return type annotation"
+                );
+            }
+
+            let err_ty = parse_closure_err_ty(&closure)?;
+
+            FinishFnValidateConfig {
+                func: FinishFnValidateFn::Closure(closure),
+                err_ty,
+            }
+        }
+        syn::Expr::Path(path) => {
+            crate::parsing::reject_attrs(&path.attrs)?;
+
+            if path.qself.is_some() {
+                bail!(&path, "qualified paths aren't supported in `validate`");
+            }
+
+            let err_ty = error.ok_or_else(|| {
+                err!(
+                    &path,
+                    "the error type of the validation function must be specified \
+                    explicitly via `error = ErrorType` next to the `validate` parameter"
+                )
+            })?;
+
+            FinishFnValidateConfig {
+                func: FinishFnValidateFn::Path(path.path.clone()),
+                err_ty,
+            }
+        }
+        _ => bail!(value, "expected a closure or a path to a function"),
+    };
+
+    SpannedKey::new(meta.path(), config)
+}
+
+fn parse_closure_err_ty(closure: &SimpleClosure) -> Result<syn::Type> {
+    match &closure.output {
+        syn::ReturnType::Type(_, ty) => {
+            ClosureResultTy::parse_unit_ok_err_ty(ty, INVALID_RETURN_TYPE_ERROR)
+        }
+        syn::ReturnType::Default => bail!(&closure.body, "{INVALID_RETURN_TYPE_ERROR}"),
+    }
+}
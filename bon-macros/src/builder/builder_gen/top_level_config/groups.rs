@@ -0,0 +1,99 @@
+use crate::util::prelude::*;
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+
+/// Groups of members declared via `#[builder(groups(name = kind, ...))]`
+#[derive(Debug, Default)]
+pub(crate) struct GroupsConfig(pub(crate) Vec<GroupConfig>);
+
+#[derive(Debug)]
+pub(crate) struct GroupConfig {
+    pub(crate) name: syn::Ident,
+    pub(crate) kind: GroupKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(clippy::enum_variant_names)]
+pub(crate) enum GroupKind {
+    ExactlyOne,
+    AtMostOne,
+    AtLeastOne,
+}
+
+impl GroupKind {
+    const VARIANTS: &'static [(&'static str, Self)] = &[
+        ("exactly_one", Self::ExactlyOne),
+        ("at_most_one", Self::AtMostOne),
+        ("at_least_one", Self::AtLeastOne),
+    ];
+
+    /// Setting one member of the group makes setters of other members unavailable.
+    pub(crate) fn is_exclusive(self) -> bool {
+        matches!(self, Self::ExactlyOne | Self::AtMostOne)
+    }
+
+    /// The finishing function requires at least one member of the group to be set.
+    pub(crate) fn requires_one(self) -> bool {
+        matches!(self, Self::ExactlyOne | Self::AtLeastOne)
+    }
+
+    pub(crate) fn as_str(self) -> &'static str {
+        Self::VARIANTS
+            .iter()
+            .find(|(_, kind)| *kind == self)
+            .map(|(name, _)| *name)
+            .unwrap_or_default()
+    }
+}
+
+impl FromMeta for GroupsConfig {
+    fn from_list(items: &[NestedMeta]) -> Result<Self> {
+        let mut groups: Vec<GroupConfig> = Vec::with_capacity(items.len());
+
+        for item in items {
+            let meta = match item {
+                NestedMeta::Meta(syn::Meta::NameValue(meta)) => meta,
+                _ => bail!(
+                    item,
+                    "expected a group declaration in the form of `name = kind` \
+                    e.g. `groups(source = exactly_one)`"
+                ),
+            };
+
+            let name = meta.path.require_ident()?.clone();
+
+            if let Some(prev) = groups.iter().find(|group| group.name == name) {
+                bail!(
+                    &name,
+                    "the group `{}` is already declared",
+                    prev.name.raw_name()
+                );
+            }
+
+            let kind = match &meta.value {
+                syn::Expr::Path(path) => path.path.get_ident(),
+                _ => None,
+            };
+
+            let kind = kind
+                .and_then(|kind| {
+                    GroupKind::VARIANTS
+                        .iter()
+                        .find(|(variant, _)| kind == variant)
+                        .map(|(_, kind)| *kind)
+                })
+                .ok_or_else(|| {
+                    let expected = GroupKind::VARIANTS
+                        .iter()
+                        .map(|(variant, _)| format!("`{variant}`"))
+                        .join(", ");
+
+                    err!(&meta.value, "expected one of the group kinds: {expected}")
+                })?;
+
+            groups.push(GroupConfig { name, kind });
+        }
+
+        Ok(Self(groups))
+    }
+}
@@ -0,0 +1,277 @@
+//! Default builder config declared in the `[package.metadata.bon]` or
+//! `[workspace.metadata.bon]` table of the `Cargo.toml` manifest.
+
+use super::TopLevelConfig;
+use crate::util::prelude::*;
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use std::path::{Path, PathBuf};
+
+/// The only key supported in the `metadata.bon` table. Its value is a string
+/// with the same syntax as the parameters of the `#[builder(...)]` attribute.
+const BUILDER_KEY: &str = "builder";
+
+pub(crate) struct ManifestConfig {
+    metas: Vec<NestedMeta>,
+}
+
+impl ManifestConfig {
+    /// Looks up the config in the manifest of the crate being compiled. If it
+    /// doesn't have the `[package.metadata.bon]` table, then the
+    /// `[workspace.metadata.bon]` table of the workspace root manifest is used.
+    pub(crate) fn load() -> Result<Option<Self>> {
+        let manifest_dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+            Some(dir) => PathBuf::from(dir),
+
+            // The macro isn't invoked by `cargo`, so there is no manifest
+            None => return Ok(None),
+        };
+
+        for (depth, dir) in manifest_dir.ancestors().enumerate() {
+            let path = dir.join("Cargo.toml");
+
+            // The workspace root is the closest ancestor manifest with the
+            // `[workspace]` table, and the directories above the package
+            // may not have manifests at all.
+            if depth > 0 && !path.is_file() {
+                continue;
+            }
+
+            let manifest = read_manifest(&path)?;
+
+            if depth == 0 {
+                if let Some(config) = Self::from_manifest(&manifest, &path, "package")? {
+                    return Ok(Some(config));
+                }
+            }
+
+            if manifest.get("workspace").is_some() {
+                return Self::from_manifest(&manifest, &path, "workspace");
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn from_manifest(manifest: &toml::Value, path: &Path, section: &str) -> Result<Option<Self>> {
+        let table = manifest
+            .get(section)
+            .and_then(|section| section.get("metadata"))
+            .and_then(|metadata| metadata.get("bon"));
+
+        let table = match table {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+
+        let origin = format!("`[{section}.metadata.bon]` in {}", path.display());
+
+        Self::from_table(table, &origin).map(Some)
+    }
+
+    fn from_table(table: &toml::Value, origin: &str) -> Result<Self> {
+        let table = table
+            .as_table()
+            .ok_or_else(|| err!(&Span::call_site(), "{origin} must be a table"))?;
+
+        if let Some(key) = table.keys().find(|key| *key != BUILDER_KEY) {
+            bail!(
+                &Span::call_site(),
+                "unknown key `{key}` in {origin}; the only supported key is `{BUILDER_KEY}`",
+            );
+        }
+
+        let builder = match table.get(BUILDER_KEY) {
+            Some(builder) => builder,
+            None => return Ok(Self { metas: vec![] }),
+        };
+
+        let builder = builder.as_str().ok_or_else(|| {
+            err!(
+                &Span::call_site(),
+                "`{BUILDER_KEY}` in {origin} must be a string with the parameters \
+                of the `#[builder(...)]` attribute, for example: \
+                `{BUILDER_KEY} = \"on(String, into), derive(Debug)\"`",
+            )
+        })?;
+
+        let metas = syn::parse_str(builder)
+            .map_err(Error::from)
+            .and_then(|tokens| Ok(NestedMeta::parse_meta_list(tokens)?))
+            .and_then(|metas| {
+                // Validate the config separately from the item's config to
+                // point to the manifest in the error message
+                TopLevelConfig::from_list(&metas)?;
+                Ok(metas)
+            })
+            .map_err(|err| {
+                err!(
+                    &Span::call_site(),
+                    "invalid `{BUILDER_KEY}` in {origin}: {err}"
+                )
+            })?;
+
+        Ok(Self { metas })
+    }
+
+    /// Adds the parameters from the manifest to the item's config as defaults.
+    /// Every parameter of the item's config overrides the parameter with the
+    /// same name from the manifest, except for the `on(...)` clauses. They are
+    /// placed after the item's own `on(...)` clauses instead, so that the
+    /// item's clauses take precedence, because the first matching clause wins.
+    pub(crate) fn merge_into(self, mut configs: Vec<NestedMeta>) -> Vec<NestedMeta> {
+        fn key(meta: &NestedMeta) -> Option<&syn::Ident> {
+            match meta {
+                NestedMeta::Meta(meta) => meta.path().get_ident(),
+                NestedMeta::Lit(_) => None,
+            }
+        }
+
+        let is_on = |meta: &NestedMeta| key(meta).map_or(false, |key| key == "on");
+
+        let (on, defaults): (Vec<_>, Vec<_>) = self.metas.into_iter().partition(is_on);
+
+        let defaults = defaults
+            .into_iter()
+            .filter(|default| !configs.iter().any(|config| key(config) == key(default)))
+            .collect::<Vec<_>>();
+
+        let on_position = configs
+            .iter()
+            .rposition(is_on)
+            .map_or(configs.len(), |position| position + 1);
+
+        configs.splice(on_position..on_position, on);
+        configs.extend(defaults);
+        configs
+    }
+}
+
+fn read_manifest(path: &Path) -> Result<toml::Value> {
+    let manifest = std::fs::read_to_string(path).map_err(|err| {
+        err!(
+            &Span::call_site(),
+            "failed to read {} to look up the `bon` config in it: {err}",
+            path.display(),
+        )
+    })?;
+
+    manifest.parse().map_err(|err| {
+        err!(
+            &Span::call_site(),
+            "failed to parse {}: {err}",
+            path.display()
+        )
+    })
+}
+
+/// `cargo` doesn't know about the files read by proc macros, and it doesn't
+/// recompile the crate when the metadata in its manifest changes. The generated
+/// `include_bytes!()` makes `rustc` report the manifests as the dependencies of
+/// the crate, so it is recompiled when the config in the manifests changes.
+///
+/// All manifests in the ancestor directories are included, because any of them
+/// may become the workspace root that the config is read from. The output is a
+/// sequence of statements that must be placed inside of a `const _` item.
+pub(crate) fn manifests_tracking() -> TokenStream {
+    let manifest_dir = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => return TokenStream::new(),
+    };
+
+    manifest_dir
+        .ancestors()
+        .enumerate()
+        .filter(|(depth, dir)| *depth == 0 || dir.join("Cargo.toml").is_file())
+        .map(|(depth, _)| {
+            let path = format!("/{}Cargo.toml", "../".repeat(depth));
+            quote! {
+                ::core::include_bytes!(::core::concat!(
+                    ::core::env!("CARGO_MANIFEST_DIR"),
+                    #path
+                ));
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[track_caller]
+    fn from_table(table: &str) -> Result<ManifestConfig> {
+        let table: toml::Value = table.parse().unwrap();
+        ManifestConfig::from_table(table.get("bon").unwrap(), "`[package.metadata.bon]`")
+    }
+
+    #[track_caller]
+    fn assert_err(table: &str, expected: &str) {
+        let err = from_table(table).err().unwrap();
+        assert_eq!(err.to_string(), expected);
+    }
+
+    #[track_caller]
+    fn merge(manifest: &str, item: TokenStream) -> String {
+        let config = from_table(&format!("[bon]\nbuilder = {manifest:?}")).unwrap();
+        let configs = NestedMeta::parse_meta_list(item).unwrap();
+        let configs = config.merge_into(configs);
+        quote!(#(#configs),*).to_string()
+    }
+
+    #[test]
+    fn malformed_table() {
+        assert_err("bon = 1", "`[package.metadata.bon]` must be a table");
+        assert_err(
+            "[bon]\nbuild = 'finish_fn = build'",
+            "unknown key `build` in `[package.metadata.bon]`; the only supported key is `builder`",
+        );
+        assert_err(
+            "[bon]\nbuilder = ['on(String, into)']",
+            "`builder` in `[package.metadata.bon]` must be a string with the parameters \
+            of the `#[builder(...)]` attribute, for example: \
+            `builder = \"on(String, into), derive(Debug)\"`",
+        );
+        assert_err(
+            "[bon]\nbuilder = 'finish_fn ='",
+            "invalid `builder` in `[package.metadata.bon]`: unexpected end of input, \
+            expected an expression",
+        );
+        assert_err(
+            "[bon]\nbuilder = 'unknown_param'",
+            "invalid `builder` in `[package.metadata.bon]`: Unknown field: `unknown_param`",
+        );
+    }
+
+    #[test]
+    fn empty_table() {
+        assert_eq!(merge("", quote!(finish_fn = finish)), "finish_fn = finish");
+    }
+
+    #[test]
+    fn item_config_overrides_defaults() {
+        assert_eq!(
+            merge(
+                "finish_fn = build, derive(Debug), builder_type = Builder",
+                quote!(derive(Clone), finish_fn = finish),
+            ),
+            "derive (Clone) , finish_fn = finish , builder_type = Builder",
+        );
+    }
+
+    #[test]
+    fn on_clauses_follow_item_clauses() {
+        assert_eq!(
+            merge(
+                "on(String, into), on(u32, default)",
+                quote!(derive(Clone), on(_, required), finish_fn = finish),
+            ),
+            "derive (Clone) , on (_ , required) , on (String , into) , \
+            on (u32 , default) , finish_fn = finish",
+        );
+        assert_eq!(
+            merge("on(String, into), finish_fn = build", quote!(derive(Clone))),
+            "derive (Clone) , on (String , into) , finish_fn = build",
+        );
+    }
+}
@@ -1,7 +1,15 @@
+mod finish_fn;
 mod generics;
+mod groups;
+#[cfg(feature = "manifest-config")]
+mod manifest;
 mod on;
 
+pub(crate) use finish_fn::*;
 pub(crate) use generics::GenericsConfig;
+pub(crate) use groups::{GroupConfig, GroupsConfig};
+#[cfg(feature = "manifest-config")]
+pub(crate) use manifest::manifests_tracking;
 pub(crate) use on::OnConfig;
 
 use crate::parsing::{BonCratePath, ItemSigConfig, ItemSigConfigParsing, SpannedKey};
@@ -12,10 +20,6 @@ use syn::parse::Parser;
 use syn::punctuated::Punctuated;
 use syn::ItemFn;
 
-fn parse_finish_fn(meta: &syn::Meta) -> Result<ItemSigConfig> {
-    ItemSigConfigParsing::new(meta, Some("builder struct's impl block")).parse()
-}
-
 fn parse_builder_type(meta: &syn::Meta) -> Result<ItemSigConfig> {
     ItemSigConfigParsing::new(meta, Some("builder struct")).parse()
 }
@@ -47,7 +51,7 @@ pub(crate) struct TopLevelConfig {
     pub(crate) start_fn: ItemSigConfig,
 
     #[darling(default, with = parse_finish_fn)]
-    pub(crate) finish_fn: ItemSigConfig,
+    pub(crate) finish_fn: FinishFnConfig,
 
     #[darling(default, with = parse_builder_type)]
     pub(crate) builder_type: ItemSigConfig,
@@ -65,6 +69,26 @@ pub(crate) struct TopLevelConfig {
     /// Specifies configuration for generic parameter conversion methods.
     #[darling(default, with = crate::parsing::parse_non_empty_paren_meta_list)]
     pub(crate) generics: Option<SpannedKey<GenericsConfig>>,
+
+    /// Generates `to_builder()` and `into_builder()` methods that convert
+    /// the struct back into a builder with all members set.
+    pub(crate) to_builder: darling::util::Flag,
+
+    /// Generates an `update()` method that returns a builder-like type for
+    /// updating the members of an existing value of the struct in place.
+    pub(crate) update: darling::util::Flag,
+
+    /// Declares groups of members that restrict how many of them can be set.
+    #[darling(default, with = crate::parsing::parse_non_empty_paren_meta_list)]
+    pub(crate) groups: GroupsConfig,
+
+    /// If `false`, then the builder doesn't have a type state. The required
+    /// members are checked at runtime by the finishing function instead.
+    pub(crate) typestate: Option<SpannedKey<bool>>,
+
+    /// Stores the values of required members in `MaybeUninit` instead of
+    /// `Option` and uses the type state to track their initialization.
+    pub(crate) maybe_uninit: darling::util::Flag,
 }
 
 impl TopLevelConfig {
@@ -89,12 +113,20 @@ impl TopLevelConfig {
 
         let me = Self::parse_for_any(configs)?;
 
+        me.require_struct_only_configs_absent()?;
+
         if me.start_fn.name.is_none() {
-            let ItemSigConfig { name: _, vis, docs } = &me.start_fn;
+            let ItemSigConfig {
+                name: _,
+                vis,
+                docs,
+                attrs,
+            } = &me.start_fn;
 
             let unexpected_param = None
                 .or_else(|| vis.as_ref().map(SpannedKey::key))
-                .or_else(|| docs.as_ref().map(SpannedKey::key));
+                .or_else(|| docs.as_ref().map(SpannedKey::key))
+                .or_else(|| attrs.as_ref().map(SpannedKey::key));
 
             if let Some(unexpected_param) = unexpected_param {
                 bail!(
@@ -119,6 +151,113 @@ impl TopLevelConfig {
         Self::parse_for_any(configs)
     }
 
+    /// Rejects the configs that generate methods on the struct itself, so
+    /// they can only be used with `#[derive(Builder)]` on structs.
+    pub(crate) fn require_struct_only_configs_absent(&self) -> Result {
+        let configs = [(&self.to_builder, "to_builder"), (&self.update, "update")];
+
+        for (config, name) in configs {
+            if config.is_present() {
+                bail!(
+                    &config.span(),
+                    "`{name}` is only supported with `#[derive(Builder)]` on structs",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the builder has a type state. It's disabled with `typestate = false`.
+    pub(crate) fn is_typestate(&self) -> bool {
+        self.typestate
+            .as_ref()
+            .map_or(true, |typestate| typestate.value)
+    }
+
+    fn validate_typestate(&self) -> Result {
+        let typestate = match &self.typestate {
+            Some(typestate) if !typestate.value => typestate,
+            _ => return Ok(()),
+        };
+
+        if !cfg!(feature = "alloc") {
+            bail!(
+                &typestate.key,
+                "`typestate = false` requires the `alloc` feature of `bon` to be \
+                enabled, because the error returned by the finishing function \
+                stores the list of the missing members in a `Vec`",
+            );
+        }
+
+        let requires_typestate = |span: Span, what: &str| {
+            err!(
+                &span,
+                "{what} can't be used with `typestate = false`, because it's \
+                enforced by the builder's type state",
+            )
+        };
+
+        if let Some(const_) = &self.const_ {
+            bail!(
+                const_,
+                "`const` can't be used with `typestate = false`, because the \
+                missing members are collected into a `Vec` at runtime, which \
+                isn't possible in a `const` context",
+            );
+        }
+
+        if let Some(group) = self.groups.0.first() {
+            return Err(requires_typestate(group.name.span(), "`groups`"));
+        }
+
+        if let Some(generics) = &self.generics {
+            if generics.setters.is_some() {
+                return Err(requires_typestate(
+                    generics.key.span(),
+                    "`generics(setters(...))`",
+                ));
+            }
+        }
+
+        if self.derive.into.is_present() {
+            bail!(
+                &self.derive.into.span(),
+                "`derive(Into)` can't be used with `typestate = false`, because the \
+                finishing function is fallible in this case; the `From` trait \
+                doesn't support fallible conversions",
+            );
+        }
+
+        Ok(())
+    }
+
+    fn validate_maybe_uninit(&self) -> Result {
+        if !self.maybe_uninit.is_present() {
+            return Ok(());
+        }
+
+        if let Some(const_) = &self.const_ {
+            bail!(
+                const_,
+                "`maybe_uninit` can't be used together with `const`, because the \
+                builder's storage has a `Drop` implementation which isn't \
+                supported in a `const` context",
+            );
+        }
+
+        if !self.is_typestate() {
+            bail!(
+                &self.maybe_uninit.span(),
+                "`maybe_uninit` can't be used together with `typestate = false`, \
+                because it relies on the builder's type state to track which \
+                members are initialized",
+            );
+        }
+
+        Ok(())
+    }
+
     fn parse_for_any(mut configs: Vec<TokenStream>) -> Result<Self> {
         fn parse_const_prefix(
             parse: syn::parse::ParseStream<'_>,
@@ -151,9 +290,15 @@ impl TopLevelConfig {
             .flatten()
             .collect::<Vec<_>>();
 
-        // This is a temporary hack. We only allow `on(_, required)` as the
-        // first `on(...)` clause. Instead we should implement an extended design:
-        // https://github.com/elastio/bon/issues/152
+        #[cfg(feature = "manifest-config")]
+        let configs = match manifest::ManifestConfig::load()? {
+            Some(manifest) => manifest.merge_into(configs),
+            None => configs,
+        };
+
+        // The order of `on(...)` clauses matters, because the first clause that
+        // matches the member wins. Require them to be consecutive to keep that
+        // order easy to follow.
         let mut on_configs = configs
             .iter()
             .enumerate()
@@ -183,6 +328,24 @@ impl TopLevelConfig {
             ..Self::from_list(&configs)?
         };
 
+        if me.const_.is_some() {
+            let closure =
+                me.finish_fn
+                    .validate
+                    .as_ref()
+                    .and_then(|validate| match &validate.func {
+                        FinishFnValidateFn::Closure(closure) => Some(closure),
+                        FinishFnValidateFn::Path(_) => None,
+                    });
+
+            if let Some(closure) = closure {
+                crate::parsing::require_embeddable_const_expr(&closure.body)?;
+            }
+        }
+
+        me.validate_typestate()?;
+        me.validate_maybe_uninit()?;
+
         if let Some(generics) = &me.generics {
             if generics.setters.is_some() {
                 if let Some(const_) = &me.const_ {
@@ -196,24 +359,6 @@ impl TopLevelConfig {
             }
         }
 
-        if let Some(on) = me.on.iter().skip(1).find(|on| on.required.is_present()) {
-            bail!(
-                &on.required.span(),
-                "`required` can only be specified in the first `on(...)` clause; \
-                this restriction may be lifted in the future",
-            );
-        }
-
-        if let Some(first_on) = me.on.first().filter(|on| on.required.is_present()) {
-            if !matches!(first_on.type_pattern, syn::Type::Infer(_)) {
-                bail!(
-                    &first_on.type_pattern,
-                    "`required` can only be used with the wildcard type pattern \
-                    i.e. `on(_, required)`; this restriction may be lifted in the future",
-                );
-            }
-        }
-
         Ok(me)
     }
 }
@@ -226,11 +371,26 @@ pub(crate) struct DerivesConfig {
     #[darling(rename = "Debug")]
     pub(crate) debug: Option<DeriveConfig>,
 
+    #[darling(rename = "PartialEq")]
+    pub(crate) partial_eq: Option<DeriveConfig>,
+
+    #[darling(rename = "Eq")]
+    pub(crate) eq: Option<DeriveConfig>,
+
+    #[darling(rename = "Hash")]
+    pub(crate) hash: Option<DeriveConfig>,
+
+    #[darling(rename = "Default")]
+    pub(crate) default: Option<DeriveConfig>,
+
     #[darling(rename = "Into")]
     pub(crate) into: darling::util::Flag,
 
     #[darling(rename = "IntoFuture")]
     pub(crate) into_future: Option<IntoFutureConfig>,
+
+    #[darling(rename = "IntoIterator")]
+    pub(crate) into_iterator: Option<IntoIteratorConfig>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -240,65 +400,99 @@ pub(crate) struct DeriveConfig {
 
 #[derive(Debug, Clone)]
 pub(crate) struct IntoFutureConfig {
-    pub(crate) box_ident: syn::Ident,
+    /// Present if the future is boxed with `IntoFuture(Box)`
+    pub(crate) box_ident: Option<syn::Ident>,
     pub(crate) is_send: bool,
 }
 
-impl syn::parse::Parse for IntoFutureConfig {
-    fn parse(input: syn::parse::ParseStream<'_>) -> syn::Result<Self> {
-        // Parse "Box" as the required first argument.
-        let box_ident: syn::Ident = input.parse()?;
-        if box_ident != "Box" {
-            return Err(syn::Error::new(
-                box_ident.span(),
-                "expected `Box` as the first argument, only boxed futures are supported",
-            ));
-        }
-
-        // Check for optional ", ?Send" part.
-        let is_send = if input.peek(syn::Token![,]) {
-            input.parse::<syn::Token![,]>()?;
-
-            // Parse "?Send" as a single unit.
-            if input.peek(syn::Token![?]) {
-                input.parse::<syn::Token![?]>()?;
-                let send_ident: syn::Ident = input.parse()?;
-                if send_ident != "Send" {
-                    return Err(syn::Error::new(
-                        send_ident.span(),
-                        "expected `Send` after ?",
-                    ));
-                }
-                false
-            } else {
-                return Err(input.error("expected `?Send` as the second argument"));
-            }
-        } else {
-            true
-        };
+impl FromMeta for IntoFutureConfig {
+    fn from_meta(meta: &syn::Meta) -> Result<Self> {
+        let (box_ident, is_send) = parse_boxable_derive(meta, "IntoFuture", "futures")?;
+        Ok(Self { box_ident, is_send })
+    }
+}
 
-        // Ensure no trailing tokens.
-        if !input.is_empty() {
-            return Err(input.error("unexpected tokens after arguments"));
-        }
+#[derive(Debug, Clone)]
+pub(crate) struct IntoIteratorConfig {
+    /// Present if the iterator is boxed with `IntoIterator(Box)`
+    pub(crate) box_ident: Option<syn::Ident>,
+    pub(crate) is_send: bool,
+}
 
+impl FromMeta for IntoIteratorConfig {
+    fn from_meta(meta: &syn::Meta) -> Result<Self> {
+        let (box_ident, is_send) = parse_boxable_derive(meta, "IntoIterator", "iterators")?;
         Ok(Self { box_ident, is_send })
     }
 }
 
-impl FromMeta for IntoFutureConfig {
-    fn from_meta(meta: &syn::Meta) -> Result<Self> {
-        let meta = match meta {
-            syn::Meta::List(meta) => meta,
-            _ => bail!(meta, "expected an attribute of form `IntoFuture(Box, ...)`"),
-        };
+/// Parses the derive of the form `Trait` or `Trait(Box, ...)`. Returns the
+/// `Box` identifier if it's present and whether the `Send` bound is required.
+fn parse_boxable_derive(
+    meta: &syn::Meta,
+    trait_name: &str,
+    kind: &str,
+) -> Result<(Option<syn::Ident>, bool)> {
+    let meta = match meta {
+        syn::Meta::Path(_) => return Ok((None, false)),
+        syn::Meta::List(meta) => meta,
+        syn::Meta::NameValue(_) => bail!(
+            meta,
+            "expected an attribute of form `{trait_name}` or `{trait_name}(Box, ...)`"
+        ),
+    };
+
+    meta.require_parens_delim()?;
+
+    let parser = |input: syn::parse::ParseStream<'_>| parse_box_args(input, kind);
+    let (box_ident, is_send) = parser.parse2(meta.tokens.clone())?;
+
+    Ok((Some(box_ident), is_send))
+}
 
-        meta.require_parens_delim()?;
+/// Parses the arguments of the form `Box` or `Box, ?Send`. Returns the `Box`
+/// identifier and whether the `Send` bound is required.
+fn parse_box_args(
+    input: syn::parse::ParseStream<'_>,
+    kind: &str,
+) -> syn::Result<(syn::Ident, bool)> {
+    // Parse "Box" as the required first argument.
+    let box_ident: syn::Ident = input.parse()?;
+    if box_ident != "Box" {
+        return Err(syn::Error::new(
+            box_ident.span(),
+            format!("expected `Box` as the first argument, only boxed {kind} are supported"),
+        ));
+    }
 
-        let me = syn::parse2(meta.tokens.clone())?;
+    // Check for optional ", ?Send" part.
+    let is_send = if input.peek(syn::Token![,]) {
+        input.parse::<syn::Token![,]>()?;
+
+        // Parse "?Send" as a single unit.
+        if input.peek(syn::Token![?]) {
+            input.parse::<syn::Token![?]>()?;
+            let send_ident: syn::Ident = input.parse()?;
+            if send_ident != "Send" {
+                return Err(syn::Error::new(
+                    send_ident.span(),
+                    "expected `Send` after ?",
+                ));
+            }
+            false
+        } else {
+            return Err(input.error("expected `?Send` as the second argument"));
+        }
+    } else {
+        true
+    };
 
-        Ok(me)
+    // Ensure no trailing tokens.
+    if !input.is_empty() {
+        return Err(input.error("unexpected tokens after arguments"));
     }
+
+    Ok((box_ident, is_send))
 }
 
 impl FromMeta for DeriveConfig {
@@ -1,34 +1,22 @@
+use crate::builder::builder_gen::member::{GetterConfig, SettersConfig, WithConfig};
+use crate::parsing::{SpannedKey, TypePattern};
 use crate::util::prelude::*;
 use darling::util::Flag;
 use darling::FromMeta;
 use syn::parse::Parse;
 use syn::spanned::Spanned;
-use syn::visit::Visit;
 
 #[derive(Debug)]
 pub(crate) struct OnConfig {
-    pub(crate) type_pattern: syn::Type,
+    pub(crate) type_pattern: TypePattern,
+    pub(crate) default: Option<SpannedKey<Option<syn::Expr>>>,
+    pub(crate) flag: Flag,
+    pub(crate) getter: Option<SpannedKey<GetterConfig>>,
     pub(crate) into: Flag,
     pub(crate) overwritable: Flag,
     pub(crate) required: Flag,
-    pub(crate) setters: OnSettersConfig,
-}
-
-#[derive(Debug, Default, FromMeta)]
-pub(crate) struct OnSettersConfig {
-    #[darling(default, with = crate::parsing::parse_non_empty_paren_meta_list)]
-    pub(crate) doc: OnSettersDocConfig,
-}
-
-#[derive(Debug, Default, FromMeta)]
-pub(crate) struct OnSettersDocConfig {
-    #[darling(default, with = crate::parsing::parse_non_empty_paren_meta_list)]
-    pub(crate) default: OnSettersDocDefaultConfig,
-}
-
-#[derive(Debug, Default, FromMeta)]
-pub(crate) struct OnSettersDocDefaultConfig {
-    pub(crate) skip: Flag,
+    pub(crate) setters: Option<SettersConfig>,
+    pub(crate) with: Option<SpannedKey<WithConfig>>,
 }
 
 impl Parse for OnConfig {
@@ -40,12 +28,18 @@ impl Parse for OnConfig {
 
         #[derive(FromMeta)]
         struct Parsed {
+            #[darling(with = crate::parsing::parse_optional_expr, map = Some)]
+            default: Option<SpannedKey<Option<syn::Expr>>>,
+            flag: Flag,
+            getter: Option<SpannedKey<GetterConfig>>,
             into: Flag,
             overwritable: Flag,
             required: Flag,
 
-            #[darling(default, with = crate::parsing::parse_non_empty_paren_meta_list)]
-            setters: OnSettersConfig,
+            #[darling(with = crate::parsing::parse_non_empty_paren_meta_list)]
+            setters: Option<SettersConfig>,
+
+            with: Option<SpannedKey<WithConfig>>,
         }
 
         if rest.is_empty() {
@@ -73,42 +67,61 @@ impl Parse for OnConfig {
             ));
         }
 
-        struct FindAttr {
-            attr: Option<Span>,
-        }
-
-        impl Visit<'_> for FindAttr {
-            fn visit_attribute(&mut self, attr: &'_ syn::Attribute) {
-                self.attr.get_or_insert_with(|| attr.span());
-            }
+        if let (Some(with), true) = (&parsed.with, parsed.into.is_present()) {
+            return Err(syn::Error::new(
+                with.key.span(),
+                "`with` can't be specified together with `into` in the same \
+                `on(type_pattern, ...)` clause",
+            ));
         }
 
-        let mut find_attr = FindAttr { attr: None };
-        find_attr.visit_type(&type_pattern);
-
-        if let Some(attr) = find_attr.attr {
+        // Names must be unique, so they can't be assigned to all members
+        // matching the type pattern at once
+        let setters = parsed.setters.as_ref();
+        let names = [
+            parsed
+                .getter
+                .as_ref()
+                .and_then(|getter| getter.name.as_ref()),
+            setters.and_then(|setters| setters.name.as_ref()),
+            setters
+                .and_then(|setters| setters.fns.some_fn.as_ref())
+                .and_then(|some_fn| some_fn.name.as_ref()),
+            setters
+                .and_then(|setters| setters.fns.option_fn.as_ref())
+                .and_then(|option_fn| option_fn.name.as_ref()),
+        ];
+
+        if let Some(name) = names.into_iter().flatten().next() {
             return Err(syn::Error::new(
-                attr,
-                "nested attributes are not allowed in the type pattern of \
-                #[builder(on(type_pattern, ...))]",
+                name.key.span(),
+                "`name` can't be specified in `on(type_pattern, ...)`, because \
+                it would assign the same name to all members matching the \
+                type pattern; specify the name on the member itself instead",
             ));
         }
 
-        // The validation is done in the process of matching the types. To make
-        // sure that matching traverses the full pattern we match it with itself.
-        let type_pattern_matches_itself = type_pattern.matches(&type_pattern)?;
-
-        assert!(
-            type_pattern_matches_itself,
-            "BUG: the type pattern does not match itself: {type_pattern:#?}"
-        );
+        let Parsed {
+            default,
+            flag,
+            getter,
+            into,
+            overwritable,
+            required,
+            setters,
+            with,
+        } = parsed;
 
         Ok(Self {
             type_pattern,
-            into: parsed.into,
-            overwritable: parsed.overwritable,
-            required: parsed.required,
-            setters: parsed.setters,
+            default,
+            flag,
+            getter,
+            into,
+            overwritable,
+            required,
+            setters,
+            with,
         })
     }
 }
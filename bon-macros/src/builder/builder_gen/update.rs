@@ -0,0 +1,290 @@
+use super::builder_decl::private_field_attrs;
+use super::member::Member;
+use super::models::Update;
+use super::setters::{SettersCtx, SettersTarget};
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+
+impl BuilderGenCtx {
+    fn update_struct_ident(&self) -> Option<&syn::Ident> {
+        self.update.as_ref()?;
+
+        let self_ty = &self.assoc_method_ctx.as_ref()?.self_ty;
+        Some(&self_ty.as_path_no_qself()?.segments.last()?.ident)
+    }
+
+    /// Name of the type returned by the `update()` method. It's derived from
+    /// the name of the struct e.g. `ExampleUpdate` for `Example`.
+    fn update_ident(&self) -> Option<syn::Ident> {
+        let struct_ident = self.update_struct_ident()?;
+        Some(format_ident!("{}Update", struct_ident.raw_name()))
+    }
+
+    fn update_lifetime(&self) -> syn::Lifetime {
+        let mut lifetime = self.namespace.unique_lifetime("target".to_owned());
+
+        // `syn::Lifetime::new` requires the string to start with the `'` character
+        lifetime.insert(0, '\'');
+
+        syn::Lifetime::new(&lifetime, Span::call_site())
+    }
+
+    /// Generates the `update()` method for the struct if `#[builder(update)]`
+    /// is enabled. It's placed in the same impl block as the starting function.
+    pub(crate) fn update_method(&self) -> Option<TokenStream> {
+        let update_ident = self.update_ident()?;
+
+        let vis = &self.builder_type.vis;
+        let generic_args = &self.generics.args;
+        let named_members = self
+            .named_members()
+            .map(|_| quote!(::core::option::Option::None));
+
+        let docs = format!(
+            "Creates an [`{update_ident}`] that updates the members of this value in place.\n\n\
+            Only the members that have their setters called are overwritten when \
+            [`{update_ident}::apply()`] is called."
+        );
+
+        Some(quote! {
+            #[doc = #docs]
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+            )]
+            #vis fn update(&mut self) -> #update_ident<'_, #(#generic_args,)*> {
+                #update_ident {
+                    __unsafe_private_target: self,
+                    __unsafe_private_named: ( #( #named_members, )* ),
+                }
+            }
+        })
+    }
+
+    /// Generates the declaration of the type returned by the `update()` method
+    /// and its setters that share the generation logic with the builder.
+    pub(super) fn update_items(&self) -> Result<Option<TokenStream>> {
+        let (update, struct_ident, update_ident) = match (
+            &self.update,
+            self.update_struct_ident(),
+            self.update_ident(),
+        ) {
+            (Some(update), Some(struct_ident), Some(update_ident)) => {
+                (update, struct_ident, update_ident)
+            }
+            _ => return Ok(None),
+        };
+
+        let self_ty = self
+            .assoc_method_ctx
+            .as_ref()
+            .map(|ctx| &ctx.self_ty)
+            .expect("BUG: update is only supported for structs");
+
+        let vis = &self.builder_type.vis;
+        let lifetime = self.update_lifetime();
+        let generics_decl_with_defaults = &self.generics.decl_with_defaults;
+        let generics_decl = &self.generics.decl_without_defaults;
+        let generic_args = &self.generics.args;
+        let where_clause = &self.generics.where_clause;
+        let private_field_attrs = private_field_attrs();
+        let allows = super::allow_warnings_on_member_types();
+
+        let named_members_types = self.named_members().map(|member| &member.ty.norm);
+
+        let setters = self
+            .named_members()
+            .map(|member| SettersCtx::new(self, member, SettersTarget::Update).setter_methods())
+            .collect::<Result<Vec<_>>>()?;
+
+        let apply = self.update_apply_method(update);
+
+        let docs = format!(
+            "Updates the members of an existing value of [`{}`] in place.\n\n\
+            Use the setters to assign new values to the members, and then call \
+            [`apply()`](Self::apply()) to write them into the value. The members \
+            that have no setters called stay unchanged.",
+            struct_ident.raw_name(),
+        );
+
+        Ok(Some(quote! {
+            #[must_use = "the update does nothing until you call `apply()` on it"]
+            #[doc = #docs]
+            #allows
+            #[allow(
+                // We use `__private` prefix for all fields intentionally to hide them
+                clippy::struct_field_names,
+                clippy::type_complexity
+            )]
+            #vis struct #update_ident<#lifetime, #(#generics_decl_with_defaults,)*>
+            #where_clause
+            {
+                #private_field_attrs
+                __unsafe_private_target: &#lifetime mut #self_ty,
+
+                #private_field_attrs
+                __unsafe_private_named: (
+                    #(
+                        ::core::option::Option<#named_members_types>,
+                    )*
+                ),
+            }
+
+            #allows
+            // Ignore dead code warnings because some setter methods may
+            // not be used
+            #[allow(dead_code)]
+            #[automatically_derived]
+            impl<#lifetime, #(#generics_decl,)*> #update_ident<#lifetime, #(#generic_args,)*>
+            #where_clause
+            {
+                #apply
+                #(#setters)*
+            }
+        }))
+    }
+
+    /// The restrictions on the setters that depend on which members were set
+    /// can't be enforced for the existing value, so they are rejected.
+    pub(super) fn validate_update(&self) -> Result {
+        if self.update.is_none() {
+            return Ok(());
+        }
+
+        for member in self.named_members() {
+            let config = &member.config;
+
+            let restrictions = [
+                config.group.as_ref().map(|group| (group.span(), "group")),
+                config
+                    .requires
+                    .first()
+                    .map(|name| (name.span(), "requires")),
+                config
+                    .conflicts_with
+                    .first()
+                    .map(|name| (name.span(), "conflicts_with")),
+                config
+                    .stage
+                    .as_ref()
+                    .map(|stage| (stage.key.span(), "stage")),
+            ];
+
+            if let Some((span, name)) = restrictions.into_iter().flatten().next() {
+                bail!(
+                    &span,
+                    "`{name}` can't be used together with `#[builder(update)]`; the \
+                    setters of the update can be called in any order, and it's unknown \
+                    which members of the existing value were set, so this restriction \
+                    can't be enforced",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Place expression for the field of the updated value that stores the member
+    fn update_target_field(update: &Update, position: usize, member: &Member) -> TokenStream {
+        let field = if update.is_tuple {
+            syn::Index::from(position).to_token_stream()
+        } else {
+            member.orig_ident().to_token_stream()
+        };
+
+        quote!(self.__unsafe_private_target.#field)
+    }
+
+    fn update_apply_method(&self, update: &Update) -> TokenStream {
+        let vis = &self.builder_type.vis;
+
+        let members_vars_decls = self.named_members().map(|member| {
+            let index = &member.index;
+            let var = &member.name.orig;
+            let ty = &member.ty.norm;
+
+            let validation = self.member_validation(member);
+
+            quote! {
+                let #var = match self.__unsafe_private_named.#index {
+                    ::core::option::Option::Some(value) => {
+                        let #var: #ty = value;
+                        #validation
+                        ::core::option::Option::Some(#var)
+                    }
+                    ::core::option::Option::None => ::core::option::Option::None,
+                };
+            }
+        });
+
+        // The cross-member validation sees the new values of the members that
+        // were set and the current values of the rest of the members.
+        let finish_fn_validation = {
+            let args = self.members.iter().enumerate().map(|(position, member)| {
+                let field = Self::update_target_field(update, position, member);
+
+                let member = match member.as_named() {
+                    Some(member) => member,
+                    None => return quote!(&#field),
+                };
+
+                let var = &member.name.orig;
+
+                quote! {
+                    ::core::option::Option::unwrap_or(
+                        ::core::option::Option::as_ref(&#var),
+                        &#field,
+                    )
+                }
+            });
+
+            self.finish_fn_validation_with_args(args)
+        };
+
+        // The values are written only after all of them pass the validation
+        // so that the value is never left partially updated.
+        let writes = self
+            .members
+            .iter()
+            .enumerate()
+            .filter_map(|(position, member)| {
+                let var = &member.as_named()?.name.orig;
+                let field = Self::update_target_field(update, position, member);
+
+                Some(quote! {
+                    if let ::core::option::Option::Some(value) = #var {
+                        #field = value;
+                    }
+                })
+            });
+
+        let is_fallible =
+            self.finish_fn.validate.is_some() || self.validation_error_ident().is_some();
+
+        let (output, ok) = if is_fallible {
+            let err_ty = self.finish_fn_error_ty();
+            (
+                quote!(-> ::core::result::Result<(), #err_ty>),
+                Some(quote!(::core::result::Result::Ok(()))),
+            )
+        } else {
+            (quote!(), None)
+        };
+
+        quote! {
+            /// Writes the values of the members that were set into the updated value.
+            #[inline(always)]
+            #[allow(
+                // This is intentional. We want the builder syntax to compile away
+                clippy::inline_always,
+            )]
+            #vis fn apply(self) #output {
+                #( #members_vars_decls )*
+                #finish_fn_validation
+                #( #writes )*
+                #ok
+            }
+        }
+    }
+}
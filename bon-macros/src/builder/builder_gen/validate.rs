@@ -0,0 +1,380 @@
+use super::member::{NamedMember, ValidateClosure};
+use super::top_level_config::FinishFnValidateFn;
+use super::BuilderGenCtx;
+use crate::util::prelude::*;
+use syn::spanned::Spanned;
+
+impl BuilderGenCtx {
+    fn validated_members(&self) -> impl Iterator<Item = (&NamedMember, &ValidateClosure)> {
+        self.named_members().filter_map(|member| {
+            let validate = member.config.validate.as_ref()?;
+            Some((member, &validate.value))
+        })
+    }
+
+    /// Name of the error enum returned by the finishing function if any of
+    /// the members have a `#[builder(validate)]` attribute.
+    pub(super) fn validation_error_ident(&self) -> Option<syn::Ident> {
+        self.validated_members().next()?;
+
+        let builder_ident = &self.builder_type.ident;
+        Some(format_ident!("{}Error", builder_ident.raw_name()))
+    }
+
+    pub(super) fn validation_error_decl(&self) -> Option<TokenStream> {
+        let error_ident = self.validation_error_ident()?;
+
+        let vis = &self.builder_type.vis;
+        let builder_ident = &self.builder_type.ident;
+        let finish_fn_ident = &self.finish_fn.ident;
+
+        let docs = format!(
+            "Error returned by [`{builder_ident}::{finish_fn_ident}()`] \
+            if any of the members fail their validation",
+        );
+
+        let variants = self.validated_members().map(|(member, validate)| {
+            let variant = &member.name.pascal;
+            let docs = format!(
+                "The value of the member `{}` failed the validation",
+                member.name.snake_raw_str
+            );
+
+            let payload = validate.output.as_ref().map(|output| {
+                let err_ty = &output.err_ty;
+                quote!((#err_ty))
+            });
+
+            quote! {
+                #[doc = #docs]
+                #variant #payload
+            }
+        });
+
+        let display_arms = self.validated_members().map(|(member, validate)| {
+            let variant = &member.name.pascal;
+            let message = format!(
+                "invalid value for the member `{}`",
+                member.name.snake_raw_str
+            );
+
+            if validate.output.is_none() {
+                return quote! {
+                    Self::#variant => f.write_str(#message)
+                };
+            }
+
+            quote! {
+                Self::#variant(err) => ::core::write!(f, "{}: {}", #message, err)
+            }
+        });
+
+        // Without the type state, the finishing function also fails if any
+        // of the required members weren't set
+        let missing_members = (!self.typestate).then(|| {
+            let bon = &self.bon;
+            let variant = quote! {
+                /// Some of the required members were not set
+                MissingMembers(#bon::MissingMembers)
+            };
+            let display_arm = quote! {
+                Self::MissingMembers(err) => ::core::fmt::Display::fmt(err, f)
+            };
+            (variant, display_arm)
+        });
+
+        let variants = variants.chain(missing_members.as_ref().map(|(variant, _)| variant.clone()));
+        let display_arms = display_arms.chain(missing_members.as_ref().map(|(_, arm)| arm.clone()));
+
+        let std_error_impl = cfg!(feature = "std").then(|| {
+            quote! {
+                #[automatically_derived]
+                impl ::std::error::Error for #error_ident {}
+            }
+        });
+
+        Some(quote! {
+            #[doc = #docs]
+            #[derive(Debug)]
+            #vis enum #error_ident {
+                #( #variants, )*
+            }
+
+            #[automatically_derived]
+            impl ::core::fmt::Display for #error_ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        #( #display_arms, )*
+                    }
+                }
+            }
+
+            #std_error_impl
+        })
+    }
+
+    /// The error type of the finishing function if it's fallible due to validation.
+    ///
+    /// If `#[builder(finish_fn(validate))]` is specified, then its error type is
+    /// used, and the errors of members' validations are converted into it via
+    /// the [`From`] trait. Otherwise, the generated error enum for members'
+    /// validations is used. If there are no validations, but the builder has
+    /// no type state, then the error is `bon::MissingMembers`.
+    pub(super) fn finish_fn_error_ty(&self) -> Option<TokenStream> {
+        if let Some(validate) = &self.finish_fn.validate {
+            return Some(validate.err_ty.to_token_stream());
+        }
+
+        if let Some(error_ident) = self.validation_error_ident() {
+            return Some(error_ident.into_token_stream());
+        }
+
+        // Without the type state, the required members are checked at runtime
+        (!self.typestate).then(|| {
+            let bon = &self.bon;
+            quote!(#bon::MissingMembers)
+        })
+    }
+
+    /// The finishing function returns a `Result` with the validation error
+    /// if any of the validation attributes are specified.
+    pub(super) fn validated_finish_fn_output(&self) -> Option<syn::ReturnType> {
+        let error_ty = self.finish_fn_error_ty()?;
+
+        let output_ty = match &self.finish_fn.output {
+            syn::ReturnType::Default => quote!(()),
+            syn::ReturnType::Type(_, output_ty) => output_ty.to_token_stream(),
+        };
+
+        Some(syn::parse_quote! {
+            -> ::core::result::Result<#output_ty, #error_ty>
+        })
+    }
+
+    pub(super) fn validate_finish_fn_validate_config(&self) -> Result {
+        let closure = match self.finish_fn.validate.as_ref().map(|config| &config.func) {
+            Some(FinishFnValidateFn::Closure(closure)) => closure,
+            _ => return Ok(()),
+        };
+
+        if closure.inputs.len() == self.members.len() {
+            return Ok(());
+        }
+
+        let members = self
+            .members
+            .iter()
+            .map(|member| format!("`{}`", member.orig_ident()))
+            .join(", ");
+
+        bail!(
+            &closure.body,
+            "expected {} input parameters in the validation closure, \
+            one for a reference to the value of each member in the order \
+            of their declaration: {members}",
+            self.members.len(),
+        );
+    }
+
+    /// Generates the code that runs the `#[builder(finish_fn(validate))]`
+    /// function with the references to the final values of all members.
+    pub(super) fn finish_fn_validation(&self) -> Option<TokenStream> {
+        let args = self.members.iter().map(|member| {
+            let var = member.orig_ident();
+            quote!(&#var)
+        });
+
+        self.finish_fn_validation_with_args(args)
+    }
+
+    /// Same as [`Self::finish_fn_validation`], but the references to the values
+    /// of the members are passed to the validation function via the given
+    /// expressions in the order of the members' declaration.
+    pub(super) fn finish_fn_validation_with_args(
+        &self,
+        args: impl IntoIterator<Item = TokenStream>,
+    ) -> Option<TokenStream> {
+        let validate = self.finish_fn.validate.as_ref()?;
+        let args = args.into_iter().collect::<Vec<_>>();
+
+        let span = match &validate.func {
+            FinishFnValidateFn::Path(path) => path.span(),
+            FinishFnValidateFn::Closure(closure) => closure.body.span(),
+        };
+
+        let check = match &validate.func {
+            FinishFnValidateFn::Path(path) => {
+                quote! {
+                    #path(#( #args ),*)
+                }
+            }
+            FinishFnValidateFn::Closure(closure) => {
+                let pats = closure.inputs.iter().map(|input| &input.pat);
+                let tys = closure
+                    .inputs
+                    .iter()
+                    .zip(&self.members)
+                    .map(|(input, member)| {
+                        input
+                            .ty
+                            .as_deref()
+                            .map(ToTokens::to_token_stream)
+                            .unwrap_or_else(|| {
+                                let ty = member.norm_ty();
+                                quote!(&#ty)
+                            })
+                    });
+
+                let output = &closure.output;
+                let body = &closure.body;
+
+                // See the comment on the same special case for `const` in
+                // the `member_validation()` method below.
+                if self.const_.is_some() {
+                    let output_ty = match output {
+                        syn::ReturnType::Type(_, ty) => Some(quote!(: #ty)),
+                        syn::ReturnType::Default => None,
+                    };
+
+                    quote! {{
+                        let (#( #pats, )*): (#( #tys, )*) = (#( #args, )*);
+                        let check #output_ty = #body;
+                        check
+                    }}
+                } else {
+                    // Avoid wrapping the body in a block if it's already a block.
+                    let body = if matches!(body.as_ref(), syn::Expr::Block(_)) {
+                        body.to_token_stream()
+                    } else {
+                        quote!({ #body })
+                    };
+
+                    quote! {
+                        (|#( #pats: #tys ),*| #output #body)(#( #args ),*)
+                    }
+                }
+            }
+        };
+
+        // Point the type errors related to the mismatching error types at
+        // the validation function instead of the macro invocation.
+        let err = quote_spanned!(span=> ::core::result::Result::Err(err));
+
+        Some(quote! {
+            if let ::core::result::Result::Err(err) = #check {
+                return #err;
+            }
+        })
+    }
+
+    /// Generates the code that checks the final value of the member stored in
+    /// the variable named after the member and returns an error if it's invalid.
+    pub(super) fn member_validation(&self, member: &NamedMember) -> Option<TokenStream> {
+        let validate = &member.config.validate.as_ref()?.value;
+        let error_ident = self.validation_error_ident()?;
+
+        let ValidateClosure {
+            pat,
+            ty,
+            body,
+            output,
+        } = validate;
+
+        let underlying_ty = member.underlying_norm_ty();
+        let ty = ty
+            .as_deref()
+            .map(ToTokens::to_token_stream)
+            .unwrap_or_else(|| quote!(&#underlying_ty));
+
+        let var = &member.name.orig;
+        let variant = &member.name.pascal;
+
+        // If there is a custom error type for the finishing function, then
+        // the members' validation errors are converted into it.
+        let convert = |err: TokenStream| {
+            if self.finish_fn.validate.is_some() {
+                quote!(::core::convert::From::from(#err))
+            } else {
+                err
+            }
+        };
+
+        let err_with_payload = convert(quote!(#error_ident::#variant(err)));
+        let err_without_payload = convert(quote!(#error_ident::#variant));
+
+        let (output, valid, on_invalid) = match output {
+            Some(output) => {
+                let result_ty = &output.result_ty;
+                (
+                    result_ty.to_token_stream(),
+                    quote!(::core::result::Result::Ok(())),
+                    quote! {
+                        if let ::core::result::Result::Err(err) = check {
+                            return ::core::result::Result::Err(#err_with_payload);
+                        }
+                    },
+                )
+            }
+            None => (
+                quote!(bool),
+                quote!(true),
+                quote! {
+                    if !check {
+                        return ::core::result::Result::Err(#err_without_payload);
+                    }
+                },
+            ),
+        };
+
+        // Closures aren't supported in `const` contexts at the time of this writing
+        // (Rust 1.86.0), so we don't wrap the body in a closure but we require the
+        // expression to be simple so that it doesn't break out of the surrounding scope.
+        // Search for `require_embeddable_const_expr` for more.
+        let check = if self.const_.is_some() {
+            if member.is_special_option_ty() {
+                quote! {
+                    match &#var {
+                        ::core::option::Option::Some(#pat) => {
+                            let #pat: #ty = #pat;
+                            #body
+                        }
+                        ::core::option::Option::None => #valid,
+                    }
+                }
+            } else {
+                quote! {{
+                    let #pat: #ty = &#var;
+                    #body
+                }}
+            }
+        } else {
+            // Avoid wrapping the body in a block if it's already a block.
+            let body = if matches!(body.as_ref(), syn::Expr::Block(_)) {
+                body.to_token_stream()
+            } else {
+                quote!({ #body })
+            };
+
+            let closure = quote!(|#pat: #ty| -> #output #body);
+
+            if member.is_special_option_ty() {
+                quote! {
+                    ::core::option::Option::map_or(
+                        ::core::option::Option::as_ref(&#var),
+                        #valid,
+                        #closure,
+                    )
+                }
+            } else {
+                quote! {
+                    (#closure)(&#var)
+                }
+            }
+        };
+
+        Some(quote! {{
+            let check: #output = #check;
+            #on_invalid
+        }})
+    }
+}
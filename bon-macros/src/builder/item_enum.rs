@@ -0,0 +1,93 @@
+use super::builder_gen::input_struct::{self, StructInputCtx};
+use super::builder_gen::MacroOutput;
+use crate::util::prelude::*;
+
+pub(crate) fn generate(orig_enum: &syn::ItemEnum) -> Result<TokenStream> {
+    let enum_config = input_struct::parse_top_level_config(&orig_enum.attrs)?;
+
+    // Every variant gets its own builder, so the config at the enum level must
+    // not assign the same names to all of them.
+    let name_overrides = [
+        ("start_fn", &enum_config.start_fn.name),
+        ("builder_type", &enum_config.builder_type.name),
+        ("state_mod", &enum_config.state_mod.name),
+    ];
+
+    for (attr, name) in name_overrides {
+        if let Some(name) = name {
+            bail!(
+                &name.key,
+                "`{attr}` name can't be overridden at the enum level because every \
+                variant gets its own builder; specify `#[builder({attr} = ...)]` \
+                on the variant instead",
+            );
+        }
+    }
+
+    for variant in &orig_enum.variants {
+        if matches!(variant.fields, syn::Fields::Named(_)) {
+            continue;
+        }
+
+        let has_builder_attrs = variant
+            .attrs
+            .iter()
+            .chain(variant.fields.iter().flat_map(|field| &field.attrs))
+            .any(|attr| attr.path().is_ident("builder"));
+
+        if has_builder_attrs {
+            bail!(
+                &variant.ident,
+                "`#[builder(...)]` attributes are allowed only on enum variants with \
+                named fields and their fields; no builder is generated for tuple \
+                and unit variants, so remove the attributes from this variant",
+            );
+        }
+    }
+
+    let variants = orig_enum
+        .variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, syn::Fields::Named(_)));
+
+    let outputs = variants
+        .map(|variant| {
+            StructInputCtx::new_for_variant(orig_enum, variant)?
+                .into_builder_gen_ctx()?
+                .output()
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if outputs.is_empty() {
+        bail!(
+            &orig_enum.ident,
+            "`#[derive(bon::Builder)]` generates builders only for enum variants \
+            with named fields, but this enum has no such variants",
+        );
+    }
+
+    let (generics_decl, generic_args, where_clause) = orig_enum.generics.split_for_impl();
+    let enum_ident = &orig_enum.ident;
+
+    let start_fns = outputs.iter().map(|output| {
+        let MacroOutput { start_fn, .. } = output;
+        let mut start_fn = start_fn.clone();
+
+        // The generics are already declared on the impl block
+        start_fn.sig.generics = syn::Generics::default();
+        start_fn
+    });
+
+    let other_items = outputs.iter().map(|output| &output.other_items);
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #generics_decl #enum_ident #generic_args
+            #where_clause
+        {
+            #( #start_fns )*
+        }
+
+        #( #other_items )*
+    })
+}
@@ -25,8 +25,15 @@ pub(crate) fn generate(
     let mut namespace = GenericsNamespace::default();
     namespace.visit_item_impl(&orig_impl_block);
 
-    if let Some((_, trait_path, _)) = &orig_impl_block.trait_ {
-        bail!(trait_path, "Impls of traits are not supported yet");
+    if let Some((Some(bang), _, _)) = &orig_impl_block.trait_ {
+        bail!(
+            bang,
+            "negative trait impls can't contain #[builder] functions"
+        );
+    }
+
+    if orig_impl_block.trait_.is_some() {
+        validate_trait_impl_generics(&orig_impl_block)?;
     }
 
     let (builder_fns, other_items): (Vec<_>, Vec<_>) =
@@ -74,14 +81,22 @@ pub(crate) fn generate(
     // for `Self` mentions, because they aren't removed from the generated code output
     let mut norm_selfful_impl_block = norm_impl_block.clone();
 
+    let trait_path = norm_impl_block
+        .trait_
+        .as_ref()
+        .map(|(_, trait_path, _)| trait_path.clone());
+
     crate::normalization::NormalizeSelfTy {
         self_ty: &norm_impl_block.self_ty.clone(),
+        trait_path: trait_path.as_ref(),
     }
     .visit_item_impl_mut(&mut norm_impl_block);
 
     let impl_ctx = Rc::new(ImplCtx {
         self_ty: norm_impl_block.self_ty,
         generics: norm_impl_block.generics,
+        trait_path: norm_impl_block.trait_.map(|(_, trait_path, _)| trait_path),
+        trait_def_ident: None,
         allow_attrs: norm_impl_block
             .attrs
             .iter()
@@ -141,13 +156,33 @@ pub(crate) fn generate(
         })
         .collect::<Result<Vec<_>>>()?;
 
-    let new_impl_items = outputs.iter().flat_map(|(adapted_fn, output)| {
-        let start_fn = &output.start_fn;
-        [syn::parse_quote!(#adapted_fn), syn::parse_quote!(#start_fn)]
-    });
+    let adapted_fns = outputs
+        .iter()
+        .map(|(adapted_fn, _)| syn::parse_quote!(#adapted_fn));
+
+    let start_fns = outputs
+        .iter()
+        .map(|(_, output)| -> syn::ImplItem {
+            let start_fn = &output.start_fn;
+            syn::parse_quote!(#start_fn)
+        })
+        .collect::<Vec<_>>();
 
     norm_selfful_impl_block.items = other_items;
-    norm_selfful_impl_block.items.extend(new_impl_items);
+    norm_selfful_impl_block.items.extend(adapted_fns);
+
+    // Trait impls can't contain any items other than the ones declared in the
+    // trait, so the starting functions are placed in a separate inherent impl
+    let start_fns_impl_block = if norm_selfful_impl_block.trait_.is_some() {
+        let mut impl_block = norm_selfful_impl_block.clone();
+        impl_block.trait_ = None;
+        impl_block.unsafety = None;
+        impl_block.items = start_fns;
+        Some(impl_block)
+    } else {
+        norm_selfful_impl_block.items.extend(start_fns);
+        None
+    };
 
     let other_items = outputs.iter().map(|(_, output)| &output.other_items);
 
@@ -160,10 +195,42 @@ pub(crate) fn generate(
         // See this issue for details: https://github.com/rust-lang/rust-analyzer/issues/18438
         #norm_selfful_impl_block
 
+        #start_fns_impl_block
+
         #(#other_items)*
     })
 }
 
+/// The starting functions for the methods of a trait impl are generated in a
+/// separate inherent impl block. Generic parameters that are constrained only by
+/// the trait aren't allowed in an inherent impl block, so we reject them early.
+fn validate_trait_impl_generics(impl_block: &syn::ItemImpl) -> Result {
+    let mut self_ty_namespace = GenericsNamespace::default();
+    self_ty_namespace.visit_type(&impl_block.self_ty);
+
+    let unconstrained = impl_block.generics.params.iter().find_map(|param| {
+        let ident = match param {
+            syn::GenericParam::Type(param) => &param.ident,
+            syn::GenericParam::Const(param) => &param.ident,
+            syn::GenericParam::Lifetime(_) => return None,
+        };
+
+        (!self_ty_namespace.idents.contains(&ident.to_string())).then(|| ident)
+    });
+
+    if let Some(ident) = unconstrained {
+        bail!(
+            ident,
+            "generic parameter `{ident}` isn't used in the self type of the trait impl; \
+            this isn't supported by #[bon] because the builder's starting function \
+            is generated in a separate inherent impl block where `{ident}` would be \
+            unconstrained"
+        );
+    }
+
+    Ok(())
+}
+
 fn conv_impl_item_fn_into_fn_item(func: syn::ImplItemFn) -> Result<syn::ItemFn> {
     let syn::ImplItemFn {
         attrs,
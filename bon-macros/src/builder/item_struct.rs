@@ -4,12 +4,18 @@ use crate::util::prelude::*;
 
 pub(crate) fn generate(orig_struct: syn::ItemStruct) -> Result<TokenStream> {
     let struct_ident = orig_struct.ident.clone();
-    let ctx = StructInputCtx::new(orig_struct)?;
+    let struct_vis = orig_struct.vis.clone();
+    let is_tuple = matches!(orig_struct.fields, syn::Fields::Unnamed(_));
+    let ctx = StructInputCtx::new(orig_struct)?.into_builder_gen_ctx()?;
+
+    let nested_builder_impls = ctx.nested_builder_impls(&struct_vis);
+    let to_builder_methods = ctx.to_builder_methods(is_tuple);
+    let update_method = ctx.update_method();
 
     let MacroOutput {
         mut start_fn,
         other_items,
-    } = ctx.into_builder_gen_ctx()?.output()?;
+    } = ctx.output()?;
 
     let impl_generics = std::mem::take(&mut start_fn.sig.generics);
 
@@ -21,8 +27,11 @@ pub(crate) fn generate(orig_struct: syn::ItemStruct) -> Result<TokenStream> {
             #where_clause
         {
             #start_fn
+            #to_builder_methods
+            #update_method
         }
 
         #other_items
+        #nested_builder_impls
     })
 }
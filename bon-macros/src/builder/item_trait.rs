@@ -0,0 +1,275 @@
+use super::builder_gen::input_fn::{FnInputCtx, FnInputCtxParams, ImplCtx};
+use super::builder_gen::TopLevelConfig;
+use crate::normalization::{GenericsNamespace, SyntaxVariant};
+use crate::parsing::BonCratePath;
+use crate::util::prelude::*;
+use darling::util::Flag;
+use darling::FromMeta;
+use std::rc::Rc;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+
+#[derive(FromMeta)]
+pub(crate) struct TraitInputParams {
+    /// Overrides the path to the `bon` crate. This is useful when the macro is
+    /// wrapped in another macro that also reexports `bon`.
+    #[darling(rename = "crate", default)]
+    bon: BonCratePath,
+
+    /// Generates the starting functions in a separate extension trait that is
+    /// implemented for `dyn Trait` as well. The trait must be dyn-compatible.
+    dyn_compatible: Flag,
+}
+
+// TraitInputParams will evolve in the future where we'll probably want to move from it
+#[allow(clippy::needless_pass_by_value)]
+pub(crate) fn generate(
+    trait_params: TraitInputParams,
+    mut orig_trait: syn::ItemTrait,
+) -> Result<TokenStream> {
+    let mut namespace = GenericsNamespace::default();
+    namespace.visit_item_trait(&orig_trait);
+
+    let (builder_fns, other_items): (Vec<_>, Vec<_>) =
+        orig_trait.items.into_iter().partition(|item| {
+            let fn_item = match item {
+                syn::TraitItem::Fn(fn_item) => fn_item,
+                _ => return false,
+            };
+
+            fn_item
+                .attrs
+                .iter()
+                .any(|attr| attr.path().is_ident("builder"))
+        });
+
+    if builder_fns.is_empty() {
+        bail!(
+            &Span::call_site(),
+            "there are no #[builder] functions in the trait, so there is no \
+            need for a #[bon] attribute here"
+        );
+    }
+
+    let trait_ident = &orig_trait.ident;
+    let trait_args = orig_trait
+        .generics
+        .params
+        .iter()
+        .map(syn::GenericParam::to_generic_argument);
+
+    let trait_path: syn::Path = syn::parse_quote!(#trait_ident<#(#trait_args),*>);
+
+    // The builder needs to be generic over the implementor of the trait.
+    // This type parameter stands for `Self` in the builder's code.
+    let self_param = namespace.unique_ident("Self_".to_owned());
+    let self_ty: syn::Type = syn::parse_quote!(#self_param);
+
+    let mut norm_generics = orig_trait.generics.clone();
+
+    crate::normalization::NormalizeSelfTy {
+        self_ty: &self_ty,
+        trait_path: Some(&trait_path),
+    }
+    .visit_generics_mut(&mut norm_generics);
+
+    norm_generics
+        .params
+        .push(syn::parse_quote!(#self_param: ?Sized + #trait_path));
+
+    let impl_ctx = Rc::new(ImplCtx {
+        self_ty: Box::new(self_ty.clone()),
+        generics: norm_generics,
+        trait_path: None,
+        trait_def_ident: Some(trait_ident.clone()),
+        allow_attrs: orig_trait
+            .attrs
+            .iter()
+            .filter_map(syn::Attribute::to_allow)
+            .collect(),
+    });
+
+    let outputs = builder_fns
+        .into_iter()
+        .map(|item| {
+            let orig_fn = match item {
+                syn::TraitItem::Fn(orig_fn) => orig_fn,
+                _ => unreachable!(),
+            };
+
+            let orig_fn = conv_trait_item_fn_into_fn_item(orig_fn, &orig_trait.vis)?;
+            let mut norm_fn = orig_fn.clone();
+
+            crate::normalization::NormalizeLifetimes::new(&namespace)
+                .visit_item_fn_mut(&mut norm_fn);
+
+            crate::normalization::NormalizeImplTraits::new(&namespace)
+                .visit_signature_mut(&mut norm_fn.sig);
+
+            crate::normalization::NormalizeSelfTy {
+                self_ty: &self_ty,
+                trait_path: Some(&trait_path),
+            }
+            .visit_signature_mut(&mut norm_fn.sig);
+
+            let mut config = TopLevelConfig::parse_for_fn(&orig_fn, None)?;
+
+            if let BonCratePath::Explicit(path) = config.bon {
+                bail!(
+                    &path,
+                    "`crate` parameter should be specified via `#[bon(crate = path::to::bon)]` \
+                    when trait syntax is used; no need to specify it in the method's \
+                    `#[builder]` attribute"
+                );
+            }
+
+            config.bon.clone_from(&trait_params.bon);
+
+            let fn_item = SyntaxVariant {
+                orig: orig_fn,
+                norm: norm_fn,
+            };
+
+            let ctx = FnInputCtx::new(FnInputCtxParams {
+                namespace: &namespace,
+                fn_item,
+                impl_ctx: Some(impl_ctx.clone()),
+                config,
+            })?;
+
+            let mut adapted_fn = ctx.adapted_fn()?;
+            let warnings = ctx.warnings();
+
+            let mut output = ctx.into_builder_gen_ctx()?.output()?;
+            output.other_items.extend(warnings);
+
+            // Visibility isn't allowed on trait items. The trait's visibility
+            // is used only to configure the visibility of the builder's items.
+            adapted_fn.vis = syn::Visibility::Inherited;
+
+            ReplaceSelfParam {
+                self_param: &self_param,
+            }
+            .visit_item_fn_mut(&mut output.start_fn);
+
+            Result::<_>::Ok((adapted_fn, output))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (adapted_fns, start_fns): (Vec<syn::TraitItem>, Vec<_>) = outputs
+        .iter()
+        .map(|(adapted_fn, output)| {
+            let mut start_fn = output.start_fn.clone();
+            start_fn.vis = syn::Visibility::Inherited;
+            (syn::parse_quote!(#adapted_fn), start_fn)
+        })
+        .unzip();
+
+    orig_trait.items = other_items;
+    orig_trait.items.extend(adapted_fns);
+
+    let ext_trait = if trait_params.dyn_compatible.is_present() {
+        // Methods of a trait object and inherent methods of `dyn Trait` are
+        // ambiguous if they have the same name, and a method that returns a
+        // type with `Self` isn't dyn-compatible unless `Self: Sized`. Therefore
+        // the starting functions are placed into a separate extension trait
+        // implemented for all implementors of the trait including `dyn Trait`.
+        let ext_trait_ident = format_ident!("{}BuilderExt", trait_ident.raw_name());
+        let vis = &orig_trait.vis;
+        let docs = format!("Extension trait with the builder methods for [`{trait_ident}`]");
+
+        let mut blanket_generics = orig_trait.generics.clone();
+        blanket_generics
+            .params
+            .push(syn::parse_quote!(#self_param: ?Sized + #trait_path));
+
+        let (impl_generics, _, where_clause) = blanket_generics.split_for_impl();
+        let (_, ty_generics, _) = orig_trait.generics.split_for_impl();
+        let trait_generics = &orig_trait.generics.params;
+        let trait_where_clause = &orig_trait.generics.where_clause;
+
+        Some(quote! {
+            #[doc = #docs]
+            #vis trait #ext_trait_ident<#trait_generics>: #trait_path
+            #trait_where_clause
+            {
+                #( #start_fns )*
+            }
+
+            #[automatically_derived]
+            impl #impl_generics #ext_trait_ident #ty_generics for #self_param
+            #where_clause
+            {}
+        })
+    } else {
+        orig_trait
+            .items
+            .extend(start_fns.into_iter().map(|mut start_fn| {
+                // The builder can't be created for unsized implementors of the trait.
+                // This bound also keeps the trait dyn-compatible if it was before.
+                start_fn
+                    .sig
+                    .generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(Self: Sized));
+
+                syn::parse_quote!(#start_fn)
+            }));
+
+        None
+    };
+
+    let other_items = outputs.iter().map(|(_, output)| &output.other_items);
+
+    Ok(quote! {
+        #orig_trait
+
+        #ext_trait
+
+        #(#other_items)*
+    })
+}
+
+fn conv_trait_item_fn_into_fn_item(
+    func: syn::TraitItemFn,
+    trait_vis: &syn::Visibility,
+) -> Result<syn::ItemFn> {
+    let syn::TraitItemFn {
+        attrs,
+        sig,
+        default,
+        semi_token: _,
+    } = func;
+
+    let block = default.ok_or_else(|| {
+        err!(
+            &sig.ident,
+            "#[builder] can be placed only on provided trait methods that have \
+            a default implementation; the builder for a required method would \
+            have to be generated in every impl block of the trait instead"
+        )
+    })?;
+
+    Ok(syn::ItemFn {
+        attrs,
+        // Trait methods are as visible as the trait itself
+        vis: trait_vis.clone(),
+        sig,
+        block: Box::new(block),
+    })
+}
+
+/// Replaces the synthetic type parameter that stands for the implementor of
+/// the trait with `Self` in the items placed inside of the trait definition.
+struct ReplaceSelfParam<'a> {
+    self_param: &'a syn::Ident,
+}
+
+impl VisitMut for ReplaceSelfParam<'_> {
+    fn visit_ident_mut(&mut self, ident: &mut syn::Ident) {
+        if ident == self.self_param {
+            *ident = syn::Ident::new("Self", ident.span());
+        }
+    }
+}
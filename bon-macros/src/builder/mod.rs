@@ -1,27 +1,82 @@
 mod builder_gen;
 
 pub(crate) mod item_impl;
+pub(crate) mod item_trait;
 
+mod item_enum;
 mod item_fn;
 mod item_struct;
 
-use crate::normalization::{ExpandCfg, Expansion, GenericsNamespace};
+use crate::normalization::{ExpandCfg, ExpandPresets, Expansion, GenericsNamespace};
 use crate::util;
 use crate::util::prelude::*;
 use builder_gen::TopLevelConfig;
 use syn::parse::Parser;
 use syn::visit::Visit;
 
+/// Adds the item that makes `rustc` recompile the crate when the default
+/// builder config in its manifests changes. It's added only at the entry
+/// points of the macros to avoid repeating it for every generated builder.
+pub(crate) fn with_manifests_tracking(output: TokenStream) -> TokenStream {
+    let manifests_tracking = manifests_tracking();
+
+    if manifests_tracking.is_empty() {
+        return output;
+    }
+
+    quote! {
+        #output
+        const _: () = { #manifests_tracking };
+    }
+}
+
+fn manifests_tracking() -> TokenStream {
+    #[cfg(feature = "manifest-config")]
+    let tracking = builder_gen::manifests_tracking();
+
+    #[cfg(not(feature = "manifest-config"))]
+    let tracking = TokenStream::new();
+
+    tracking
+}
+
 pub(crate) fn generate_from_derive(item: TokenStream) -> TokenStream {
-    try_generate_from_derive(item).unwrap_or_else(Error::write_errors)
+    try_generate_from_derive(TokenStream::new(), item).unwrap_or_else(Error::write_errors)
 }
 
-fn try_generate_from_derive(item: TokenStream) -> Result<TokenStream> {
-    match syn::parse2(item)? {
+/// The derive can't be invoked recursively, so to resolve the presets it
+/// uses a hidden attribute macro as the callback. This attribute consumes
+/// the item and outputs only the builder code.
+pub(crate) fn generate_from_derive_callback(params: TokenStream, item: TokenStream) -> TokenStream {
+    try_generate_from_derive(params, item).unwrap_or_else(Error::write_errors)
+}
+
+fn try_generate_from_derive(params: TokenStream, item: TokenStream) -> Result<TokenStream> {
+    let ctx = ExpandPresets {
+        current_macro: syn::parse_quote!(__::__derive_builder_callback),
+        config: params,
+        item: syn::parse2(item)?,
+    };
+
+    let input = match ctx.expand_presets()? {
+        Expansion::Expanded(input) => input,
+        Expansion::Recurse(output) => return Ok(output),
+    };
+
+    if !input.config.is_empty() {
+        bail!(
+            &input.config,
+            "BUG: unexpected parameters in the derive callback attribute"
+        );
+    }
+
+    match *input.item {
         syn::Item::Struct(item_struct) => item_struct::generate(item_struct),
+        syn::Item::Enum(item_enum) => item_enum::generate(&item_enum),
         _ => bail!(
             &Span::call_site(),
-            "only `struct` items are supported by the `#[derive(bon::Builder)]` attribute"
+            "only `struct` and `enum` items are supported by the \
+            `#[derive(bon::Builder)]` attribute"
         ),
     }
 }
@@ -47,6 +102,17 @@ fn try_generate_from_attr(params: TokenStream, item: TokenStream) -> Result<Toke
         Expansion::Recurse(output) => return Ok(output),
     };
 
+    let ctx = ExpandPresets {
+        current_macro: syn::parse_quote!(builder),
+        config: input.config,
+        item: input.item,
+    };
+
+    let input = match ctx.expand_presets()? {
+        Expansion::Expanded(input) => input,
+        Expansion::Recurse(output) => return Ok(output),
+    };
+
     let main_output = match *input.item {
         syn::Item::Fn(item_fn) => {
             let mut namespace = GenericsNamespace::default();
@@ -81,5 +147,25 @@ fn generate_completion_triggers(params: TokenStream) -> TokenStream {
         .parse2(params)
         .unwrap_or_default();
 
-    util::ide::generate_completion_triggers(meta)
+    let completion_triggers = util::ide::generate_completion_triggers(meta);
+
+    // The `#[builder]` attribute may be misplaced on a method inside of an impl
+    // block, where multiple `const _` items conflict with each other, so the
+    // manifests tracking shares the item with the completion triggers
+    let manifests_tracking = manifests_tracking();
+
+    quote! {
+        // The special `rust_analyzer` CFG is enabled only when Rust Analyzer is
+        // running its code analysis. This allows us to provide code that is
+        // useful only for Rust Analyzer for it to provide hints and completions.
+        #[allow(unexpected_cfgs)]
+        const _: () = {
+            #[cfg(rust_analyzer)]
+            {
+                #completion_triggers
+            }
+
+            #manifests_tracking
+        };
+    }
 }
@@ -121,6 +121,11 @@ pub fn builder(
 
 /// Derives a builder for the struct it's placed on.
 ///
+/// If placed on an enum, then a separate builder is generated for every
+/// variant with named fields. If placed on a tuple struct, then its fields
+/// become positional members of the starting function unless they are given
+/// a name via `#[builder(name = ...)]`.
+///
 /// ## Quick example
 ///
 /// Add a `#[derive(Builder)]` attribute to your struct to generate a `builder()` method for it.
@@ -156,7 +161,7 @@ pub fn builder(
 /// - [Attributes reference](https://bon-rs.com/reference/builder)
 #[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive_builder(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
-    builder::generate_from_derive(item.into()).into()
+    builder::with_manifests_tracking(builder::generate_from_derive(item.into())).into()
 }
 
 /// Companion macro for [`builder`]. You should place it on top of the `impl` block
@@ -165,6 +170,9 @@ pub fn derive_builder(item: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// It provides the necessary context to the [`builder`] macros on top of the functions
 /// inside of the `impl` block. You'll get compile errors without that context.
 ///
+/// It can also be placed on a trait definition to generate builders for its
+/// provided methods.
+///
 /// # Quick example
 ///
 /// ```rust ignore
@@ -220,7 +228,7 @@ pub fn bon(
     params: proc_macro::TokenStream,
     item: proc_macro::TokenStream,
 ) -> proc_macro::TokenStream {
-    bon::generate(params.into(), item.into()).into()
+    builder::with_manifests_tracking(bon::generate(params.into(), item.into())).into()
 }
 
 /// Creates any map-like collection that implements [`FromIterator<(K, V)>`].
@@ -327,3 +335,14 @@ pub fn __privatize(
 ) -> proc_macro::TokenStream {
     privatize::privatize_fn(input.into()).into()
 }
+
+// This attribute is used as a callback by `#[derive(Builder)]` to resolve
+// presets. It consumes the item and generates only the builder for it.
+#[doc(hidden)]
+#[proc_macro_attribute]
+pub fn __derive_builder_callback(
+    params: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    builder::generate_from_derive_callback(params.into(), item.into()).into()
+}
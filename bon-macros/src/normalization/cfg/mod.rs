@@ -198,6 +198,7 @@ impl ExpandCfg {
 
                 format!("impl_{self_ty}_fn_{first_fn}")
             }
+            syn::Item::Trait(item) => format!("trait_{}", item.ident.raw_name()),
             _ => bail!(&Span::call_site(), "Unsupported item type"),
         };
 
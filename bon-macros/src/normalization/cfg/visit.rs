@@ -23,6 +23,7 @@ where
         match item {
             syn::Item::Fn(fn_item) => self.visit_item_fn(fn_item),
             syn::Item::Impl(impl_item) => self.visit_item_impl(impl_item),
+            syn::Item::Trait(trait_item) => self.visit_item_trait(trait_item),
             _ => Ok(()),
         }
     }
@@ -96,4 +97,32 @@ where
             _ => Ok(true),
         })
     }
+
+    fn visit_item_trait(&mut self, trait_item: &mut syn::ItemTrait) -> Result {
+        if !self.visit(&mut trait_item.attrs)? {
+            bail!(
+                trait_item,
+                "This code should never be executed if there is a `#[cfg(...)]` attribute \
+                on the trait itself, because if that cfg evaluates to `false`, \
+                no other proc macro on the item should be called."
+            )
+        }
+
+        self.visit_generics(&mut trait_item.generics)?;
+
+        trait_item.items.try_retain_mut(|item| match item {
+            syn::TraitItem::Fn(fn_item) => self.visit_trait_item_fn(fn_item),
+            _ => Ok(true),
+        })
+    }
+
+    fn visit_trait_item_fn(&mut self, fn_item: &mut syn::TraitItemFn) -> Result<bool> {
+        if !self.visit(&mut fn_item.attrs)? {
+            return Ok(false);
+        }
+
+        self.visit_signature(&mut fn_item.sig)?;
+
+        Ok(true)
+    }
 }
@@ -2,6 +2,7 @@ mod cfg;
 mod generics_namespace;
 mod impl_traits;
 mod lifetimes;
+mod preset;
 mod self_ty;
 mod syntax_variant;
 
@@ -9,5 +10,6 @@ pub(crate) use cfg::*;
 pub(crate) use generics_namespace::*;
 pub(crate) use impl_traits::*;
 pub(crate) use lifetimes::*;
+pub(crate) use preset::*;
 pub(crate) use self_ty::*;
 pub(crate) use syntax_variant::*;
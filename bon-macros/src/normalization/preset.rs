@@ -0,0 +1,257 @@
+use super::{Expanded, Expansion};
+use crate::util::prelude::*;
+use proc_macro2::{Delimiter, Spacing, TokenTree};
+use syn::parse::{Parse, ParseStream, Parser};
+
+mod kw {
+    syn::custom_keyword!(__preset);
+}
+
+/// Expands the `preset = path` parameters in the `#[builder(...)]` configs.
+///
+/// Presets are defined with the `bon::preset!` macro, which generates a
+/// `macro_rules!` macro that holds the preset's parameters. Proc macros can't
+/// look into other items, so we use the same callback trick as for `cfg`
+/// evaluation (see `bon::__eval_cfg_callback`).
+///
+/// When the macro finds a `preset = path` parameter, it generates a call to
+/// the preset macro, passing the current macro's path, its parameters and the
+/// item. The preset macro then invokes the current macro again with the preset's
+/// parameters in the special `__preset({...})` prefix of the macro's parameters.
+/// The preset's parameters replace the `preset = path` they were resolved for.
+///
+/// The presets are resolved one by one. This way a preset may reference
+/// other presets, which are resolved in the next iterations.
+pub(crate) struct ExpandPresets {
+    /// Path to the current macro relative to the `bon` crate's root
+    pub(crate) current_macro: syn::Path,
+    pub(crate) config: TokenStream,
+    pub(crate) item: Box<syn::Item>,
+}
+
+impl ExpandPresets {
+    pub(crate) fn expand_presets(mut self) -> Result<Expansion> {
+        let resolved = syn::parse2::<ResolvedPreset>(std::mem::take(&mut self.config))?;
+        self.config = resolved.rest;
+
+        let mut configs = self.configs_mut();
+
+        if let Some(preset) = resolved.params {
+            // The resolved preset always belongs to the first reference, because
+            // the references are resolved in the order they appear in the configs.
+            let reference = find_first_reference(&configs)?;
+            let (config, index) = reference
+                .and_then(|reference| Some((configs.get_mut(reference.config)?, reference.chunk)))
+                .ok_or_else(|| {
+                    err!(
+                        &Span::call_site(),
+                        "BUG: a preset was resolved, but no `preset = ...` references were found",
+                    )
+                })?;
+
+            let chunks = split_config(std::mem::take(*config))
+                .into_iter()
+                .enumerate()
+                .map(|(i, chunk)| if i == index { preset.clone() } else { chunk });
+
+            **config = join_config(chunks);
+        }
+
+        let preset_path = match find_first_reference(&configs)? {
+            Some(reference) => reference.path,
+            None => {
+                return Ok(Expansion::Expanded(Expanded {
+                    config: self.config,
+                    item: self.item,
+                }))
+            }
+        };
+
+        let bon = find_bon_crate_path(&configs)?;
+
+        let Self {
+            current_macro,
+            config,
+            item,
+        } = self;
+
+        Ok(Expansion::Recurse(quote! {
+            #preset_path! {
+                #bon::#current_macro,
+                ( #config )
+                #item
+            }
+        }))
+    }
+
+    /// Returns the configs from the macro's parameters and all `#[builder(...)]`
+    /// attributes where the top-level config may be specified.
+    fn configs_mut(&mut self) -> Vec<&mut TokenStream> {
+        let attrs: Vec<&mut Vec<syn::Attribute>> = match &mut *self.item {
+            syn::Item::Fn(item) => vec![&mut item.attrs],
+            syn::Item::Struct(item) => vec![&mut item.attrs],
+            syn::Item::Enum(item) => std::iter::once(&mut item.attrs)
+                .chain(item.variants.iter_mut().map(|variant| &mut variant.attrs))
+                .collect(),
+            syn::Item::Impl(item) => item
+                .items
+                .iter_mut()
+                .filter_map(|item| match item {
+                    syn::ImplItem::Fn(item) => Some(&mut item.attrs),
+                    _ => None,
+                })
+                .collect(),
+            syn::Item::Trait(item) => item
+                .items
+                .iter_mut()
+                .filter_map(|item| match item {
+                    syn::TraitItem::Fn(item) => Some(&mut item.attrs),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        };
+
+        let attr_configs = attrs
+            .into_iter()
+            .flatten()
+            .filter(|attr| attr.path().is_ident("builder"))
+            .filter_map(|attr| match &mut attr.meta {
+                syn::Meta::List(meta) => Some(&mut meta.tokens),
+                _ => None,
+            });
+
+        std::iter::once(&mut self.config)
+            .chain(attr_configs)
+            .collect()
+    }
+}
+
+/// Represents the special directive inserted at the beginning of the macro
+/// parameters that has the syntax `__preset({...})`. It delivers the parameters
+/// of the resolved preset to the macro.
+struct ResolvedPreset {
+    params: Option<TokenStream>,
+    rest: TokenStream,
+}
+
+impl Parse for ResolvedPreset {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        if !input.peek(kw::__preset) {
+            return Ok(Self {
+                params: None,
+                rest: input.parse()?,
+            });
+        }
+
+        input.parse::<kw::__preset>()?;
+
+        let content;
+        syn::parenthesized!(content in input);
+
+        let params;
+        syn::braced!(params in content);
+
+        Ok(Self {
+            params: Some(params.parse()?),
+            rest: input.parse()?,
+        })
+    }
+}
+
+struct PresetReference {
+    /// Index of the config where the reference is located
+    config: usize,
+
+    /// Index of the comma-separated chunk in the config
+    chunk: usize,
+
+    path: syn::Path,
+}
+
+fn find_first_reference(configs: &[&mut TokenStream]) -> Result<Option<PresetReference>> {
+    for (config_index, config) in configs.iter().enumerate() {
+        for (chunk_index, chunk) in split_config((**config).clone()).into_iter().enumerate() {
+            if let Some(path) = parse_preset_reference(chunk)? {
+                return Ok(Some(PresetReference {
+                    config: config_index,
+                    chunk: chunk_index,
+                    path,
+                }));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn parse_preset_reference(chunk: TokenStream) -> Result<Option<syn::Path>> {
+    let is_preset = matches!(
+        chunk.clone().into_iter().next(),
+        Some(TokenTree::Ident(ident)) if ident == "preset"
+    );
+
+    if !is_preset {
+        return Ok(None);
+    }
+
+    let meta: syn::MetaNameValue = syn::parse2(chunk)?;
+
+    match &meta.value {
+        syn::Expr::Path(path) if path.qself.is_none() && path.attrs.is_empty() => {
+            Ok(Some(path.path.clone()))
+        }
+        _ => bail!(
+            &meta.value,
+            "expected a path to a preset defined with `bon::preset!` \
+            e.g. `preset = my_preset`",
+        ),
+    }
+}
+
+fn find_bon_crate_path(configs: &[&mut TokenStream]) -> Result<syn::Path> {
+    for config in configs {
+        for chunk in split_config((**config).clone()) {
+            let meta = match syn::parse2::<syn::MetaNameValue>(chunk) {
+                Ok(meta) if meta.path.is_ident("crate") => meta,
+                _ => continue,
+            };
+
+            let path = &meta.value;
+            return Ok(syn::Path::parse_mod_style.parse2(quote!(#path))?);
+        }
+    }
+
+    Ok(syn::parse_quote!(::bon))
+}
+
+/// Splits the config into chunks separated by the top-level commas
+fn split_config(config: TokenStream) -> Vec<TokenStream> {
+    let mut chunks = vec![TokenStream::new()];
+
+    for token in config {
+        match &token {
+            TokenTree::Punct(punct)
+                if punct.as_char() == ',' && punct.spacing() == Spacing::Alone =>
+            {
+                chunks.push(TokenStream::new());
+            }
+            // `None`-delimited groups may come from `macro_rules!` expansions
+            TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+                let last = chunks.last_mut().expect("chunks are never empty");
+                last.extend(group.stream());
+            }
+            _ => {
+                let last = chunks.last_mut().expect("chunks are never empty");
+                last.extend([token]);
+            }
+        }
+    }
+
+    chunks
+}
+
+fn join_config(chunks: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let chunks = chunks.into_iter().filter(|chunk| !chunk.is_empty());
+    quote!(#(#chunks),*)
+}
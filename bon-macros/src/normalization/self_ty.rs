@@ -4,6 +4,11 @@ use syn::visit_mut::VisitMut;
 
 pub(crate) struct NormalizeSelfTy<'a> {
     pub(crate) self_ty: &'a syn::Type,
+
+    /// Path to the trait if `Self` is referenced inside of a trait impl block.
+    /// Projections like `Self::Assoc` are qualified with this trait in this case
+    /// because otherwise the associated type would be ambiguous.
+    pub(crate) trait_path: Option<&'a syn::Path>,
 }
 
 impl VisitMut for NormalizeSelfTy<'_> {
@@ -46,6 +51,7 @@ impl VisitMut for NormalizeSelfTy<'_> {
     fn visit_type_path_mut(&mut self, type_path: &mut syn::TypePath) {
         syn::visit_mut::visit_type_path_mut(self, type_path);
 
+        let span = type_path.span();
         let syn::TypePath { qself, path } = type_path;
 
         let is_self_projection =
@@ -57,19 +63,25 @@ impl VisitMut for NormalizeSelfTy<'_> {
 
         // There is no `.remove()` method in `Punctuated`
         // https://github.com/dtolnay/syn/issues/1314
-        path.segments = std::mem::take(&mut path.segments)
-            .into_iter()
-            .skip(1)
-            .collect();
+        let assoc_segments = std::mem::take(&mut path.segments).into_iter().skip(1);
+
+        let (position, as_token) = match self.trait_path {
+            Some(trait_path) => {
+                path.leading_colon = trait_path.leading_colon;
+                path.segments = trait_path.segments.clone();
+                (trait_path.segments.len(), Some(syn::Token![as](span)))
+            }
+            None => (0, None),
+        };
 
-        let span = type_path.span();
+        path.segments.extend(assoc_segments);
 
         // QSelf doesn't implement `Parse` trait
-        type_path.qself = Some(syn::QSelf {
+        *qself = Some(syn::QSelf {
             lt_token: syn::Token![<](span),
             ty: Box::new(self.self_ty.clone()),
-            position: 0,
-            as_token: None,
+            position,
+            as_token,
             gt_token: syn::Token![>](span),
         });
     }
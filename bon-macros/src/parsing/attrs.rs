@@ -0,0 +1,28 @@
+use super::SpannedKey;
+use crate::util::prelude::*;
+
+/// Parses the `attrs(...)` parameter. It accepts a list of attributes without
+/// the `#[...]` wrapper that are forwarded to the generated item verbatim e.g.
+/// `attrs(derive(serde::Serialize), must_use = "...")`.
+pub(crate) fn parse_attrs(meta: &syn::Meta) -> Result<SpannedKey<Vec<syn::Attribute>>> {
+    crate::parsing::require_non_empty_paren_meta_list_or_name_value(meta)?;
+
+    let metas = super::parse_paren_meta_list_with_terminated::<syn::Meta, syn::Token![,]>(meta)?;
+
+    let attrs = metas
+        .into_iter()
+        .map(|meta| {
+            if meta.path().is_ident("doc") {
+                bail!(
+                    &meta,
+                    "doc comments can't be specified in `attrs(...)`; \
+                    use the `doc {{ ... }}` parameter instead"
+                );
+            }
+
+            Ok(syn::parse_quote!(#[#meta]))
+        })
+        .collect::<Result<_>>()?;
+
+    SpannedKey::new(meta.path(), attrs)
+}
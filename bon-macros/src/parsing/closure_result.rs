@@ -0,0 +1,93 @@
+use crate::util::prelude::*;
+
+/// The `Ok` type that is expected in the `-> *Result<Ok, Err>` return type
+/// annotation of a closure.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ClosureResultOkTy {
+    /// The `_` placeholder that marks the underlying type of a member
+    Infer,
+
+    /// The unit type `()`
+    Unit,
+}
+
+/// Parsed `-> *Result<Ok, Err>` or `-> *Result<Ok>` return type annotation
+/// of a closure.
+#[derive(Debug, Clone)]
+pub(crate) struct ClosureResultTy {
+    /// Path to the `Result` type with the generic arguments stripped
+    pub(crate) result_path: syn::Path,
+
+    /// The error type if it was specified explicitly
+    pub(crate) err_ty: Option<syn::Type>,
+}
+
+impl ClosureResultTy {
+    /// Parses the return type of a closure. The `invalid_return_type_error` is
+    /// reported with the span of the return type if it doesn't match the syntax.
+    pub(crate) fn parse(
+        ty: &syn::Type,
+        ok_ty: ClosureResultOkTy,
+        invalid_return_type_error: &str,
+    ) -> Result<Self> {
+        let err = || err!(ty, "{invalid_return_type_error}");
+
+        let ty = ty
+            .as_generic_angle_bracketed_path(|last_segment| {
+                // We allow for arbitrary `Result` type variations
+                // including custom type aliases like `ApiResult<_>`
+                last_segment.to_string().ends_with("Result")
+            })
+            .ok_or_else(err)?;
+
+        let mut args = ty.args.iter();
+
+        let is_expected_ok_ty = match (ok_ty, args.next()) {
+            (ClosureResultOkTy::Infer, Some(syn::GenericArgument::Type(syn::Type::Infer(_)))) => {
+                true
+            }
+            (
+                ClosureResultOkTy::Unit,
+                Some(syn::GenericArgument::Type(syn::Type::Tuple(tuple))),
+            ) => tuple.elems.is_empty(),
+            _ => false,
+        };
+
+        if !is_expected_ok_ty {
+            return Err(err());
+        }
+
+        let err_ty = match (args.next(), args.next()) {
+            (None, _) => None,
+            (Some(syn::GenericArgument::Type(ty)), None) => Some(ty.clone()),
+            _ => return Err(err()),
+        };
+
+        let mut result_path = ty.path.clone();
+
+        // We store the error type of the result separately.
+        // Strip the generic arguments, because we only care
+        // about the path of the `Result` in `result_path` field.
+        result_path
+            .segments
+            .last_mut()
+            .expect("BUG: segments can't be empty")
+            .arguments = syn::PathArguments::None;
+
+        Ok(Self {
+            result_path,
+            err_ty,
+        })
+    }
+
+    /// Same as [`Self::parse`], but requires the `Ok` type to be `()` and the
+    /// error type to be specified explicitly. Returns the error type.
+    pub(crate) fn parse_unit_ok_err_ty(
+        ty: &syn::Type,
+        invalid_return_type_error: &str,
+    ) -> Result<syn::Type> {
+        Self::parse(ty, ClosureResultOkTy::Unit, invalid_return_type_error)?
+            .err_ty
+            .ok_or_else(|| err!(ty, "{invalid_return_type_error}"))
+    }
+}
@@ -14,6 +14,9 @@ pub(crate) struct ItemSigConfig<N = syn::Ident> {
     pub(crate) name: Option<SpannedKey<N>>,
     pub(crate) vis: Option<SpannedKey<syn::Visibility>>,
     pub(crate) docs: Option<SpannedKey<Vec<syn::Attribute>>>,
+
+    /// Additional attributes forwarded to the item verbatim
+    pub(crate) attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
 }
 
 impl<N> Default for ItemSigConfig<N> {
@@ -22,6 +25,7 @@ impl<N> Default for ItemSigConfig<N> {
             name: None,
             vis: None,
             docs: None,
+            attrs: None,
         }
     }
 }
@@ -40,6 +44,10 @@ impl<N> ItemSigConfig<N> {
     pub(crate) fn docs(&self) -> Option<&[syn::Attribute]> {
         self.docs.as_ref().map(|docs| docs.value.as_slice())
     }
+
+    pub(crate) fn attrs(&self) -> Option<&[syn::Attribute]> {
+        self.attrs.as_ref().map(|attrs| attrs.value.as_slice())
+    }
 }
 
 pub(crate) struct ItemSigConfigParsing<'a> {
@@ -67,6 +75,7 @@ impl<'a> ItemSigConfigParsing<'a> {
                 name: Some(name),
                 vis: None,
                 docs: None,
+                attrs: None,
             });
         }
 
@@ -77,6 +86,9 @@ impl<'a> ItemSigConfigParsing<'a> {
 
             #[darling(default, with = super::parse_docs, map = Some)]
             doc: Option<SpannedKey<Vec<syn::Attribute>>>,
+
+            #[darling(default, with = super::parse_attrs, map = Some)]
+            attrs: Option<SpannedKey<Vec<syn::Attribute>>>,
         }
 
         let full: Full<N> = crate::parsing::parse_non_empty_paren_meta_list(meta)?;
@@ -91,6 +103,7 @@ impl<'a> ItemSigConfigParsing<'a> {
             name: full.name,
             vis: full.vis,
             docs: full.doc,
+            attrs: full.attrs,
         };
 
         Ok(config)
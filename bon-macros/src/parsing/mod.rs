@@ -1,16 +1,22 @@
+mod attrs;
 mod bon_crate_path;
+mod closure_result;
 mod const_;
 mod docs;
 mod item_sig;
 mod simple_closure;
 mod spanned_key;
+mod type_pattern;
 
+pub(crate) use attrs::*;
 pub(crate) use bon_crate_path::*;
+pub(crate) use closure_result::*;
 pub(crate) use const_::*;
 pub(crate) use docs::*;
 pub(crate) use item_sig::*;
 pub(crate) use simple_closure::*;
 pub(crate) use spanned_key::*;
+pub(crate) use type_pattern::*;
 
 use crate::util::prelude::*;
 use darling::FromMeta;
@@ -95,6 +101,14 @@ fn parse_path_mod_style(meta: &syn::Meta) -> Result<syn::Path> {
     Ok(expr.require_path_mod_style()?.clone())
 }
 
+pub(crate) fn parse_optional_expr(meta: &syn::Meta) -> Result<SpannedKey<Option<syn::Expr>>> {
+    match meta {
+        syn::Meta::Path(path) => SpannedKey::new(path, None),
+        syn::Meta::List(_) => Err(Error::unsupported_format("list").with_span(meta)),
+        syn::Meta::NameValue(meta) => SpannedKey::new(&meta.path, Some(meta.value.clone())),
+    }
+}
+
 pub(crate) fn reject_syntax<T: Spanned>(name: &'static str, syntax: &Option<T>) -> Result {
     if let Some(syntax) = syntax {
         bail!(syntax, "{name} is not allowed here")
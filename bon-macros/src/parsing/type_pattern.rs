@@ -0,0 +1,158 @@
+use crate::util::prelude::*;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+
+/// Type pattern used in `#[builder(on(type_pattern, ...))]`. It consists of
+/// one or more alternatives separated by `|`. The pattern matches a type if
+/// any of its alternatives matches it.
+#[derive(Debug)]
+pub(crate) struct TypePattern {
+    pub(crate) alternatives: Punctuated<TypePatternAlternative, syn::Token![|]>,
+}
+
+#[derive(Debug)]
+pub(crate) enum TypePatternAlternative {
+    /// Regular type syntax where `_` matches any type e.g. `Vec<_>`
+    Type(syn::Type),
+
+    /// `*::path::Suffix` matches any path type that ends with the given segments
+    /// e.g. `*::Duration` matches both `Duration` and `std::time::Duration`
+    PathSuffix(syn::Path),
+}
+
+impl TypePattern {
+    pub(crate) fn matches(&self, scrutinee: &syn::Type) -> Result<bool> {
+        for alternative in &self.alternatives {
+            let matched = match alternative {
+                TypePatternAlternative::Type(pattern) => scrutinee.matches(pattern)?,
+                TypePatternAlternative::PathSuffix(suffix) => {
+                    scrutinee.matches_path_suffix(suffix)?
+                }
+            };
+
+            if matched {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Regular types mentioned in the pattern. The path suffixes aren't
+    /// included, because they may not resolve to any type in the scope.
+    pub(crate) fn types(&self) -> impl Iterator<Item = &syn::Type> {
+        self.alternatives
+            .iter()
+            .filter_map(|alternative| match alternative {
+                TypePatternAlternative::Type(ty) => Some(ty),
+                TypePatternAlternative::PathSuffix(_) => None,
+            })
+    }
+
+    fn validate(&self) -> Result {
+        for alternative in &self.alternatives {
+            // The validation is done in the process of matching the types. To make
+            // sure that matching traverses the full pattern we match it with itself.
+            let (pattern_matches_itself, pattern) = match alternative {
+                TypePatternAlternative::Type(ty) => (ty.matches(ty)?, ty.to_token_stream()),
+                TypePatternAlternative::PathSuffix(suffix) => {
+                    let ty = syn::Type::Path(syn::TypePath {
+                        qself: None,
+                        path: suffix.clone(),
+                    });
+                    (ty.matches_path_suffix(suffix)?, suffix.to_token_stream())
+                }
+            };
+
+            assert!(
+                pattern_matches_itself,
+                "BUG: the type pattern does not match itself: {pattern}"
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Parse for TypePattern {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let mut alternatives = Punctuated::new();
+
+        loop {
+            alternatives.push_value(input.parse()?);
+
+            if !input.peek(syn::Token![|]) {
+                break;
+            }
+
+            alternatives.push_punct(input.parse()?);
+        }
+
+        let me = Self { alternatives };
+
+        me.validate()?;
+
+        Ok(me)
+    }
+}
+
+impl Parse for TypePatternAlternative {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        // Pointer types also start with `*`, but they are followed by `const` or `mut`
+        if !(input.peek(syn::Token![*]) && input.peek2(syn::Token![::])) {
+            let ty = input.parse()?;
+            reject_nested_attrs(&ty)?;
+            return Ok(Self::Type(ty));
+        }
+
+        input.parse::<syn::Token![*]>()?;
+        input.parse::<syn::Token![::]>()?;
+
+        let ty = input.parse()?;
+        reject_nested_attrs(&ty)?;
+
+        let suffix = match ty {
+            syn::Type::Path(syn::TypePath { qself: None, path })
+                if path.leading_colon.is_none() =>
+            {
+                path
+            }
+            ty => {
+                return Err(syn::Error::new(
+                    ty.span(),
+                    "expected a path without a leading `::` after `*::` \
+                    in the type pattern e.g. `*::Duration`",
+                ))
+            }
+        };
+
+        Ok(Self::PathSuffix(suffix))
+    }
+}
+
+fn reject_nested_attrs(ty: &syn::Type) -> syn::Result<()> {
+    struct FindAttr {
+        attr: Option<Span>,
+    }
+
+    impl Visit<'_> for FindAttr {
+        fn visit_attribute(&mut self, attr: &'_ syn::Attribute) {
+            self.attr.get_or_insert_with(|| attr.span());
+        }
+    }
+
+    let mut find_attr = FindAttr { attr: None };
+    find_attr.visit_type(ty);
+
+    if let Some(attr) = find_attr.attr {
+        return Err(syn::Error::new(
+            attr,
+            "nested attributes are not allowed in the type pattern of \
+            #[builder(on(type_pattern, ...))]",
+        ));
+    }
+
+    Ok(())
+}
@@ -170,20 +170,7 @@ pub(crate) fn generate_completion_triggers(meta: Vec<Meta>) -> TokenStream {
         ],
     );
 
-    let completion_triggers = completions.generate_completion_triggers(&bon, meta, &[]);
-
-    quote! {
-        // The special `rust_analyzer` CFG is enabled only when Rust Analyzer is
-        // running its code analysis. This allows us to provide code that is
-        // useful only for Rust Analyzer for it to provide hints and completions.
-        #[allow(unexpected_cfgs)]
-        const _: () = {
-            #[cfg(rust_analyzer)]
-            {
-                #completion_triggers
-            }
-        };
-    }
+    completions.generate_completion_triggers(&bon, meta, &[])
 }
 
 struct CompletionsSchema {
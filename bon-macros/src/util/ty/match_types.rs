@@ -25,16 +25,36 @@ fn match_paths(scrutinee: &syn::Path, pattern: &syn::Path) -> Result<bool> {
         && scrutinee
             .segments
             .iter()
-            .try_equals_with(&pattern.segments, |scrutinee, pattern| {
-                let verdict = scrutinee.ident == pattern.ident
-                    && match_path_args(&scrutinee.arguments, &pattern.arguments)?;
+            .try_equals_with(&pattern.segments, match_path_segments)?;
 
-                Ok(verdict)
-            })?;
+    Ok(verdict)
+}
+
+fn match_path_segments(scrutinee: &syn::PathSegment, pattern: &syn::PathSegment) -> Result<bool> {
+    let verdict = scrutinee.ident == pattern.ident
+        && match_path_args(&scrutinee.arguments, &pattern.arguments)?;
 
     Ok(verdict)
 }
 
+pub(crate) fn match_path_suffix(scrutinee: &syn::Type, suffix: &syn::Path) -> Result<bool> {
+    let scrutinee = match scrutinee.peel() {
+        syn::Type::Path(scrutinee) if scrutinee.qself.is_none() => &scrutinee.path,
+        _ => return Ok(false),
+    };
+
+    let skip = match scrutinee.segments.len().checked_sub(suffix.segments.len()) {
+        Some(skip) => skip,
+        None => return Ok(false),
+    };
+
+    scrutinee
+        .segments
+        .iter()
+        .skip(skip)
+        .try_equals_with(&suffix.segments, match_path_segments)
+}
+
 fn match_path_args(scrutinee: &syn::PathArguments, pattern: &syn::PathArguments) -> Result<bool> {
     use syn::PathArguments::*;
 
@@ -316,6 +336,36 @@ mod tests {
         assert_not_match_types(pq!(foo::Foo<u32>), pq!(foo::Bar<u32>));
     }
 
+    #[test]
+    fn path_suffix() {
+        #[track_caller]
+        fn assert_match(scrutinee: syn::Type, suffix: syn::Path) {
+            assert!(scrutinee.matches_path_suffix(&suffix).unwrap());
+        }
+
+        #[track_caller]
+        fn assert_not_match(scrutinee: syn::Type, suffix: syn::Path) {
+            assert!(!scrutinee.matches_path_suffix(&suffix).unwrap());
+        }
+
+        assert_match(pq!(Duration), pq!(Duration));
+        assert_match(pq!(time::Duration), pq!(Duration));
+        assert_match(pq!(std::time::Duration), pq!(Duration));
+        assert_match(pq!(::std::time::Duration), pq!(Duration));
+        assert_match(pq!(std::time::Duration), pq!(time::Duration));
+
+        assert_match(pq!(std::vec::Vec<u32>), pq!(Vec<_>));
+        assert_match(pq!(std::vec::Vec<u32>), pq!(Vec<u32>));
+
+        assert_not_match(pq!(Duration), pq!(time::Duration));
+        assert_not_match(pq!(std::time::Instant), pq!(Duration));
+        assert_not_match(pq!(std::time::Duration), pq!(std::Duration));
+        assert_not_match(pq!(std::vec::Vec<u32>), pq!(Vec<bool>));
+        assert_not_match(pq!(std::vec::Vec<u32>), pq!(Vec));
+        assert_not_match(pq!(&Duration), pq!(Duration));
+        assert_not_match(pq!(<T as Trait>::Duration), pq!(Duration));
+    }
+
     #[test]
     fn ptr() {
         assert_match_types(pq!(*const u8), pq!(*const u8));
@@ -35,6 +35,11 @@ pub(crate) trait TypeExt {
     /// Any wildcards in `Self` will not be specially handled. Only wildcards in `pattern`
     /// have semantic meaning.
     fn matches(&self, pattern: &syn::Type) -> Result<bool>;
+
+    /// Returns `true` if the given type is a path that ends with the segments
+    /// of the `suffix`. The segments are compared the same way as in [`Self::matches`].
+    /// E.g. both `Duration` and `std::time::Duration` match the suffix `Duration`.
+    fn matches_path_suffix(&self, suffix: &syn::Path) -> Result<bool>;
 }
 
 impl TypeExt for syn::Type {
@@ -105,6 +110,10 @@ impl TypeExt for syn::Type {
     fn matches(&self, pattern: &syn::Type) -> Result<bool> {
         match_types::match_types(self, pattern)
     }
+
+    fn matches_path_suffix(&self, suffix: &syn::Path) -> Result<bool> {
+        match_types::match_path_suffix(self, suffix)
+    }
 }
 
 pub(crate) struct GenericAngleBracketedPath<'a> {
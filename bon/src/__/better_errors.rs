@@ -8,7 +8,9 @@
 //! They improve the spans of error messages because compiler knows that it needs to
 //! point to the origin of the offending type (member's type T) from the turbofish
 //! syntax to where the type came from (original code written by the user).
+use super::{MemberState, UninitMember};
 use core::fmt::Debug;
+use core::hash::{Hash, Hasher};
 
 #[inline(always)]
 pub fn clone_member<T: Clone>(member: &Option<T>) -> Option<T> {
@@ -20,7 +22,33 @@ pub fn as_dyn_debug<T: Debug>(member: &T) -> &dyn Debug {
     member
 }
 
+#[inline(always)]
+#[must_use]
+pub fn eq_member<T: PartialEq>(left: Option<&T>, right: Option<&T>) -> bool {
+    left == right
+}
+
+#[inline(always)]
+pub fn assert_eq<T: Eq + ?Sized>() {}
+
+#[inline(always)]
+pub fn hash_member<T: Hash, H: Hasher>(member: Option<&T>, state: &mut H) {
+    member.hash(state);
+}
+
 #[inline(always)]
 pub fn copy_member<T: Copy>(member: &Option<T>) -> Option<T> {
     *member
 }
+
+#[inline(always)]
+pub fn clone_uninit_member<State: MemberState, T: Clone>(
+    member: &UninitMember<State, T>,
+) -> UninitMember<State, T> {
+    member.clone()
+}
+
+#[inline(always)]
+pub fn copy_ref<T: Copy>(value: &T) -> T {
+    *value
+}
@@ -15,14 +15,22 @@ pub mod ide;
 pub mod better_errors;
 
 mod cfg_eval;
+mod nested;
+mod preset;
+mod uninit_member;
 
 // This reexport is a private implementation detail and should not be used
 // directly! This reexport may change or be removed at any time between
 // patch releases. Use the export from your generated  builder's state module
 // directly instead of using this reexport from `bon::__`.
 pub use crate::builder_state::{IsSet, IsUnset};
-pub use bon_macros::__privatize;
+
+#[cfg(feature = "alloc")]
+pub use crate::missing_members::check_missing_members;
+pub use bon_macros::{__derive_builder_callback, __privatize};
+pub use nested::{FinishNested, NestedBuilder};
 pub use rustversion;
+pub use uninit_member::{MemberState, UninitMember};
 
 pub(crate) mod sealed {
     // The purpose of the `Sealed` trait **is** to be unnameable from outside the crate.
@@ -0,0 +1,31 @@
+//! Traits used by the setters of `#[builder(nested)]` members.
+//!
+//! They are implemented by `#[derive(Builder)]` for every struct whose builder
+//! can be started and finished without any additional arguments. This way the
+//! setter of a nested member can name the builder of the member's type and
+//! finish it only when the builder's state is complete.
+
+/// Gives access to the builder of the type.
+#[rustversion::attr(
+    since(1.78.0),
+    diagnostic::on_unimplemented(
+        message = "the type `{Self}` can't be used with `#[builder(nested)]`",
+        label = "the type `{Self}` doesn't have a builder generated by `#[derive(Builder)]`",
+        note = "`#[builder(nested)]` requires the type of the member to derive `Builder`, \
+            and its builder must be possible to start and finish without any arguments"
+    )
+)]
+pub trait NestedBuilder: Sized {
+    /// The builder in its initial state.
+    type Builder;
+
+    /// Calls the starting function of the builder.
+    fn nested_builder() -> Self::Builder;
+}
+
+/// Implemented by the builder of the type `T` only in the state where all
+/// required members are set.
+pub trait FinishNested<T> {
+    /// Calls the finishing function of the builder.
+    fn finish_nested(self) -> T;
+}
@@ -0,0 +1,55 @@
+/// Generates the `macro_rules!` macro for the preset defined with `bon::preset!`.
+///
+/// Nested `macro_rules!` definitions can't use `$` directly in their matchers,
+/// because the outer macro would try to interpret them. So the `$` token is
+/// passed to this macro as `$d` by the `bon::preset!` macro.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __define_preset {
+    (
+        ($d:tt)
+        $(#[$attr:meta])*
+        $name:ident ( $($params:tt)* )
+    ) => {
+        $(#[$attr])*
+        macro_rules! $name {
+            ($d($d rest:tt)*) => {
+                $crate::__eval_preset_callback! {
+                    { $($params)* }
+                    $d($d rest)*
+                }
+            };
+        }
+    };
+}
+
+/// The builder macros can't see the parameters of the preset, because they
+/// are defined in a different item. So when the builder macro finds a reference
+/// to a preset with `#[builder(preset = path)]` it invokes the preset macro passing
+/// its own path, parameters and the item to it. The preset macro then calls this
+/// macro to invoke the builder macro again with the preset's parameters.
+///
+/// This is the same trick as the one used for `cfg` evaluation. See the docs on
+/// `__eval_cfg_callback` for details.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __eval_preset_callback {
+    (
+        // Parameters of the resolved preset
+        { $($preset:tt)* }
+
+        // The proc macro attribute to invoke with the resolved preset
+        $final_macro:path,
+
+        // Parameters to pass to the proc macro attribute after the preset
+        ( $($macro_params:tt)* )
+
+        // The item to attach the proc macro attribute to
+        $($item:tt)*
+    ) => {
+        // The special `__preset({...})` prefix is parsed by the proc macro
+        // attribute to get the parameters of the resolved preset.
+        #[$final_macro(__preset({ $($preset)* }) $($macro_params)*)]
+        $($item)*
+    };
+}
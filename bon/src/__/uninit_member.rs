@@ -0,0 +1,140 @@
+//! Storage for the required members of builders generated with
+//! `#[builder(maybe_uninit)]`.
+//!
+//! The builder's type state already tracks whether a required member was set,
+//! so there is no need to store an `Option` discriminant for it at runtime. The
+//! [`UninitMember`] stores the value in a [`MaybeUninit`] instead and uses the
+//! type state of the member to decide whether the value is initialized.
+//!
+//! All `unsafe` code lives in this module. The generated code only uses its
+//! safe API, which upholds the following invariant:
+//!
+//! > The value of `UninitMember<State, T>` is initialized if and only if
+//! > `State::IS_SET` is `true`.
+//!
+//! It holds, because an unset member can only be created via
+//! [`UninitMember::uninit()`], a set member can only be created via
+//! [`UninitMember::new()`], and [`IsSet`] and [`IsUnset`] are sealed traits
+//! implemented only for [`Set`] and [`Unset`] respectively.
+
+// This is the only place in `bon` where `unsafe` code is used. See the
+// invariant described above.
+#![allow(unsafe_code)]
+
+use super::{Sealed, Set, Unset};
+use crate::builder_state::{IsSet, IsUnset};
+use core::fmt;
+use core::marker::PhantomData;
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+/// Type state of a single member of the builder.
+///
+/// It's implemented only for [`Set`] and [`Unset`] and it's used as a bound on
+/// the associated types of the builder's `State` trait with `#[builder(maybe_uninit)]`.
+pub trait MemberState: Sealed {
+    #[doc(hidden)]
+    const IS_SET: bool;
+}
+
+impl<Name> MemberState for Set<Name> {
+    const IS_SET: bool = true;
+}
+
+impl<Name> MemberState for Unset<Name> {
+    const IS_SET: bool = false;
+}
+
+/// See the module-level docs for details.
+pub struct UninitMember<State: MemberState, T> {
+    value: MaybeUninit<T>,
+
+    // Wrapping `State` in `fn() -> State` makes the compiler think that
+    // we don't "own" an instance of `State`, so it doesn't influence the
+    // auto traits of the builder.
+    state: PhantomData<fn() -> State>,
+}
+
+impl<State: MemberState + IsUnset, T> UninitMember<State, T> {
+    #[inline(always)]
+    #[must_use]
+    pub fn uninit() -> Self {
+        Self {
+            value: MaybeUninit::uninit(),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<State: MemberState + IsSet, T> UninitMember<State, T> {
+    #[inline(always)]
+    #[must_use]
+    pub fn new(value: T) -> Self {
+        Self {
+            value: MaybeUninit::new(value),
+            state: PhantomData,
+        }
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn get(&self) -> &T {
+        // SAFETY: the `State` is `IsSet`, so the value is initialized
+        unsafe { &*self.value.as_ptr() }
+    }
+}
+
+impl<State: MemberState, T> UninitMember<State, T> {
+    /// The check for `IS_SET` is optimized away, because it's a constant
+    #[inline(always)]
+    #[must_use]
+    pub fn into_option(self) -> Option<T> {
+        let this = ManuallyDrop::new(self);
+
+        if !State::IS_SET {
+            return None;
+        }
+
+        // SAFETY: the value is initialized because `IS_SET` is `true`. It's
+        // never read again because `this` is wrapped in `ManuallyDrop`.
+        Some(unsafe { this.value.as_ptr().read() })
+    }
+
+    #[inline(always)]
+    #[must_use]
+    pub fn as_option(&self) -> Option<&T> {
+        if !State::IS_SET {
+            return None;
+        }
+
+        // SAFETY: the value is initialized because `IS_SET` is `true`
+        Some(unsafe { &*self.value.as_ptr() })
+    }
+}
+
+impl<State: MemberState, T> Drop for UninitMember<State, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if State::IS_SET {
+            // SAFETY: the value is initialized because `IS_SET` is `true`
+            unsafe { self.value.as_mut_ptr().drop_in_place() }
+        }
+    }
+}
+
+impl<State: MemberState, T: Clone> Clone for UninitMember<State, T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            value: self
+                .as_option()
+                .map_or_else(MaybeUninit::uninit, |value| MaybeUninit::new(value.clone())),
+            state: PhantomData,
+        }
+    }
+}
+
+impl<State: MemberState, T: fmt::Debug> fmt::Debug for UninitMember<State, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_option(), f)
+    }
+}
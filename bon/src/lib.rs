@@ -26,3 +26,13 @@ mod collections;
 pub mod __;
 
 mod builder_state;
+
+/// Declarative macro for defining reusable builder configuration presets.
+mod preset;
+
+#[cfg(feature = "alloc")]
+mod missing_members;
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(any(feature = "std", feature = "alloc"))))]
+pub use missing_members::MissingMembers;
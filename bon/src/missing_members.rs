@@ -0,0 +1,59 @@
+use crate::__::alloc::vec::Vec;
+use core::fmt;
+
+/// Error returned by the finishing function of a builder generated with
+/// `#[builder(typestate = false)]` if some of the required members were not set.
+///
+/// See the [`typestate`](https://bon-rs.com/reference/builder/top-level/typestate)
+/// attribute reference for details.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingMembers {
+    members: Vec<&'static str>,
+}
+
+impl MissingMembers {
+    /// Names of the required members that were not set in the order of
+    /// their declaration.
+    #[must_use]
+    pub fn members(&self) -> &[&'static str] {
+        &self.members
+    }
+}
+
+impl fmt::Display for MissingMembers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the following required members were not set: ")?;
+
+        for (i, member) in self.members.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{member}`")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MissingMembers {}
+
+/// Accepts the names of the required members with the flags whether they
+/// were set.
+///
+/// # Errors
+///
+/// Returns an error with the names of the members that weren't set, if any.
+pub fn check_missing_members(members: &[(&'static str, bool)]) -> Result<(), MissingMembers> {
+    let members: Vec<_> = members
+        .iter()
+        .filter(|(_, is_set)| !is_set)
+        .map(|(name, _)| *name)
+        .collect();
+
+    if members.is_empty() {
+        return Ok(());
+    }
+
+    Err(MissingMembers { members })
+}
@@ -0,0 +1,80 @@
+/// Defines a named set of builder parameters that can be reused across items.
+///
+/// The preset is referenced with `#[builder(preset = name)]` anywhere where the
+/// top-level builder config is accepted: on structs with `#[derive(Builder)]`,
+/// on functions with `#[builder]` and on methods inside of `#[bon]` impl blocks.
+/// The `preset = name` is replaced with the parameters of the preset in place,
+/// so the parameters that follow it take precedence according to the usual rules
+/// of the respective attributes (e.g. the order of `on(...)` clauses).
+///
+/// ```
+/// bon::preset!(http_defaults = (on(String, into), derive(Debug, Clone)));
+///
+/// #[derive(bon::Builder)]
+/// #[builder(preset = http_defaults)]
+/// struct Request {
+///     url: String,
+///     method: String,
+/// }
+///
+/// let request = Request::builder()
+///     .url("https://bon-rs.com")
+///     .method("GET");
+///
+/// // `Debug` and `Clone` were derived for the builder by the preset
+/// let _ = request.clone();
+/// ```
+///
+/// The preset is a `macro_rules!` macro under the hood, so it follows the same
+/// scoping rules. Use the visibility syntax to make it available outside
+/// of the current module:
+///
+/// - `name = (...)` - the preset is visible only in the textual scope after its definition;
+/// - `pub(crate) name = (...)`, `pub(in path) name = (...)` - the preset can also be
+///   referenced by its path e.g. `preset = crate::presets::name`;
+/// - `pub name = (...)` - the preset is exported from the crate root via `#[macro_export]`.
+///   Other crates can reference it by its path e.g. `preset = my_crate::name`.
+///   Within the defining crate it's available only in the textual scope, because
+///   of the limitations of `#[macro_export]`.
+///
+/// Presets may reference other presets using the `preset = name` syntax.
+///
+/// See the [presets reference](https://bon-rs.com/reference/builder/top-level/preset)
+/// for details.
+#[macro_export]
+macro_rules! preset {
+    (
+        $(#[$attr:meta])*
+        pub $name:ident = ( $($params:tt)+ ) $(;)?
+    ) => {
+        $crate::__define_preset! {
+            ($)
+            $(#[$attr])*
+            #[macro_export]
+            $name ( $($params)+ )
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        pub ( $($vis:tt)+ ) $name:ident = ( $($params:tt)+ ) $(;)?
+    ) => {
+        $crate::__define_preset! {
+            ($)
+            $(#[$attr])*
+            $name ( $($params)+ )
+        }
+
+        #[allow(unused_imports)]
+        pub ( $($vis)+ ) use $name;
+    };
+    (
+        $(#[$attr:meta])*
+        $name:ident = ( $($params:tt)+ ) $(;)?
+    ) => {
+        $crate::__define_preset! {
+            ($)
+            $(#[$attr])*
+            $name ( $($params)+ )
+        }
+    };
+}
@@ -0,0 +1,110 @@
+use crate::prelude::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn builder_type() {
+    #[derive(Builder)]
+    #[builder(
+        typestate = false,
+        builder_type(attrs(derive(PartialEq), must_use = "custom"))
+    )]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+    }
+
+    assert!(Sut::builder().x1(1) == Sut::builder().x1(1));
+    assert!(Sut::builder().x1(1) != Sut::builder().x1(1).x2(2));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn cfg_attr() {
+    #[derive(Builder)]
+    #[builder(
+        typestate = false,
+        builder_type(attrs(cfg_attr(all(), derive(PartialEq))))
+    )]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+    }
+
+    assert!(Sut::builder().x1(1) == Sut::builder().x1(1));
+}
+
+#[test]
+#[allow(deprecated)]
+fn setters() {
+    #[derive(Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(setters(attrs(deprecated = "use `x2` instead")))]
+        x1: Option<u32>,
+
+        #[builder(setters(attrs(deprecated = "common"), some_fn(attrs(deprecated = "some_fn")),))]
+        x2: Option<u32>,
+
+        #[builder(setters(attrs(deprecated)), getter(attrs(deprecated)))]
+        x3: u32,
+    }
+
+    let builder = Sut::builder().x1(1).maybe_x2(Some(2)).x3(3);
+
+    assert_eq!(*builder.get_x3(), 3);
+
+    let sut = builder.build();
+
+    assert_eq!(sut.x1, Some(1));
+    assert_eq!(sut.x2, Some(2));
+}
+
+#[test]
+#[allow(deprecated)]
+fn start_and_finish_fn_in_struct() {
+    #[derive(Builder)]
+    #[builder(
+        start_fn(name = start, attrs(deprecated)),
+        finish_fn(name = finish, attrs(must_use = "custom", deprecated)),
+        state_mod(attrs(deprecated)),
+    )]
+    struct Sut {
+        x1: u32,
+    }
+
+    assert_eq!(Sut::start().x1(1).finish().x1, 1);
+}
+
+#[test]
+#[allow(deprecated)]
+fn start_and_finish_fn_in_function() {
+    #[builder(
+        start_fn(name = start, attrs(deprecated)),
+        finish_fn(attrs(deprecated)),
+    )]
+    fn sut(x1: u32) -> u32 {
+        x1
+    }
+
+    assert_eq!(start().x1(1).call(), 1);
+}
+
+#[test]
+#[allow(deprecated)]
+fn on() {
+    #[derive(Builder)]
+    #[builder(on(u32, setters(attrs(deprecated)), getter(attrs(deprecated))))]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+
+        #[builder(setters(attrs(must_use = "the setter returns a new builder")))]
+        x2: u32,
+    }
+
+    let builder = Sut::builder().x1(1).x2(2);
+
+    assert_eq!(*builder.get_x1(), 1);
+    assert_eq!(builder.build().x2, 2);
+}
@@ -93,6 +93,60 @@ mod msrv_1_61 {
             assert!(builder.x2 == 13);
         }
 
+        #[test]
+        const fn test_validate() {
+            #[derive(Builder)]
+            #[builder(const)]
+            struct Sut {
+                #[builder(validate = |value| *value > 0)]
+                #[allow(dead_code)]
+                x1: u32,
+
+                #[builder(validate = |value| -> Result<(), u32> {
+                    if *value < 10 { Ok(()) } else { Err(*value) }
+                })]
+                #[allow(dead_code)]
+                x2: Option<u32>,
+            }
+
+            const VALID: bool = Sut::builder().x1(1).x2(2).build().is_ok();
+            const INVALID_X1: bool =
+                matches!(Sut::builder().x1(0).build(), Err(SutBuilderError::X1));
+            const INVALID_X2: bool = matches!(
+                Sut::builder().x1(1).x2(10).build(),
+                Err(SutBuilderError::X2(10))
+            );
+
+            #[allow(clippy::assertions_on_constants)]
+            {
+                assert!(VALID);
+                assert!(INVALID_X1);
+                assert!(INVALID_X2);
+            }
+        }
+
+        #[test]
+        const fn test_finish_fn_validate() {
+            #[derive(Builder)]
+            #[builder(const, finish_fn(validate = |min, max| -> Result<(), ()> {
+                if *min <= *max { Ok(()) } else { Err(()) }
+            }))]
+            #[allow(dead_code)]
+            struct Sut {
+                min: u32,
+                max: u32,
+            }
+
+            const VALID: bool = Sut::builder().min(1).max(2).build().is_ok();
+            const INVALID: bool = Sut::builder().min(3).max(2).build().is_err();
+
+            #[allow(clippy::assertions_on_constants)]
+            {
+                assert!(VALID);
+                assert!(INVALID);
+            }
+        }
+
         #[test]
         const fn test_function() {
             type Output = (u32, u32, u32, u32, Option<u32>, u32, u32, Option<u32>);
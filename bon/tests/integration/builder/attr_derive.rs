@@ -414,3 +414,213 @@ mod attr_bounds_non_empty {
         let _ = Sut::sut::<NoTraitImpls>().arg(&NoTraitImpls).clone();
     }
 }
+
+mod eq_and_hash {
+    use crate::prelude::*;
+
+    #[cfg(feature = "std")]
+    fn hash<T: core::hash::Hash>(value: &T) -> u64 {
+        use core::hash::Hasher;
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_struct() {
+        #[derive(Builder)]
+        #[builder(derive(Clone, PartialEq, Eq, Hash))]
+        #[allow(dead_code)]
+        struct Sut {
+            #[builder(start_fn)]
+            start_fn_arg: bool,
+
+            #[builder(field)]
+            field: u32,
+
+            required: u32,
+            optional: Option<u32>,
+        }
+
+        let builder = Sut::builder(true);
+
+        assert!(builder == Sut::builder(true));
+        assert!(builder != Sut::builder(false));
+
+        let builder = builder.required(1);
+
+        assert!(builder == Sut::builder(true).required(1));
+        assert!(builder != Sut::builder(true).required(2));
+
+        // The set/unset state of optional members is compared too
+        assert!(builder.clone().maybe_optional(None) == builder.clone().maybe_optional(None));
+        assert!(builder.clone().optional(1) != builder.clone().maybe_optional(None));
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(hash(&builder), hash(&Sut::builder(true).required(1)));
+            assert_ne!(hash(&builder), hash(&Sut::builder(true).required(2)));
+            assert_ne!(
+                hash(&builder.clone().optional(1)),
+                hash(&builder.clone().maybe_optional(None))
+            );
+        }
+    }
+
+    #[test]
+    fn test_function() {
+        #[builder(derive(PartialEq, Eq, Hash))]
+        #[allow(unused_variables)]
+        fn sut(#[builder(start_fn)] start_fn_arg: bool, required: u32, optional: Option<u32>) {}
+
+        assert!(sut(true).required(1) == sut(true).required(1));
+        assert!(sut(true).required(1) != sut(false).required(1));
+        assert!(sut(true).optional(1) != sut(true).optional(2));
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(hash(&sut(true).required(1)), hash(&sut(true).required(1)));
+            assert_ne!(hash(&sut(true).required(1)), hash(&sut(true).required(2)));
+        }
+    }
+
+    #[test]
+    fn test_method() {
+        #[derive(PartialEq, Eq, Hash)]
+        struct Sut(u32);
+
+        #[bon]
+        impl Sut {
+            #[builder(derive(PartialEq, Eq, Hash))]
+            fn with_self(&self, _required: u32) {
+                let _ = self;
+            }
+        }
+
+        let sut_1 = Sut(1);
+        let sut_2 = Sut(2);
+
+        assert!(sut_1.with_self().required(1) == sut_1.with_self().required(1));
+        assert!(sut_1.with_self().required(1) != sut_2.with_self().required(1));
+
+        #[cfg(feature = "std")]
+        {
+            assert_eq!(
+                hash(&sut_1.with_self().required(1)),
+                hash(&sut_1.with_self().required(1))
+            );
+            assert_ne!(
+                hash(&sut_1.with_self().required(1)),
+                hash(&sut_2.with_self().required(1))
+            );
+        }
+    }
+
+    #[test]
+    fn maybe_uninit() {
+        #[derive(Builder)]
+        #[builder(maybe_uninit, derive(PartialEq, Eq))]
+        #[allow(dead_code)]
+        struct Sut {
+            required: u32,
+            optional: Option<u32>,
+        }
+
+        assert!(Sut::builder() == Sut::builder());
+        assert!(Sut::builder().required(1) == Sut::builder().required(1));
+        assert!(Sut::builder().required(1) != Sut::builder().required(2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn no_typestate() {
+        #[derive(Builder)]
+        #[builder(typestate = false, derive(PartialEq, Eq))]
+        #[allow(dead_code)]
+        struct Sut {
+            required: u32,
+        }
+
+        assert!(Sut::builder() == Sut::builder());
+        assert!(Sut::builder() != Sut::builder().required(1));
+    }
+
+    #[test]
+    fn bounds() {
+        struct NoTraitImpls;
+
+        #[derive(Builder)]
+        #[builder(derive(PartialEq(bounds()), Eq(bounds()), Hash(bounds())))]
+        struct Sut<T> {
+            _arg: core::marker::PhantomData<T>,
+        }
+
+        let _ = Sut::<NoTraitImpls>::builder() == Sut::builder();
+    }
+}
+
+mod default {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_struct() {
+        #[derive(Builder)]
+        #[builder(derive(Default, Debug))]
+        #[allow(dead_code)]
+        struct Sut {
+            #[builder(field = 42)]
+            field: u32,
+
+            #[builder(field)]
+            field_default: u32,
+
+            required: u32,
+        }
+
+        assert_debug_eq(
+            SutBuilder::default(),
+            expect!["SutBuilder { field: 42, field_default: 0 }"],
+        );
+
+        let _: Sut = SutBuilder::default().required(1).build();
+    }
+
+    #[test]
+    fn test_function() {
+        #[builder(derive(Default, Debug, PartialEq))]
+        fn sut(required: u32) -> u32 {
+            required
+        }
+
+        assert_debug_eq(SutBuilder::default(), expect!["SutBuilder"]);
+        assert!(SutBuilder::default() == sut());
+        assert_eq!(SutBuilder::default().required(1).call(), 1);
+    }
+
+    #[test]
+    fn test_method() {
+        struct Sut;
+
+        #[bon]
+        impl Sut {
+            #[builder(derive(Default, PartialEq))]
+            fn sut(required: u32) -> u32 {
+                required
+            }
+        }
+
+        assert!(SutSutBuilder::default() == Sut::sut());
+        assert_eq!(SutSutBuilder::default().required(1).call(), 1);
+    }
+
+    #[test]
+    fn generics() {
+        #[derive(Builder)]
+        #[builder(derive(Default))]
+        struct Sut<T> {
+            value: T,
+        }
+
+        assert_eq!(SutBuilder::<u32>::default().value(1).build().value, 1);
+    }
+}
@@ -0,0 +1,159 @@
+#![cfg(feature = "alloc")]
+
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(each = arg)]
+        args: Vec<u32>,
+
+        #[builder(each = tag)]
+        tags: BTreeSet<&'static str>,
+
+        #[builder(each = header)]
+        headers: BTreeMap<&'static str, u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().build(),
+        expect!["Sut { args: [], tags: {}, headers: {} }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .arg(1)
+            .tag("a")
+            .arg(2)
+            .header("x", 1)
+            .tag("b")
+            .header("y", 2)
+            .build(),
+        expect![[r#"
+            Sut {
+                args: [
+                    1,
+                    2,
+                ],
+                tags: {
+                    "a",
+                    "b",
+                },
+                headers: {
+                    "x": 1,
+                    "y": 2,
+                },
+            }"#]],
+    );
+}
+
+#[test]
+fn bulk_setter_extends_items() {
+    #[derive(Debug, Builder)]
+    struct Sut {
+        #[builder(each = arg)]
+        #[allow(dead_code)]
+        args: Vec<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().arg(1).args(vec![2, 3]).arg(4).build(),
+        expect!["Sut { args: [1, 2, 3, 4] }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().arg(1).maybe_args(None).arg(2).build(),
+        expect!["Sut { args: [1, 2] }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().args(vec![1]).build(),
+        expect!["Sut { args: [1] }"],
+    );
+}
+
+#[test]
+fn into() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(each = arg, into)]
+        args: Vec<String>,
+
+        #[builder(each = env, into)]
+        envs: BTreeMap<String, String>,
+    }
+
+    assert_debug_eq(
+        Sut::builder()
+            .arg("a")
+            .arg(String::from("b"))
+            .env("k", "v")
+            .build(),
+        expect![[r#"Sut { args: ["a", "b"], envs: {"k": "v"} }"#]],
+    );
+}
+
+#[test]
+fn each_with_getter_and_setters() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        /// Command line arguments
+        #[builder(
+            getter,
+            setters(name = arguments_list),
+            each(name = argument, vis = "pub(crate)", doc {
+                /// Adds an argument
+            }),
+        )]
+        args: Vec<u32>,
+    }
+
+    let builder = Sut::builder().argument(1).arguments_list(vec![2]);
+
+    assert_debug_eq(builder.get_args(), expect!["Some([1, 2])"]);
+    assert_debug_eq(
+        builder.argument(3).build(),
+        expect!["Sut { args: [1, 2, 3] }"],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(
+        #[builder(each = arg)] args: Vec<u32>,
+        #[builder(each = tag)] tags: BTreeSet<u32>,
+    ) -> u32 {
+        args.iter().sum::<u32>() + tags.iter().sum::<u32>()
+    }
+
+    assert_eq!(sut().call(), 0);
+    assert_eq!(sut().arg(1).tag(2).arg(3).tag(2).call(), 6);
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(#[builder(each = arg)] args: Vec<u32>) -> Vec<u32> {
+            args
+        }
+
+        #[builder]
+        fn with_self(&self, #[builder(each = arg)] args: Vec<u32>) -> Vec<u32> {
+            let _ = self;
+            args
+        }
+    }
+
+    assert_debug_eq(Sut::sut().arg(1).arg(2).call(), expect!["[1, 2]"]);
+    assert_debug_eq(Sut.with_self().arg(3).call(), expect!["[3]"]);
+}
@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    #[allow(clippy::struct_excessive_bools)]
+    struct Sut {
+        #[builder(flag)]
+        verbose: bool,
+
+        #[builder(flag)]
+        dry_run: bool,
+
+        #[builder(flag, setters(name = enable_loud, option_fn = set_loud))]
+        is_loud: bool,
+
+        name: &'static str,
+    }
+
+    assert_debug_eq(
+        Sut::builder().name("a").build(),
+        expect![[r#"
+            Sut {
+                verbose: false,
+                dry_run: false,
+                is_loud: false,
+                name: "a",
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .verbose()
+            .with_dry_run(false)
+            .enable_loud()
+            .name("b")
+            .build(),
+        expect![[r#"
+            Sut {
+                verbose: true,
+                dry_run: false,
+                is_loud: true,
+                name: "b",
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().set_loud(true).name("c").build(),
+        expect![[r#"
+            Sut {
+                verbose: false,
+                dry_run: false,
+                is_loud: true,
+                name: "c",
+            }"#]],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(#[builder(flag)] force: bool, #[builder(flag)] quiet: bool) -> (bool, bool) {
+        (force, quiet)
+    }
+
+    assert_debug_eq(sut().call(), expect!["(false, false)"]);
+    assert_debug_eq(sut().force().call(), expect!["(true, false)"]);
+    assert_debug_eq(sut().with_quiet(true).call(), expect!["(false, true)"]);
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(#[builder(flag)] force: bool) -> bool {
+            force
+        }
+    }
+
+    assert!(!Sut::sut().call());
+    assert!(Sut::sut().force().call());
+}
+
+#[test]
+fn on_bool_flag() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    #[builder(on(bool, flag))]
+    struct Sut {
+        verbose: bool,
+
+        #[builder(default = true)]
+        colored: bool,
+
+        #[builder(getter)]
+        dry_run: bool,
+
+        maybe: Option<bool>,
+    }
+
+    let builder = Sut::builder().dry_run();
+
+    assert!(builder.get_dry_run().copied().unwrap_or_default());
+
+    assert_debug_eq(
+        builder.verbose().colored(false).build(),
+        expect![[r#"
+            Sut {
+                verbose: true,
+                colored: false,
+                dry_run: true,
+                maybe: None,
+            }"#]],
+    );
+}
@@ -0,0 +1,182 @@
+use crate::prelude::*;
+
+#[test]
+fn exactly_one() {
+    #[derive(Debug, Builder)]
+    #[builder(groups(source = exactly_one))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(group = source)]
+        path: Option<u32>,
+
+        #[builder(group = source)]
+        url: Option<u32>,
+
+        #[builder(group = source, default = 99)]
+        inline: u32,
+
+        other: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().path(1).build(),
+        expect!["Sut { path: Some(1), url: None, inline: 99, other: None }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().other(2).url(3).build(),
+        expect![[r#"
+            Sut {
+                path: None,
+                url: Some(
+                    3,
+                ),
+                inline: 99,
+                other: Some(
+                    2,
+                ),
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().inline(4).build(),
+        expect!["Sut { path: None, url: None, inline: 4, other: None }"],
+    );
+}
+
+#[test]
+fn at_most_one() {
+    #[derive(Debug, Builder)]
+    #[builder(groups(auth = at_most_one))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(group = auth)]
+        token: Option<u32>,
+
+        #[builder(group = auth)]
+        password: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().build(),
+        expect!["Sut { token: None, password: None }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().password(1).build(),
+        expect!["Sut { token: None, password: Some(1) }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().maybe_token(None).build(),
+        expect!["Sut { token: None, password: None }"],
+    );
+}
+
+#[test]
+fn at_least_one() {
+    #[derive(Debug, Builder)]
+    #[builder(groups(contact = at_least_one))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(group = contact)]
+        email: Option<u32>,
+
+        #[builder(group = contact)]
+        phone: Option<u32>,
+
+        #[builder(group = contact)]
+        address: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().phone(1).build(),
+        expect!["Sut { email: None, phone: Some(1), address: None }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().address(1).email(2).phone(3).build(),
+        expect!["Sut { email: Some(2), phone: Some(3), address: Some(1) }"],
+    );
+}
+
+#[test]
+fn multiple_groups() {
+    #[derive(Debug, Builder)]
+    #[builder(groups(a = exactly_one, b = exactly_one))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(group = a)]
+        a1: Option<u32>,
+
+        #[builder(group = b)]
+        b1: Option<u32>,
+
+        #[builder(group = a)]
+        a2: Option<u32>,
+
+        #[builder(group = b)]
+        b2: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().b2(1).a1(2).build(),
+        expect!["Sut { a1: Some(2), b1: None, a2: None, b2: Some(1) }"],
+    );
+}
+
+#[test]
+fn generic_is_complete() {
+    #[derive(Debug, Builder)]
+    #[builder(groups(source = exactly_one))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(group = source)]
+        path: Option<u32>,
+
+        #[builder(group = source)]
+        url: Option<u32>,
+    }
+
+    fn finish<S: sut_builder::IsComplete>(builder: SutBuilder<S>) -> Sut {
+        builder.build()
+    }
+
+    assert_debug_eq(
+        finish(Sut::builder().url(1)),
+        expect!["Sut { path: None, url: Some(1) }"],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder(groups(source = exactly_one))]
+    fn sut(
+        #[builder(group = source)] path: Option<u32>,
+        #[builder(group = source)] url: Option<u32>,
+    ) -> (Option<u32>, Option<u32>) {
+        (path, url)
+    }
+
+    assert_eq!(sut().path(1).call(), (Some(1), None));
+    assert_eq!(sut().url(2).call(), (None, Some(2)));
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder(groups(source = exactly_one))]
+        fn sut(
+            #[builder(group = source)] path: Option<u32>,
+            #[builder(group = source)] url: Option<u32>,
+        ) -> (Option<u32>, Option<u32>) {
+            (path, url)
+        }
+    }
+
+    assert_eq!(Sut::sut().path(1).call(), (Some(1), None));
+    assert_eq!(Sut::sut().url(2).call(), (None, Some(2)));
+}
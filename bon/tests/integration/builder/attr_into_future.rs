@@ -305,4 +305,102 @@ mod tests {
             assert_eq!(usize, 42);
         }
     }
+
+    mod sync_fn {
+        use super::*;
+        use core::future::{Future, Ready};
+        use core::pin::Pin;
+
+        #[tokio::test]
+        async fn impl_future() {
+            #[builder(derive(IntoFuture(Box)))]
+            fn sut(value: u32) -> impl Future<Output = u32> + Send {
+                // Some synchronous processing before the future is created
+                let value = value * 2;
+                async move { ready(value).await }
+            }
+
+            let builder = sut().value(21);
+            assert_eq!(assert_send(builder).await, 42);
+        }
+
+        #[tokio::test]
+        async fn impl_future_non_send() {
+            #[builder(derive(IntoFuture(Box, ?Send)))]
+            #[expect(clippy::future_not_send)]
+            fn sut(value: u32) -> impl Future<Output = u32> {
+                let value = value * 2;
+                async move {
+                    non_send_future().await;
+                    value
+                }
+            }
+
+            assert_eq!(sut().value(21).await, 42);
+        }
+
+        #[tokio::test]
+        async fn impl_future_with_lifetimes() {
+            #[builder(derive(IntoFuture(Box)))]
+            fn sut<'a, 'b: 'a>(
+                x1: &'a u32,
+                x2: &'b u32,
+            ) -> impl Future<Output = (&'a u32, &'b u32)> + Send + 'a {
+                ready((x1, x2))
+            }
+
+            // Store the values in local variables to make sure no `'static`
+            // lifetime promotion happens
+            let local_x1 = 1;
+            let local_x2 = 2;
+
+            let builder = sut().x1(&local_x1).x2(&local_x2);
+            assert_eq!(assert_send(builder).await, (&1, &2));
+        }
+
+        #[tokio::test]
+        async fn nameable_boxed_future() {
+            type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+            #[builder(derive(IntoFuture))]
+            fn sut(value: u32) -> BoxFuture<u32> {
+                Box::pin(ready(value * 2))
+            }
+
+            // The future is used as is without additional boxing
+            let fut: BoxFuture<u32> = sut().value(21).into_future();
+            assert_eq!(fut.await, 42);
+
+            assert_eq!(assert_send(sut().value(21)).await, 42);
+        }
+
+        #[tokio::test]
+        async fn nameable_future() {
+            #[builder(derive(IntoFuture))]
+            fn sut(value: u32) -> Ready<u32> {
+                ready(value * 2)
+            }
+
+            let fut: Ready<u32> = sut().value(21).into_future();
+            assert_eq!(fut.await, 42);
+        }
+
+        #[tokio::test]
+        async fn method() {
+            struct Sut {
+                value: u32,
+            }
+
+            #[bon]
+            impl Sut {
+                #[builder(derive(IntoFuture(Box)))]
+                fn multiply(&self, factor: u32) -> impl Future<Output = u32> + Send {
+                    ready(self.value * factor)
+                }
+            }
+
+            let sut = Sut { value: 21 };
+            assert_eq!(assert_send(sut.multiply().factor(2)).await, 42);
+        }
+    }
 }
@@ -0,0 +1,168 @@
+use crate::prelude::*;
+
+mod unboxed {
+    use super::*;
+
+    #[test]
+    fn test_fn() {
+        #[builder(derive(IntoIterator))]
+        fn sut(value: u32, count: Option<u8>) -> core::iter::Take<core::iter::Repeat<u32>> {
+            core::iter::repeat(value).take(count.unwrap_or(2).into())
+        }
+
+        let mut sum = 0;
+
+        for value in sut().value(2).count(3) {
+            sum += value;
+        }
+
+        assert_eq!(sum, 6);
+        assert_eq!(sut().value(1).into_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_generic_fn() {
+        #[builder(derive(IntoIterator))]
+        fn sut<T: Copy>(value: T) -> [T; 2] {
+            [value, value]
+        }
+
+        let mut iter = sut().value(true).into_iter();
+
+        assert_eq!(iter.next(), Some(true));
+        assert_eq!(iter.next(), Some(true));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_method() {
+        struct Sut {
+            values: [u32; 3],
+        }
+
+        #[bon]
+        impl Sut {
+            #[builder(derive(IntoIterator))]
+            fn scan(&self, skip: usize) -> core::iter::Skip<core::slice::Iter<'_, u32>> {
+                self.values.iter().skip(skip)
+            }
+        }
+
+        let sut = Sut { values: [1, 2, 3] };
+
+        let mut sum = 0;
+
+        for value in sut.scan().skip(1) {
+            sum += value;
+        }
+
+        assert_eq!(sum, 5);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+mod boxed {
+    use super::*;
+
+    fn assert_send<T: Send>(value: T) -> T {
+        value
+    }
+
+    #[test]
+    fn test_fn() {
+        #[builder(derive(IntoIterator(Box)))]
+        fn sut(value: u32, count: Option<usize>) -> impl Iterator<Item = u32> {
+            core::iter::repeat(value).take(count.unwrap_or(2))
+        }
+
+        let mut sum = 0;
+
+        for value in sut().value(2).count(3) {
+            sum += value;
+        }
+
+        assert_eq!(sum, 6);
+
+        let iter = assert_send(sut().value(1).into_iter());
+        assert_eq!(iter.count(), 2);
+    }
+
+    #[test]
+    fn test_fn_nameable_return_type() {
+        #[builder(derive(IntoIterator(Box)))]
+        fn sut(value: u32) -> Vec<u32> {
+            vec![value, value]
+        }
+
+        let values: Vec<_> = sut().value(2).into_iter().collect();
+        assert_eq!(values, [2, 2]);
+    }
+
+    #[test]
+    fn test_fn_non_send() {
+        #[builder(derive(IntoIterator(Box, ?Send)))]
+        fn sut(value: u32) -> impl Iterator<Item = Rc<u32>> {
+            core::iter::once(Rc::new(value))
+        }
+
+        let values: Vec<_> = sut().value(2).into_iter().collect();
+        assert_eq!(values, [Rc::new(2)]);
+    }
+
+    #[test]
+    fn test_fn_with_lifetimes() {
+        #[builder(derive(IntoIterator(Box)))]
+        #[allow(single_use_lifetimes)]
+        fn sut<'a, 'b: 'a>(
+            values: &'a [u32],
+            prefix: &'b [u32],
+        ) -> impl Iterator<Item = &'a u32> + 'a {
+            values.iter().filter(move |value| !prefix.contains(value))
+        }
+
+        let values = [1, 2, 3];
+
+        let filtered: Vec<_> = sut().values(&values).prefix(&[1]).into_iter().collect();
+        assert_eq!(filtered, [&2, &3]);
+    }
+
+    #[test]
+    fn test_method() {
+        struct Db {
+            rows: Vec<String>,
+        }
+
+        #[bon]
+        impl Db {
+            #[builder(derive(IntoIterator(Box)))]
+            fn scan<'a>(
+                &'a self,
+                prefix: &'a str,
+                limit: Option<usize>,
+            ) -> impl Iterator<Item = &'a str> + 'a {
+                self.rows
+                    .iter()
+                    .map(String::as_str)
+                    .filter(move |row| row.starts_with(prefix))
+                    .take(limit.unwrap_or(usize::MAX))
+            }
+        }
+
+        let db = Db {
+            rows: vec![
+                "ab".to_owned(),
+                "b".to_owned(),
+                "ac".to_owned(),
+                "ad".to_owned(),
+            ],
+        };
+
+        let mut rows = vec![];
+
+        for row in db.scan().prefix("a").limit(2) {
+            rows.push(row);
+        }
+
+        assert_eq!(rows, ["ab", "ac"]);
+    }
+}
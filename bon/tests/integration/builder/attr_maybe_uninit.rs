@@ -0,0 +1,247 @@
+#![cfg(feature = "alloc")]
+
+use crate::prelude::*;
+use core::cell::Cell;
+
+/// Counts how many times the values were dropped to make sure that the
+/// builder drops only the members that were set, and exactly once.
+#[derive(Debug, Clone)]
+struct DropCounter<'a>(&'a Cell<u32>);
+
+impl Drop for DropCounter<'_> {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(maybe_uninit, derive(Clone, Debug))]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(into, getter)]
+        required: String,
+
+        #[builder(getter(copy))]
+        copy: u32,
+
+        #[builder(getter(clone))]
+        cloned: Vec<u32>,
+
+        #[builder(getter(deref(str)))]
+        deref: String,
+
+        optional: Option<u32>,
+
+        #[builder(default = 1)]
+        default: u32,
+
+        #[builder(with = |x: u32, y: u32| x + y)]
+        with: u32,
+
+        #[builder(required)]
+        required_option: Option<u32>,
+    }
+
+    let builder = Sut::builder()
+        .required("a")
+        .copy(1)
+        .cloned(vec![2])
+        .deref("b".to_owned());
+
+    assert_eq!(builder.get_required(), "a");
+    assert_eq!(builder.get_copy(), 1);
+    assert_eq!(builder.get_cloned(), [2]);
+    assert_eq!(builder.get_deref(), "b");
+
+    assert_debug_eq(
+        &builder,
+        expect![[r#"
+            SutBuilder {
+                required: "a",
+                copy: 1,
+                cloned: [
+                    2,
+                ],
+                deref: "b",
+            }"#]],
+    );
+
+    let sut = builder
+        .clone()
+        .with(2, 3)
+        .required_option(None)
+        .optional(4)
+        .build();
+
+    assert_debug_eq(
+        sut,
+        expect![[r#"
+            Sut {
+                required: "a",
+                copy: 1,
+                cloned: [
+                    2,
+                ],
+                deref: "b",
+                optional: Some(
+                    4,
+                ),
+                default: 1,
+                with: 5,
+                required_option: None,
+            }"#]],
+    );
+
+    // The original builder is still usable after the clone
+    assert_debug_eq(
+        builder.with(1, 1).required_option(Some(2)).build(),
+        expect![[r#"
+            Sut {
+                required: "a",
+                copy: 1,
+                cloned: [
+                    2,
+                ],
+                deref: "b",
+                optional: None,
+                default: 1,
+                with: 2,
+                required_option: Some(
+                    2,
+                ),
+            }"#]],
+    );
+}
+
+#[test]
+fn drops_only_set_members() {
+    #[derive(Builder)]
+    #[builder(maybe_uninit, derive(Clone))]
+    #[allow(dead_code)]
+    struct Sut<'a> {
+        x1: DropCounter<'a>,
+        x2: DropCounter<'a>,
+        x3: Option<DropCounter<'a>>,
+    }
+
+    let drops = Cell::new(0);
+
+    drop(Sut::builder());
+    assert_eq!(drops.get(), 0);
+
+    drop(Sut::builder().x2(DropCounter(&drops)));
+    assert_eq!(drops.get(), 1);
+
+    let builder = Sut::builder()
+        .x1(DropCounter(&drops))
+        .x3(DropCounter(&drops));
+    let clone = builder.clone();
+
+    drop(builder);
+    assert_eq!(drops.get(), 3);
+
+    let sut = clone.x2(DropCounter(&drops)).build();
+    assert_eq!(drops.get(), 3);
+
+    drop(sut);
+    assert_eq!(drops.get(), 6);
+}
+
+#[test]
+fn drops_on_setter_error() {
+    #[derive(Builder)]
+    #[builder(maybe_uninit)]
+    #[allow(dead_code)]
+    struct Sut<'a> {
+        x1: DropCounter<'a>,
+
+        #[builder(with = |value: u32| -> Result<_, u32> {
+            if value == 0 { Err(value) } else { Ok(value) }
+        })]
+        x2: u32,
+    }
+
+    let drops = Cell::new(0);
+
+    let result = Sut::builder().x1(DropCounter(&drops)).x2(0);
+    assert!(result.is_err());
+    assert_eq!(drops.get(), 1);
+
+    let sut = Sut::builder()
+        .x1(DropCounter(&drops))
+        .x2(1)
+        .unwrap()
+        .build();
+    assert_eq!(sut.x2, 1);
+    assert_eq!(drops.get(), 1);
+}
+
+#[test]
+fn test_function() {
+    #[builder(maybe_uninit)]
+    fn sut<T: Into<u32>>(
+        #[builder(start_fn)] x1: u32,
+        #[builder(finish_fn)] x2: u32,
+        x3: T,
+        x4: &str,
+        #[builder(default)] x5: u32,
+    ) -> u32 {
+        x1 + x2 + x3.into() + x4.parse::<u32>().unwrap() + x5
+    }
+
+    assert_eq!(sut(1).x3(2_u8).x4("3").x5(4).call(5), 15);
+    assert_eq!(sut(1).x4("3").x3(2_u16).call(5), 11);
+}
+
+#[test]
+fn test_method() {
+    struct Sut(u32);
+
+    #[bon]
+    impl Sut {
+        #[builder(maybe_uninit)]
+        fn sut(&self, x: u32, y: Option<u32>) -> u32 {
+            self.0 + x + y.unwrap_or_default()
+        }
+    }
+
+    assert_eq!(Sut(1).sut().x(2).call(), 3);
+    assert_eq!(Sut(1).sut().y(3).x(2).call(), 6);
+}
+
+#[test]
+fn to_builder() {
+    #[derive(Debug, Builder)]
+    #[builder(maybe_uninit, to_builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        x: String,
+        y: Option<u32>,
+    }
+
+    let sut = Sut::builder().x("a".to_owned()).build();
+
+    assert_debug_eq(
+        sut.into_builder().build(),
+        expect![[r#"Sut { x: "a", y: None }"#]],
+    );
+}
+
+#[cfg(feature = "experimental-generics-setters")]
+#[test]
+fn generics_setters() {
+    #[derive(Debug, Builder)]
+    #[builder(maybe_uninit, generics(setters = "conv_{}"))]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        x: String,
+        y: T,
+    }
+
+    assert_debug_eq(
+        Sut::<()>::builder().x("a".to_owned()).conv_t().y(1).build(),
+        expect![[r#"Sut { x: "a", y: 1 }"#]],
+    );
+}
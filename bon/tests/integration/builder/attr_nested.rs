@@ -0,0 +1,154 @@
+use crate::prelude::*;
+
+#[derive(Debug, Builder)]
+#[allow(dead_code)]
+struct Inner {
+    x: u32,
+
+    #[builder(default = 1)]
+    y: u32,
+}
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(nested)]
+        required: Inner,
+
+        #[builder(nested)]
+        optional: Option<Inner>,
+
+        #[builder(nested, default = Inner { x: 0, y: 0 })]
+        with_default: Inner,
+    }
+
+    assert_debug_eq(
+        Sut::builder().required(|b| b.x(1)).build(),
+        expect![[r#"
+            Sut {
+                required: Inner {
+                    x: 1,
+                    y: 1,
+                },
+                optional: None,
+                with_default: Inner {
+                    x: 0,
+                    y: 0,
+                },
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .required(|b| b.x(1).y(2))
+            .optional(|b| b.x(3))
+            .with_default(|b| b.x(4).y(5))
+            .build(),
+        expect![[r#"
+            Sut {
+                required: Inner {
+                    x: 1,
+                    y: 2,
+                },
+                optional: Some(
+                    Inner {
+                        x: 3,
+                        y: 1,
+                    },
+                ),
+                with_default: Inner {
+                    x: 4,
+                    y: 5,
+                },
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .required(|b| b.x(1))
+            .maybe_optional(Some(Inner { x: 6, y: 7 }))
+            .build()
+            .optional,
+        expect!["Some(Inner { x: 6, y: 7 })"],
+    );
+}
+
+#[test]
+fn generics() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Inner<'a, T: Copy> {
+        value: &'a T,
+    }
+
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut<'a> {
+        #[builder(nested)]
+        inner: Inner<'a, u32>,
+    }
+
+    let value = 1;
+
+    assert_debug_eq(
+        Sut::builder().inner(|b| b.value(&value)).build(),
+        expect!["Sut { inner: Inner { value: 1 } }"],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(#[builder(nested)] inner: Inner, #[builder(nested)] optional: Option<Inner>) -> u32 {
+        inner.x + inner.y + optional.map(|inner| inner.x).unwrap_or_default()
+    }
+
+    assert_eq!(sut().inner(|b| b.x(1)).call(), 2);
+    assert_eq!(sut().inner(|b| b.x(1).y(2)).optional(|b| b.x(3)).call(), 6);
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(#[builder(nested)] inner: Inner) -> u32 {
+            inner.x + inner.y
+        }
+
+        #[builder]
+        fn with_self(&self, #[builder(nested)] inner: Inner) -> u32 {
+            let _ = self;
+            inner.x
+        }
+    }
+
+    assert_eq!(Sut::sut().inner(|b| b.x(1)).call(), 2);
+    assert_eq!(Sut.with_self().inner(|b| b.x(3)).call(), 3);
+}
+
+#[test]
+fn deeply_nested() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Middle {
+        #[builder(nested)]
+        inner: Inner,
+    }
+
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(nested)]
+        middle: Middle,
+    }
+
+    assert_debug_eq(
+        Sut::builder().middle(|b| b.inner(|b| b.x(1))).build(),
+        expect!["Sut { middle: Middle { inner: Inner { x: 1, y: 1 } } }"],
+    );
+}
@@ -70,3 +70,156 @@ fn match_generic() {
 
     sut().arg1(true).arg2(()).arg3(IntoGeneric("foo")).call();
 }
+
+#[test]
+fn match_generic_param() {
+    #[builder(on(T, into))]
+    fn sut<T>(_arg1: T, _arg2: Option<T>, _arg3: bool) {}
+
+    sut::<&str>()
+        .arg1(IntoStrRef("foo"))
+        .arg2(IntoStrRef("bar"))
+        .arg3(true)
+        .call();
+}
+
+#[test]
+fn match_alternatives() {
+    #[builder(on(bool | () | Generic<_>, into))]
+    fn sut<T>(_arg1: bool, _arg2: Option<()>, _arg3: Generic<T>, _arg4: &str) {}
+
+    sut()
+        .arg1(IntoBool(true))
+        .arg2(IntoUnit)
+        .arg3(IntoGeneric("foo"))
+        .arg4("bar")
+        .call();
+}
+
+#[test]
+// The same type is intentionally referenced via different paths
+#[allow(unused_qualifications)]
+fn match_path_suffix() {
+    use core::time;
+
+    #[builder(on(*::Duration, default = time::Duration::from_secs(1)))]
+    fn sut(arg1: time::Duration, arg2: core::time::Duration, arg3: ::core::time::Duration) -> u64 {
+        arg1.as_secs() + arg2.as_secs() + arg3.as_secs()
+    }
+
+    assert_eq!(sut().call(), 3);
+    assert_eq!(sut().arg2(time::Duration::from_secs(5)).call(), 7);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn default_and_with() {
+    #[derive(Debug, Builder)]
+    #[builder(on(Vec<_>, default, with = FromIterator::from_iter))]
+    #[allow(dead_code)]
+    struct Sut {
+        items: Vec<u32>,
+        names: Vec<&'static str>,
+        level: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().items([1, 2]).level(3).build(),
+        expect!["Sut { items: [1, 2], names: [], level: 3 }"],
+    );
+}
+
+#[test]
+fn getter() {
+    #[derive(Builder)]
+    #[builder(on(u32, getter(copy)))]
+    #[allow(dead_code)]
+    struct Sut {
+        level: u32,
+        limit: Option<u32>,
+    }
+
+    let builder = Sut::builder().level(3).limit(5);
+
+    let level: u32 = builder.get_level();
+    let limit: Option<u32> = builder.get_limit();
+
+    assert_eq!(level, 3);
+    assert_eq!(limit, Some(5));
+
+    let _ = builder.build();
+}
+
+#[test]
+fn setters_vis() {
+    mod private {
+        use crate::prelude::*;
+
+        #[derive(Debug, Builder)]
+        #[builder(on(u32, setters(vis = "pub(super)", doc {
+            /// Visible to the parent module only.
+        })))]
+        pub(super) struct Sut {
+            _level: u32,
+            _limit: Option<u32>,
+        }
+    }
+
+    let _ = private::Sut::builder().level(3).limit(5).build();
+}
+
+#[test]
+fn required() {
+    #[builder(on(Option<_>, required))]
+    fn sut(arg1: Option<u32>, arg2: u32) -> (Option<u32>, u32) {
+        (arg1, arg2)
+    }
+
+    assert_eq!(sut().arg1(None).arg2(2).call(), (None, 2));
+}
+
+#[test]
+fn required_with_into() {
+    #[builder(on(_, into), on(Option<_>, required))]
+    fn sut(arg1: Option<u32>) -> Option<u32> {
+        arg1
+    }
+
+    // `Option<u32>` is the underlying type for `into` because `required`
+    // disables the special handling of `Option<_>`
+    assert_eq!(sut().arg1(7).call(), Some(7));
+}
+
+#[test]
+fn composition() {
+    #[derive(Debug, Builder)]
+    #[builder(
+        on(u32, default = 1),
+        on(u32, default = 2, getter(copy)),
+        on(_, default = 3)
+    )]
+    // Clippy compares the keys of the nested attributes without their values
+    #[allow(clippy::duplicated_attributes)]
+    #[allow(dead_code)]
+    struct Sut {
+        // Member's own attributes take precedence
+        #[builder(default = 4)]
+        x1: u32,
+
+        // The first matching clause that specifies the parameter wins
+        x2: u32,
+
+        // Parameters from different clauses are combined
+        x3: u64,
+    }
+
+    let builder = Sut::builder().x1(5);
+
+    assert_eq!(builder.get_x1(), Some(5));
+
+    assert_debug_eq(builder.build(), expect!["Sut { x1: 5, x2: 1, x3: 3 }"]);
+    assert_debug_eq(
+        Sut::builder().build(),
+        expect!["Sut { x1: 4, x2: 1, x3: 3 }"],
+    );
+}
@@ -0,0 +1,150 @@
+use crate::prelude::*;
+
+bon::preset!(into_u32 = (on(u32, into)));
+bon::preset!(debug_into_u32 = (preset = into_u32, derive(Debug)));
+
+bon::preset!(
+    /// Exported presets are available in the textual scope in the defining crate
+    pub exported_into_u32 = (on(u32, into))
+);
+
+mod presets {
+    bon::preset!(pub(crate) clone = (derive(Clone)));
+    bon::preset!(pub(super) finish = (finish_fn = finish));
+}
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[builder(preset = into_u32, derive(Debug))]
+    #[allow(dead_code)]
+    struct Sut {
+        x1: u32,
+        x2: Option<u32>,
+        x3: bool,
+    }
+
+    let builder = Sut::builder().x1(1_u8).x2(2_u16);
+
+    assert_debug_eq(&builder, expect!["SutBuilder { x1: 1, x2: 2 }"]);
+
+    assert_debug_eq(
+        builder.x3(true).build(),
+        expect!["Sut { x1: 1, x2: Some(2), x3: true }"],
+    );
+}
+
+#[test]
+fn test_enum() {
+    #[derive(Builder)]
+    #[builder(preset = into_u32)]
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Sut {
+        #[builder(preset = crate::builder::attr_preset::presets::clone)]
+        A {
+            x1: u32,
+        },
+        B {
+            x1: u32,
+        },
+    }
+
+    let builder = Sut::a().x1(1_u8);
+    let _ = builder.clone().build();
+
+    assert_debug_eq(builder.build(), expect!["A { x1: 1 }"]);
+    assert_debug_eq(Sut::b().x1(2_u8).build(), expect!["B { x1: 2 }"]);
+}
+
+#[test]
+fn test_function() {
+    #[builder(preset = into_u32)]
+    fn sut(x1: u32, x2: Option<u32>) -> (u32, Option<u32>) {
+        (x1, x2)
+    }
+
+    assert_debug_eq(sut().x1(1_u8).x2(2_u16).call(), expect!["(1, Some(2))"]);
+
+    #[builder]
+    #[builder(preset = into_u32)]
+    fn sut_in_attr(x1: u32) -> u32 {
+        x1
+    }
+
+    assert_eq!(sut_in_attr().x1(1_u8).call(), 1);
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder(preset = into_u32)]
+        fn sut(x1: u32) -> u32 {
+            x1
+        }
+
+        #[builder(preset = presets::finish)]
+        fn with_self(&self, x1: u32) -> u32 {
+            let _ = self;
+            x1
+        }
+    }
+
+    assert_eq!(Sut::sut().x1(1_u8).call(), 1);
+    assert_eq!(Sut.with_self().x1(2).finish(), 2);
+}
+
+#[test]
+fn nested_presets() {
+    #[builder(preset = debug_into_u32)]
+    fn sut(x1: u32) -> u32 {
+        x1
+    }
+
+    assert_debug_eq(sut().x1(1_u8), expect!["SutBuilder { x1: 1 }"]);
+}
+
+#[test]
+fn multiple_presets() {
+    #[derive(Builder)]
+    #[builder(
+        preset = into_u32,
+        preset = presets::clone,
+        preset = presets::finish,
+    )]
+    struct Sut {
+        x1: u32,
+    }
+
+    let builder = Sut::builder().x1(2_u8);
+    let _ = builder.clone().finish();
+
+    assert_eq!(builder.finish().x1, 2);
+}
+
+#[test]
+fn exported() {
+    #[builder(preset = exported_into_u32, crate = ::bon)]
+    fn sut(x1: u32) -> u32 {
+        x1
+    }
+
+    assert_eq!(sut().x1(1_u8).call(), 1);
+}
+
+#[test]
+fn with_cfgs() {
+    #[builder(preset = into_u32)]
+    fn sut(
+        #[cfg(not(any()))] x1: u32,
+        #[cfg(any())] x2: u32,
+        #[cfg_attr(not(any()), builder(default))] x3: u32,
+    ) -> u32 {
+        x1 + x3
+    }
+
+    assert_eq!(sut().x1(1_u8).call(), 1);
+}
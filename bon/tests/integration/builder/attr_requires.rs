@@ -0,0 +1,119 @@
+use crate::prelude::*;
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        client_cert: Option<u32>,
+
+        #[builder(requires = client_cert)]
+        client_key: Option<u32>,
+
+        #[builder(flag, conflicts_with = ca_bundle)]
+        insecure: bool,
+
+        ca_bundle: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder()
+            .client_cert(1)
+            .client_key(2)
+            .insecure()
+            .build(),
+        expect![[r#"
+            Sut {
+                client_cert: Some(
+                    1,
+                ),
+                client_key: Some(
+                    2,
+                ),
+                insecure: true,
+                ca_bundle: None,
+            }"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().ca_bundle(3).build(),
+        expect![[r#"
+            Sut {
+                client_cert: None,
+                client_key: None,
+                insecure: false,
+                ca_bundle: Some(
+                    3,
+                ),
+            }"#]],
+    );
+}
+
+#[test]
+fn multiple() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        a: u32,
+        b: Option<u32>,
+
+        #[builder(requires = a, requires = b)]
+        c: Option<u32>,
+
+        #[builder(conflicts_with = b, conflicts_with = c)]
+        d: Option<u32>,
+
+        // Both sides of the conflict are declared
+        #[builder(conflicts_with = d)]
+        e: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().a(1).b(2).c(3).build(),
+        expect!["Sut { a: 1, b: Some(2), c: Some(3), d: None, e: None }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().a(1).d(4).build(),
+        expect!["Sut { a: 1, b: None, c: None, d: Some(4), e: None }"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().e(5).a(1).build(),
+        expect!["Sut { a: 1, b: None, c: None, d: None, e: Some(5) }"],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(
+        cert: Option<u32>,
+        #[builder(requires = cert)] key: Option<u32>,
+        #[builder(conflicts_with = cert)] anonymous: Option<bool>,
+    ) -> (Option<u32>, Option<u32>, Option<bool>) {
+        (cert, key, anonymous)
+    }
+
+    assert_eq!(sut().cert(1).key(2).call(), (Some(1), Some(2), None));
+    assert_eq!(sut().anonymous(true).call(), (None, None, Some(true)));
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(
+            cert: Option<u32>,
+            #[builder(requires = cert)] key: Option<u32>,
+        ) -> (Option<u32>, Option<u32>) {
+            (cert, key)
+        }
+    }
+
+    assert_eq!(Sut::sut().cert(1).key(2).call(), (Some(1), Some(2)));
+    assert_eq!(Sut::sut().cert(1).call(), (Some(1), None));
+}
@@ -0,0 +1,99 @@
+use crate::prelude::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(stage = 1)]
+        host: String,
+
+        #[builder(stage = 1)]
+        port: Option<u16>,
+
+        #[builder(stage = 2)]
+        user: Option<String>,
+
+        #[builder(stage = 3, flag)]
+        compress: bool,
+
+        // Members without a stage can be set at any time
+        timeout: Option<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder()
+            .port(80)
+            .host("localhost".to_owned())
+            .timeout(1)
+            .user("admin".to_owned())
+            .compress()
+            .build(),
+        expect![[r#"
+            Sut {
+                host: "localhost",
+                port: Some(
+                    80,
+                ),
+                user: Some(
+                    "admin",
+                ),
+                compress: true,
+                timeout: Some(
+                    1,
+                ),
+            }"#]],
+    );
+
+    // Stages can be skipped
+    assert_debug_eq(
+        Sut::builder()
+            .host("localhost".to_owned())
+            .compress()
+            .timeout(1)
+            .build(),
+        expect![[r#"
+            Sut {
+                host: "localhost",
+                port: None,
+                user: None,
+                compress: true,
+                timeout: Some(
+                    1,
+                ),
+            }"#]],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(
+        #[builder(stage = 1)] a: u32,
+        #[builder(stage = 2)] b: Option<u32>,
+    ) -> (u32, Option<u32>) {
+        (a, b)
+    }
+
+    assert_eq!(sut().a(1).b(2).call(), (1, Some(2)));
+    assert_eq!(sut().a(1).call(), (1, None));
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(
+            #[builder(stage = 1)] a: u32,
+            #[builder(stage = 2)] b: Option<u32>,
+        ) -> (u32, Option<u32>) {
+            (a, b)
+        }
+    }
+
+    assert_eq!(Sut::sut().a(1).b(2).call(), (1, Some(2)));
+}
@@ -0,0 +1,137 @@
+use crate::prelude::*;
+
+#[test]
+fn round_trip() {
+    #[derive(Debug, Clone, Builder)]
+    #[builder(to_builder)]
+    #[allow(dead_code)]
+    struct Sut<T> {
+        #[builder(start_fn)]
+        start_fn: u32,
+
+        #[builder(field)]
+        field: u32,
+
+        required: T,
+        optional: Option<u32>,
+
+        #[builder(default = 1)]
+        default: u32,
+
+        #[builder(skip = 2)]
+        skip: u32,
+    }
+
+    let sut = Sut::builder(1).required("r").optional(3).build();
+    let sut = Sut {
+        field: 5,
+        skip: 6,
+        default: 7,
+        ..sut
+    };
+
+    assert_debug_eq(
+        sut.to_builder().build(),
+        expect![[r#"
+            Sut {
+                start_fn: 1,
+                field: 5,
+                required: "r",
+                optional: Some(
+                    3,
+                ),
+                default: 7,
+                skip: 2,
+            }"#]],
+    );
+
+    assert_debug_eq(
+        sut.into_builder().build(),
+        expect![[r#"
+            Sut {
+                start_fn: 1,
+                field: 5,
+                required: "r",
+                optional: Some(
+                    3,
+                ),
+                default: 7,
+                skip: 2,
+            }"#]],
+    );
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Debug, Builder)]
+    #[builder(to_builder)]
+    struct Sut(#[builder(start_fn)] bool, #[builder(name = a)] u32);
+
+    assert_debug_eq(
+        Sut::builder(true).a(1).build().to_builder().build(),
+        expect!["Sut(true, 1)"],
+    );
+}
+
+#[test]
+fn finish_fn_member() {
+    #[derive(Debug, Builder)]
+    #[builder(to_builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(finish_fn)]
+        finish_fn: u32,
+        member: u32,
+    }
+
+    let sut = Sut::builder().member(1).build(2);
+
+    assert_debug_eq(
+        sut.into_builder().build(3),
+        expect!["Sut { finish_fn: 3, member: 1 }"],
+    );
+}
+
+#[cfg(feature = "experimental-overwritable")]
+#[test]
+fn overwritable() {
+    #[derive(Debug, Builder)]
+    #[builder(to_builder, on(_, overwritable))]
+    struct Sut {
+        a: u32,
+        b: Option<u32>,
+    }
+
+    let sut = Sut::builder().a(1).b(2).build();
+
+    assert_debug_eq(
+        sut.to_builder().a(3).maybe_b(None).build(),
+        expect!["Sut { a: 3, b: None }"],
+    );
+
+    assert_debug_eq(
+        sut.into_builder().b(4).build(),
+        expect!["Sut { a: 1, b: Some(4) }"],
+    );
+}
+
+#[test]
+fn into_builder_non_clone() {
+    #[derive(Debug)]
+    struct NonClone;
+
+    #[derive(Debug, Builder)]
+    #[builder(to_builder)]
+    struct Sut {
+        member: NonClone,
+    }
+
+    assert_debug_eq(
+        Sut::builder()
+            .member(NonClone)
+            .build()
+            .into_builder()
+            .build(),
+        expect!["Sut { member: NonClone }"],
+    );
+}
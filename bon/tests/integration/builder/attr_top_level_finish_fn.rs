@@ -0,0 +1,178 @@
+mod validate {
+    use crate::prelude::*;
+    use core::fmt;
+
+    #[derive(Debug)]
+    enum Error {
+        InvalidRange,
+        InvalidMember(&'static str),
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                Self::InvalidRange => f.write_str("invalid range"),
+                Self::InvalidMember(member) => write!(f, "invalid member {member}"),
+            }
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    fn validate_range(min: &u32, max: &u32) -> Result<(), Error> {
+        if min > max {
+            return Err(Error::InvalidRange);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct_closure() {
+        #[derive(Debug, Builder)]
+        #[builder(finish_fn(validate = |min, max| -> Result<(), Error> {
+            if min > max {
+                return Err(Error::InvalidRange);
+            }
+            Ok(())
+        }))]
+        #[allow(dead_code)]
+        struct Sut {
+            #[builder(default)]
+            min: u32,
+            max: u32,
+        }
+
+        assert_debug_eq(
+            Sut::builder().max(2).build(),
+            expect!["Ok(Sut { min: 0, max: 2 })"],
+        );
+
+        assert_debug_eq(
+            Sut::builder().min(3).max(2).build(),
+            expect!["Err(InvalidRange)"],
+        );
+    }
+
+    #[test]
+    fn test_struct_path() {
+        #[derive(Debug, Builder)]
+        #[builder(finish_fn(name = finish, validate = validate_range, error = Error))]
+        #[allow(dead_code)]
+        struct Sut {
+            min: u32,
+            max: u32,
+        }
+
+        assert_debug_eq(
+            Sut::builder().min(1).max(2).finish(),
+            expect!["Ok(Sut { min: 1, max: 2 })"],
+        );
+
+        assert_debug_eq(
+            Sut::builder().min(3).max(2).finish(),
+            expect!["Err(InvalidRange)"],
+        );
+    }
+
+    #[test]
+    fn test_function() {
+        #[builder(finish_fn(validate = validate_range, error = Error))]
+        fn sut(min: u32, max: u32) -> u32 {
+            max - min
+        }
+
+        assert_debug_eq(sut().min(1).max(2).call(), expect!["Ok(1)"]);
+        assert_debug_eq(sut().min(3).max(2).call(), expect!["Err(InvalidRange)"]);
+    }
+
+    #[test]
+    fn test_method() {
+        struct Sut {
+            offset: u32,
+        }
+
+        #[bon]
+        impl Sut {
+            #[builder(finish_fn(validate = |min: &u32, max: &u32| -> Result<(), Error> {
+                validate_range(min, max)
+            }))]
+            fn sut(&self, min: u32, max: u32) -> u32 {
+                self.offset + max - min
+            }
+        }
+
+        let sut = Sut { offset: 10 };
+
+        assert_debug_eq(sut.sut().min(1).max(2).call(), expect!["Ok(11)"]);
+        assert_debug_eq(sut.sut().min(3).max(2).call(), expect!["Err(InvalidRange)"]);
+    }
+
+    #[test]
+    fn all_kinds_of_members() {
+        #[derive(Debug, Builder)]
+        #[builder(finish_fn(validate = |a, b, c, d, e| -> Result<(), Error> {
+            if *a + *b + *c + *d + e.unwrap_or_default() > 10 {
+                return Err(Error::InvalidRange);
+            }
+            Ok(())
+        }))]
+        #[allow(dead_code)]
+        struct Sut {
+            #[builder(start_fn)]
+            a: u32,
+
+            #[builder(finish_fn)]
+            b: u32,
+
+            #[builder(skip = 1)]
+            c: u32,
+
+            d: u32,
+            e: Option<u32>,
+        }
+
+        assert_debug_eq(
+            Sut::builder(1).d(2).build(3),
+            expect!["Ok(Sut { a: 1, b: 3, c: 1, d: 2, e: None })"],
+        );
+
+        assert_debug_eq(
+            Sut::builder(1).d(2).e(10).build(3),
+            expect!["Err(InvalidRange)"],
+        );
+    }
+
+    #[test]
+    fn with_member_validation() {
+        #[derive(Debug, Builder)]
+        #[builder(finish_fn(validate = validate_range, error = Error))]
+        #[allow(dead_code)]
+        struct Sut {
+            #[builder(validate = |value| *value > 0)]
+            min: u32,
+            max: u32,
+        }
+
+        impl From<SutBuilderError> for Error {
+            fn from(err: SutBuilderError) -> Self {
+                match err {
+                    SutBuilderError::Min => Self::InvalidMember("min"),
+                }
+            }
+        }
+
+        assert_debug_eq(
+            Sut::builder().min(1).max(2).build(),
+            expect!["Ok(Sut { min: 1, max: 2 })"],
+        );
+
+        assert_debug_eq(
+            Sut::builder().min(0).max(2).build(),
+            expect![[r#"Err(InvalidMember("min"))"#]],
+        );
+
+        assert_debug_eq(
+            Sut::builder().min(3).max(2).build(),
+            expect!["Err(InvalidRange)"],
+        );
+    }
+}
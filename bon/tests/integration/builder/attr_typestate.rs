@@ -0,0 +1,196 @@
+#![cfg(feature = "alloc")]
+
+use crate::prelude::*;
+use alloc::string::ToString;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(typestate = false)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(into)]
+        required: String,
+
+        optional: Option<u32>,
+
+        #[builder(default = 1)]
+        default: u32,
+
+        #[builder(with = |x: u32, y: u32| x + y)]
+        with: u32,
+
+        #[builder(each = item)]
+        items: Vec<u32>,
+    }
+
+    assert_debug_eq(
+        Sut::builder().required("a").with(1, 2).item(3).build(),
+        expect![[r#"
+            Ok(
+                Sut {
+                    required: "a",
+                    optional: None,
+                    default: 1,
+                    with: 3,
+                    items: [
+                        3,
+                    ],
+                },
+            )"#]],
+    );
+
+    // The builder's type doesn't change when the setters are called
+    let mut builder: SutBuilder = Sut::builder();
+    builder = builder.optional(2);
+    builder = builder.required("b");
+
+    let err = builder.build().unwrap_err();
+
+    assert_debug_eq(&err, expect![[r#"MissingMembers { members: ["with"] }"#]]);
+    assert_debug_eq(err.members(), expect![[r#"["with"]"#]]);
+    assert_eq!(
+        err.to_string(),
+        "the following required members were not set: `with`"
+    );
+
+    assert_debug_eq(
+        Sut::builder().build().unwrap_err(),
+        expect![[r#"MissingMembers { members: ["required", "with"] }"#]],
+    );
+}
+
+#[test]
+fn getters() {
+    #[derive(Builder)]
+    #[builder(typestate = false)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(getter)]
+        required: u32,
+
+        #[builder(getter)]
+        optional: Option<u32>,
+
+        #[builder(getter, default = 1)]
+        default: u32,
+    }
+
+    let builder = Sut::builder();
+
+    assert_eq!(builder.get_required(), None);
+    assert_eq!(builder.get_optional(), None);
+    assert_eq!(builder.get_default(), None);
+
+    let builder = builder.required(1).optional(2).default(3);
+
+    assert_eq!(builder.get_required(), Some(&1));
+    assert_eq!(builder.get_optional(), Some(&2));
+    assert_eq!(builder.get_default(), Some(&3));
+}
+
+#[test]
+fn validation() {
+    #[derive(Debug, Builder)]
+    #[builder(typestate = false)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(validate = |value| *value > 0)]
+        level: u32,
+
+        name: &'static str,
+    }
+
+    assert_debug_eq(
+        Sut::builder().level(1).name("a").build(),
+        expect![[r#"Ok(Sut { level: 1, name: "a" })"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().level(0).name("a").build(),
+        expect!["Err(Level)"],
+    );
+
+    let err = Sut::builder().level(1).build().unwrap_err();
+
+    assert_debug_eq(
+        &err,
+        expect![[r#"MissingMembers(MissingMembers { members: ["name"] })"#]],
+    );
+    assert_eq!(
+        err.to_string(),
+        "the following required members were not set: `name`"
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder(typestate = false)]
+    fn sut(
+        #[builder(start_fn)] x1: u32,
+        #[builder(finish_fn)] x4: u32,
+        x2: u32,
+        #[builder(default)] x3: u32,
+    ) -> u32 {
+        x1 + x2 + x3 + x4
+    }
+
+    assert_debug_eq(sut(1).x2(2).call(4), expect!["Ok(7)"]);
+    assert_debug_eq(
+        sut(1).x3(3).call(4),
+        expect![[r#"Err(MissingMembers { members: ["x2"] })"#]],
+    );
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder(typestate = false)]
+        fn sut(&self, x: u32) -> u32 {
+            let _ = self;
+            x
+        }
+    }
+
+    assert_debug_eq(Sut.sut().x(1).call(), expect!["Ok(1)"]);
+    assert_debug_eq(
+        Sut.sut().call(),
+        expect![[r#"Err(MissingMembers { members: ["x"] })"#]],
+    );
+}
+
+#[test]
+fn derives() {
+    #[derive(Builder)]
+    #[builder(typestate = false, derive(Clone, Debug))]
+    #[allow(dead_code)]
+    struct Sut {
+        x: u32,
+        y: Option<u32>,
+    }
+
+    let builder = Sut::builder().x(1);
+
+    assert_debug_eq(builder.clone().y(2), expect!["SutBuilder { x: 1, y: 2 }"]);
+    assert_debug_eq(builder, expect!["SutBuilder { x: 1 }"]);
+}
+
+#[rustversion::since(1.64)]
+#[tokio::test]
+async fn into_future() {
+    use core::future::ready;
+
+    #[builder(typestate = false, derive(IntoFuture(Box)))]
+    async fn sut(value: u32) -> u32 {
+        ready(value * 2).await
+    }
+
+    assert_debug_eq(sut().value(21).await, expect!["Ok(42)"]);
+    assert_debug_eq(
+        sut().await,
+        expect![[r#"Err(MissingMembers { members: ["value"] })"#]],
+    );
+}
@@ -0,0 +1,298 @@
+use crate::prelude::*;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(into)]
+        required: String,
+
+        optional: Option<u32>,
+
+        #[builder(default = 1)]
+        default: u32,
+
+        #[builder(with = |x: u32, y: u32| x + y)]
+        with: u32,
+
+        #[builder(each = item)]
+        items: Vec<u32>,
+
+        #[builder(skip = 2)]
+        skip: u32,
+    }
+
+    let mut sut = Sut::builder()
+        .required("a")
+        .optional(1)
+        .with(1, 2)
+        .items(vec![1])
+        .build();
+
+    sut.update().apply();
+
+    assert_debug_eq(
+        &sut,
+        expect![[r#"
+            Sut {
+                required: "a",
+                optional: Some(
+                    1,
+                ),
+                default: 1,
+                with: 3,
+                items: [
+                    1,
+                ],
+                skip: 2,
+            }"#]],
+    );
+
+    sut.update()
+        .items(vec![2])
+        .item(3)
+        .with(3, 4)
+        .default(5)
+        .required("b")
+        .apply();
+
+    assert_debug_eq(
+        &sut,
+        expect![[r#"
+            Sut {
+                required: "b",
+                optional: Some(
+                    1,
+                ),
+                default: 5,
+                with: 7,
+                items: [
+                    2,
+                    3,
+                ],
+                skip: 2,
+            }"#]],
+    );
+
+    // `None` clears the `Option<T>` member and leaves the other members unchanged
+    sut.update()
+        .maybe_optional(None)
+        .maybe_default(None)
+        .apply();
+
+    assert_debug_eq(
+        &sut,
+        expect![[r#"
+            Sut {
+                required: "b",
+                optional: None,
+                default: 5,
+                with: 7,
+                items: [
+                    2,
+                    3,
+                ],
+                skip: 2,
+            }"#]],
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn patch_request() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    struct User {
+        name: String,
+        age: u32,
+        email: Option<String>,
+    }
+
+    struct UserPatch {
+        name: Option<String>,
+        age: Option<u32>,
+        email: Option<String>,
+    }
+
+    let mut user = User::builder().name("Bon".to_owned()).age(10).build();
+
+    let patch = UserPatch {
+        name: None,
+        age: Some(11),
+        email: Some("bon@example.com".to_owned()),
+    };
+
+    user.update()
+        .maybe_name(patch.name)
+        .maybe_age(patch.age)
+        .maybe_email(patch.email)
+        .apply();
+
+    assert_debug_eq(
+        user,
+        expect![[r#"
+            User {
+                name: "Bon",
+                age: 11,
+                email: Some(
+                    "bon@example.com",
+                ),
+            }"#]],
+    );
+}
+
+#[test]
+fn clear_option_member() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    struct Sut {
+        a: u32,
+        b: Option<u32>,
+        c: Option<u32>,
+    }
+
+    let mut sut = Sut::builder().a(1).b(2).c(3).build();
+
+    // `None` is written for `Option<T>` members, and it's ignored for the rest
+    sut.update().maybe_a(None).maybe_b(None).apply();
+
+    assert_debug_eq(&sut, expect!["Sut { a: 1, b: None, c: Some(3) }"]);
+
+    sut.update().b(4).apply();
+
+    assert_debug_eq(&sut, expect!["Sut { a: 1, b: Some(4), c: Some(3) }"]);
+}
+
+#[test]
+fn validation() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    struct Sut {
+        #[builder(validate = |value| *value > 0)]
+        a: u32,
+
+        #[builder(validate = |value| *value < 10)]
+        b: Option<u32>,
+    }
+
+    let mut sut = Sut::builder().a(1).build().unwrap();
+
+    // Nothing is written if any of the members fail the validation
+    let err = sut.update().a(2).b(10).apply().unwrap_err();
+
+    assert_debug_eq(err, expect!["B"]);
+    assert_debug_eq(&sut, expect!["Sut { a: 1, b: None }"]);
+
+    sut.update().a(2).b(9).apply().unwrap();
+
+    assert_debug_eq(&sut, expect!["Sut { a: 2, b: Some(9) }"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn finish_fn_validation() {
+    #[derive(Debug, Builder)]
+    #[builder(update, finish_fn(validate = |min, max| -> Result<(), String> {
+        if min > max {
+            return Err(format!("min {min} is greater than max {max}"));
+        }
+        Ok(())
+    }))]
+    struct Sut {
+        min: u32,
+        max: u32,
+    }
+
+    let mut sut = Sut::builder().min(1).max(5).build().unwrap();
+
+    // The new value of `min` is validated against the current value of `max`
+    let err = sut.update().min(6).apply().unwrap_err();
+
+    assert_debug_eq(err, expect![[r#""min 6 is greater than max 5""#]]);
+    assert_debug_eq(&sut, expect!["Sut { min: 1, max: 5 }"]);
+
+    sut.update().min(6).max(10).apply().unwrap();
+
+    assert_debug_eq(&sut, expect!["Sut { min: 6, max: 10 }"]);
+}
+
+#[test]
+fn finish_fn_validation_with_members_validation() {
+    #[derive(Debug)]
+    enum Error {
+        Member(SutBuilderError),
+        Range,
+    }
+
+    impl From<SutBuilderError> for Error {
+        fn from(err: SutBuilderError) -> Self {
+            Self::Member(err)
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref, clippy::ref_option)]
+    fn validate_range(start: &u32, min: &u32, max: &Option<u32>) -> Result<(), Error> {
+        match max {
+            Some(max) if *start + *min > *max => Err(Error::Range),
+            _ => Ok(()),
+        }
+    }
+
+    #[derive(Debug, Builder)]
+    #[builder(update, finish_fn(validate = validate_range, error = Error))]
+    struct Sut(
+        #[builder(start_fn)] u32,
+        #[builder(name = min, validate = |value| *value > 0)] u32,
+        #[builder(name = max)] Option<u32>,
+    );
+
+    let mut sut = Sut::builder(1).min(2).build().unwrap();
+
+    assert_debug_eq(
+        sut.update().min(0).apply().unwrap_err(),
+        expect!["Member(Min)"],
+    );
+    assert_debug_eq(sut.update().max(2).apply().unwrap_err(), expect!["Range"]);
+
+    sut.update().max(3).apply().unwrap();
+
+    assert_debug_eq(sut, expect!["Sut(1, 2, Some(3))"]);
+}
+
+#[test]
+fn tuple_struct() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    #[allow(dead_code)]
+    struct Sut(
+        #[builder(start_fn)] u32,
+        #[builder(name = a)] u32,
+        #[builder(name = b)] Option<u32>,
+    );
+
+    let mut sut = Sut::builder(1).a(2).build();
+
+    sut.update().b(3).apply();
+
+    assert_debug_eq(sut, expect!["Sut(1, 2, Some(3))"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generics() {
+    #[derive(Debug, Builder)]
+    #[builder(update)]
+    struct Sut<'a, T: Clone + Default> {
+        a: &'a str,
+        b: T,
+    }
+
+    let mut sut = Sut::builder().a("a").b(vec![1]).build();
+
+    sut.update().b(vec![2]).a("b").apply();
+
+    assert_debug_eq(sut, expect![[r#"Sut { a: "b", b: [2] }"#]]);
+}
@@ -0,0 +1,141 @@
+use crate::prelude::*;
+
+#[test]
+fn test_struct() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(validate = |value| *value > 0)]
+        level: u32,
+
+        #[builder(validate = |value: &&str| -> Result<(), &'static str> {
+            if value.is_empty() {
+                return Err("empty name");
+            }
+            Ok(())
+        })]
+        name: &'static str,
+
+        #[builder(validate = |value| *value < 10)]
+        optional: Option<u32>,
+
+        #[builder(default = 5, validate = |value| *value % 5 == 0)]
+        with_default: u32,
+    }
+
+    assert_debug_eq(
+        Sut::builder().level(1).name("a").optional(9).build(),
+        expect![[r#"
+            Ok(
+                Sut {
+                    level: 1,
+                    name: "a",
+                    optional: Some(
+                        9,
+                    ),
+                    with_default: 5,
+                },
+            )"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder().level(0).name("a").build(),
+        expect!["Err(Level)"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().level(1).name("").build(),
+        expect![[r#"Err(Name("empty name"))"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder()
+            .level(1)
+            .name("a")
+            .maybe_optional(Some(10))
+            .build(),
+        expect!["Err(Optional)"],
+    );
+
+    assert_debug_eq(
+        Sut::builder().level(1).name("a").with_default(6).build(),
+        expect!["Err(WithDefault)"],
+    );
+}
+
+#[test]
+fn validates_default_value() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(default = 0, validate = |value| *value > 0)]
+        value: u32,
+    }
+
+    assert_debug_eq(Sut::builder().build(), expect!["Err(Value)"]);
+    assert_debug_eq(
+        Sut::builder().value(1).build(),
+        expect!["Ok(Sut { value: 1 })"],
+    );
+}
+
+#[test]
+fn test_function() {
+    #[builder]
+    fn sut(
+        #[builder(validate = |value| *value > 0)] x: u32,
+        #[builder(validate = |value: &u32| -> Result<(), u32> {
+            if *value > 10 { Err(*value) } else { Ok(()) }
+        })]
+        y: Option<u32>,
+    ) -> u32 {
+        x + y.unwrap_or_default()
+    }
+
+    assert_debug_eq(sut().x(1).y(2).call(), expect!["Ok(3)"]);
+    assert_debug_eq(sut().x(0).call(), expect!["Err(X)"]);
+    assert_debug_eq(sut().x(1).y(11).call(), expect!["Err(Y(11))"]);
+}
+
+#[test]
+fn test_method() {
+    struct Sut;
+
+    #[bon]
+    impl Sut {
+        #[builder]
+        fn sut(#[builder(validate = |value| !value.is_empty())] name: &str) {
+            let _ = name;
+        }
+    }
+
+    assert_debug_eq(Sut::sut().name("a").call(), expect!["Ok(())"]);
+    assert_debug_eq(Sut::sut().name("").call(), expect!["Err(Name)"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn display() {
+    use alloc::string::ToString;
+
+    #[derive(Builder)]
+    #[allow(dead_code)]
+    struct Sut {
+        #[builder(validate = |value| *value > 0)]
+        x: u32,
+
+        #[builder(validate = |value| -> Result<(), &'static str> {
+            if *value > 0 { Ok(()) } else { Err("must be positive") }
+        })]
+        y: u32,
+    }
+
+    let err = Sut::builder().x(0).y(1).build().err().unwrap();
+    assert_eq!(err.to_string(), "invalid value for the member `x`");
+
+    let err = Sut::builder().x(1).y(0).build().err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid value for the member `y`: must be positive"
+    );
+}
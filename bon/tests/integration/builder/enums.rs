@@ -0,0 +1,109 @@
+use crate::prelude::*;
+use core::fmt::Debug;
+
+#[test]
+fn smoke() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    enum Msg {
+        Ping {
+            seq: u32,
+            payload: Option<u32>,
+        },
+        Pong {
+            #[builder(start_fn)]
+            seq: u32,
+
+            #[builder(default = 42)]
+            payload: u32,
+        },
+        Close,
+        Tuple(u32),
+    }
+
+    assert_debug_eq(
+        Msg::ping().seq(1).build(),
+        expect!["Ping { seq: 1, payload: None }"],
+    );
+
+    assert_debug_eq(
+        Msg::ping().seq(1).payload(2).build(),
+        expect!["Ping { seq: 1, payload: Some(2) }"],
+    );
+
+    assert_debug_eq(
+        Msg::pong(2).build(),
+        expect!["Pong { seq: 2, payload: 42 }"],
+    );
+
+    let _: MsgPingBuilder = Msg::ping();
+    let _: MsgPongBuilder = Msg::pong(3);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn enum_and_variant_level_config() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    #[builder(on(String, into), derive(Clone, Debug), finish_fn = finish)]
+    enum Sut {
+        #[builder(start_fn = create_first, builder_type = FirstBuilder)]
+        First { name: String },
+
+        #[builder(state_mod(vis = "pub(crate)"))]
+        Second { name: Option<String>, flag: bool },
+    }
+
+    let builder: FirstBuilder<first_builder::SetName> = Sut::create_first().name("foo");
+
+    assert_debug_eq(&builder, expect![[r#"FirstBuilder { name: "foo" }"#]]);
+
+    assert_debug_eq(builder.finish(), expect![[r#"First { name: "foo" }"#]]);
+
+    let builder: SutSecondBuilder<sut_second_builder::SetFlag> = Sut::second().flag(true);
+
+    assert_debug_eq(
+        builder.name("bar").finish(),
+        expect![[r#"Second { name: Some("bar"), flag: true }"#]],
+    );
+}
+
+#[test]
+fn generics() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    enum Sut<'a, T, const N: usize>
+    where
+        T: Debug + Clone,
+    {
+        Borrowed { value: &'a T },
+        Owned { values: [T; N] },
+    }
+
+    assert_debug_eq(
+        Sut::<u32, 0>::borrowed().value(&1).build(),
+        expect!["Borrowed { value: 1 }"],
+    );
+
+    assert_debug_eq(
+        Sut::<'_, _, 2>::owned().values([1, 2]).build(),
+        expect!["Owned { values: [1, 2] }"],
+    );
+}
+
+#[test]
+fn keyword_variant_names() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    enum Sut {
+        Type { value: u32 },
+        Match { value: u32 },
+    }
+
+    assert_debug_eq(Sut::r#type().value(1).build(), expect!["Type { value: 1 }"]);
+
+    assert_debug_eq(
+        Sut::r#match().value(2).build(),
+        expect!["Match { value: 2 }"],
+    );
+}
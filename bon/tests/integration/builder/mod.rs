@@ -1,22 +1,38 @@
+mod attr_attrs;
 mod attr_bon;
 mod attr_builder;
 mod attr_const;
 mod attr_crate;
 mod attr_default;
 mod attr_derive;
+mod attr_each;
 mod attr_field;
+mod attr_flag;
 mod attr_getter;
+mod attr_group;
 mod attr_into;
 mod attr_into_future;
+mod attr_into_iterator;
+mod attr_maybe_uninit;
+mod attr_nested;
 mod attr_on;
 #[cfg(feature = "experimental-overwritable")]
 mod attr_overwritable;
+mod attr_preset;
 mod attr_required;
+mod attr_requires;
 mod attr_setters;
 mod attr_skip;
+mod attr_stage;
+mod attr_to_builder;
+mod attr_top_level_finish_fn;
 mod attr_top_level_start_fn;
+mod attr_typestate;
+mod attr_update;
+mod attr_validate;
 mod attr_with;
 mod cfgs;
+mod enums;
 mod generics;
 #[cfg(feature = "experimental-generics-setters")]
 mod generics_setters;
@@ -31,6 +47,9 @@ mod raw_idents;
 mod smoke;
 mod target_feature;
 mod track_caller;
+mod trait_defs;
+mod trait_impls;
+mod tuple_structs;
 
 use crate::prelude::*;
 
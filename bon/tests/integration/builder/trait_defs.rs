@@ -0,0 +1,140 @@
+#![cfg(feature = "alloc")]
+
+use crate::prelude::*;
+use core::fmt::Debug;
+
+#[test]
+fn smoke() {
+    #[bon]
+    trait Repo {
+        fn name(&self) -> &'static str;
+
+        #[builder]
+        fn query(&self, filter: &str, limit: Option<u32>, offset: Option<u32>) -> String {
+            let limit = limit.unwrap_or(100);
+            let offset = offset.unwrap_or(0);
+            format!(
+                "{}: {filter} (limit: {limit}, offset: {offset})",
+                self.name()
+            )
+        }
+    }
+
+    struct Users;
+
+    impl Repo for Users {
+        fn name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    fn generic<T: Repo>(repo: &T) -> String {
+        repo.query().filter("active").limit(10).call()
+    }
+
+    assert_debug_eq(
+        generic(&Users),
+        expect![[r#""users: active (limit: 10, offset: 0)""#]],
+    );
+
+    assert_debug_eq(
+        Users.query().filter("all").offset(5).call(),
+        expect![[r#""users: all (limit: 100, offset: 5)""#]],
+    );
+
+    let _: RepoQueryBuilder<'_, '_, Users> = Users.query();
+}
+
+#[test]
+fn dyn_compatible() {
+    #[bon(dyn_compatible)]
+    trait Repo {
+        fn name(&self) -> &'static str;
+
+        #[builder]
+        fn query(&self, filter: &str, limit: Option<u32>) -> String {
+            format!("{}: {filter} {limit:?}", self.name())
+        }
+    }
+
+    struct Users;
+
+    impl Repo for Users {
+        fn name(&self) -> &'static str {
+            "users"
+        }
+    }
+
+    // Generic code can use the builder via the extension trait
+    fn generic<T: ?Sized + RepoBuilderExt>(repo: &T) -> String {
+        repo.query().filter("generic").call()
+    }
+
+    let repo: &dyn Repo = &Users;
+
+    assert_debug_eq(generic(&Users), expect![[r#""users: generic None""#]]);
+    assert_debug_eq(generic(repo), expect![[r#""users: generic None""#]]);
+
+    assert_debug_eq(
+        repo.query().filter("all").limit(1).call(),
+        expect![[r#""users: all Some(1)""#]],
+    );
+
+    let repo: Box<dyn Repo> = Box::new(Users);
+
+    assert_debug_eq(
+        repo.query().filter("none").call(),
+        expect![[r#""users: none None""#]],
+    );
+}
+
+#[test]
+fn generics_assoc_types_and_new_method() {
+    #[bon]
+    trait Store<K: Debug> {
+        type Value: Debug;
+
+        fn get(&self, key: &K) -> Self::Value;
+
+        #[builder]
+        fn new(value: Self::Value, label: Option<&'static str>) -> Self
+        where
+            Self: Sized + From<Self::Value>,
+        {
+            let _ = label;
+            Self::from(value)
+        }
+
+        #[builder]
+        fn describe(&self, key: K, prefix: Option<&str>) -> String {
+            let prefix = prefix.unwrap_or("");
+            format!("{prefix}{key:?} => {:?}", self.get(&key))
+        }
+    }
+
+    #[derive(Debug)]
+    struct Sut(u32);
+
+    impl From<u32> for Sut {
+        fn from(value: u32) -> Self {
+            Self(value)
+        }
+    }
+
+    impl Store<&str> for Sut {
+        type Value = u32;
+
+        fn get(&self, key: &&str) -> u32 {
+            self.0 + u32::try_from(key.len()).unwrap()
+        }
+    }
+
+    let sut: Sut = <Sut as Store<&str>>::builder().value(2).build();
+
+    assert_debug_eq(&sut, expect!["Sut(2)"]);
+
+    assert_debug_eq(
+        sut.describe().key("abc").prefix("> ").call(),
+        expect![[r#""> \"abc\" => 5""#]],
+    );
+}
@@ -0,0 +1,111 @@
+use crate::prelude::*;
+use core::fmt::Debug;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn smoke() {
+    trait Connector {
+        fn connect(&self, host: &str, port: u16, timeout: Option<u32>, tls: bool) -> String;
+    }
+
+    struct Sut {
+        prefix: &'static str,
+    }
+
+    #[bon]
+    impl Connector for Sut {
+        #[builder]
+        fn connect(&self, host: &str, port: u16, timeout: Option<u32>, tls: bool) -> String {
+            let timeout = timeout.unwrap_or(10);
+            format!(
+                "{}: {host}:{port} (timeout: {timeout}, tls: {tls})",
+                self.prefix
+            )
+        }
+    }
+
+    let sut = Sut { prefix: "conn" };
+
+    assert_debug_eq(
+        sut.connect().host("localhost").port(80).tls(true).call(),
+        expect![[r#""conn: localhost:80 (timeout: 10, tls: true)""#]],
+    );
+
+    // The trait method is still available via the trait
+    assert_debug_eq(
+        Connector::connect(&sut, "localhost", 443, Some(5), false),
+        expect![[r#""conn: localhost:443 (timeout: 5, tls: false)""#]],
+    );
+
+    let _: SutConnectBuilder<'_, '_> = sut.connect();
+}
+
+#[test]
+fn assoc_types_and_new_method() {
+    trait Make {
+        type Output;
+
+        fn new(value: u32, label: Option<&'static str>) -> Self;
+        fn output(self, extra: Self::Output) -> Self::Output;
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Sut {
+        value: u32,
+        label: Option<&'static str>,
+    }
+
+    #[bon]
+    impl Make for Sut {
+        type Output = u32;
+
+        #[builder]
+        fn new(value: u32, label: Option<&'static str>) -> Self {
+            Self { value, label }
+        }
+
+        #[builder]
+        fn output(self, extra: Self::Output) -> Self::Output {
+            self.value + extra
+        }
+    }
+
+    assert_debug_eq(
+        Sut::builder().value(1).label("a").build(),
+        expect![[r#"Sut { value: 1, label: Some("a") }"#]],
+    );
+
+    assert_eq!(Sut::builder().value(1).build().output().extra(2).call(), 3);
+
+    let _: SutBuilder = Sut::builder();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn generics_and_visibility() {
+    use core::marker::PhantomData;
+
+    trait Describe<U> {
+        fn describe(&self, value: U, suffix: &str) -> String;
+    }
+
+    struct Wrapper<T, U>(T, PhantomData<U>);
+
+    #[bon]
+    impl<T: Debug, U: Debug> Describe<U> for Wrapper<T, U> {
+        #[builder]
+        pub(crate) fn describe(&self, value: U, suffix: &str) -> String {
+            format!("{:?} {value:?}{suffix}", self.0)
+        }
+    }
+
+    assert_debug_eq(
+        Wrapper(1, PhantomData)
+            .describe()
+            .value("v")
+            .suffix("!")
+            .call(),
+        expect![[r#""1 \"v\"!""#]],
+    );
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+use core::fmt::Debug;
+
+#[test]
+fn positional_by_default() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Rgb(u8, u8, #[builder(into)] u8);
+
+    assert_debug_eq(Rgb::builder(1, 2, 3_u8).build(), expect!["Rgb(1, 2, 3)"]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn named_members() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    #[builder(on(String, into))]
+    struct Sut(
+        #[builder(start_fn)] u32,
+        #[builder(name = label)] String,
+        #[builder(name = weight, default = 10)] u32,
+        #[builder(name = note)] Option<String>,
+    );
+
+    assert_debug_eq(
+        Sut::builder(1).label("a").build(),
+        expect![[r#"Sut(1, "a", 10, None)"#]],
+    );
+
+    assert_debug_eq(
+        Sut::builder(1).label("a").weight(2).note("b").build(),
+        expect![[r#"Sut(1, "a", 2, Some("b"))"#]],
+    );
+}
+
+#[test]
+fn special_members() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Sut(
+        u32,
+        #[builder(finish_fn)] u32,
+        #[builder(skip = 99)] u32,
+        #[builder(name = extra)] u32,
+    );
+
+    assert_debug_eq(
+        Sut::builder(1).extra(2).build(3),
+        expect!["Sut(1, 3, 99, 2)"],
+    );
+}
+
+#[test]
+fn generics() {
+    #[derive(Debug, Builder)]
+    #[allow(dead_code)]
+    struct Pair<A, B>(A, #[builder(name = second)] B)
+    where
+        A: Debug,
+        B: Debug;
+
+    assert_debug_eq(
+        Pair::builder("a").second(2).build(),
+        expect![[r#"Pair("a", 2)"#]],
+    );
+}
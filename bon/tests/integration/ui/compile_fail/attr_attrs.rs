@@ -0,0 +1,28 @@
+use bon::{builder, Builder};
+
+#[derive(Builder)]
+#[builder(builder_type(attrs()))]
+struct EmptyAttrs {}
+
+#[derive(Builder)]
+#[builder(builder_type(attrs = derive(Clone)))]
+struct NameValueAttrs {}
+
+#[derive(Builder)]
+#[builder(builder_type(attrs(doc = "docs")))]
+struct DocInAttrs {}
+
+#[builder(start_fn(attrs(deprecated)))]
+fn start_fn_attrs_without_name() {}
+
+#[derive(Builder)]
+struct UnusedSettersAttrs {
+    #[builder(setters(
+        attrs(deprecated),
+        some_fn(attrs(must_use)),
+        option_fn(attrs(must_use))
+    ))]
+    x1: Option<u32>,
+}
+
+fn main() {}
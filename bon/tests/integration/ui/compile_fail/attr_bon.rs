@@ -56,4 +56,27 @@ impl ActiveBuilderInsideImplClueless {
     fn active_bon_clueless() {}
 }
 
+trait Describe<T> {
+    fn describe(&self, value: T);
+}
+
+struct Wrapper;
+
+#[bon]
+impl<T> Describe<T> for Wrapper {
+    #[builder]
+    fn describe(&self, value: T) {}
+}
+
+#[bon]
+trait RequiredMethod {
+    #[builder]
+    fn required(&self, value: u32);
+}
+
+#[bon]
+trait NoBuilderMethodsInTrait {
+    fn provided(&self) {}
+}
+
 fn main() {}
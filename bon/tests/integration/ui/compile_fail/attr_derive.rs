@@ -134,4 +134,24 @@ fn unsupported_bounds_on_derive_into() -> u32 {
     99
 }
 
+#[derive(Builder)]
+#[builder(derive(PartialEq, Eq, Hash))]
+struct StructContainsNonCmpTrait {
+    #[builder(start_fn)]
+    no_impl_start_fn: NoTraitImpls,
+
+    no_impls_required: NoTraitImpls,
+
+    no_impl_optional: Option<NoTraitImpls>,
+}
+
+#[builder(derive(Default))]
+fn derive_default_with_start_fn_member(#[builder(start_fn)] _start_fn: u32) {}
+
+#[bon]
+impl StructContainsNonTrait {
+    #[builder(derive(Default))]
+    fn derive_default_with_receiver(&self) {}
+}
+
 fn main() {}
@@ -0,0 +1,55 @@
+use bon::Builder;
+use std::collections::BTreeMap;
+
+#[derive(Builder)]
+struct NotCollection {
+    #[builder(each = item)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct OptionalCollection {
+    #[builder(each = item)]
+    items: Option<Vec<u32>>,
+}
+
+#[derive(Builder)]
+struct BareEach {
+    #[builder(each)]
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+struct NoName {
+    #[builder(each(vis = "pub"))]
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+struct SameName {
+    #[builder(each = items)]
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+struct WithDefault {
+    #[builder(each = item, default)]
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+struct Const {
+    #[builder(each = item)]
+    items: Vec<u32>,
+}
+
+#[derive(Builder)]
+struct WrongItemType {
+    #[builder(each = entry)]
+    entries: BTreeMap<u32, u32>,
+}
+
+fn main() {
+    WrongItemType::builder().entry(1).build();
+}
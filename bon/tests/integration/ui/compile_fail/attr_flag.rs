@@ -0,0 +1,42 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct NonBoolFlag {
+    #[builder(flag)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct FlagWithDefault {
+    #[builder(flag, default = true)]
+    value: bool,
+}
+
+#[derive(Builder)]
+struct FlagWithInto {
+    #[builder(flag, into)]
+    value: bool,
+}
+
+#[derive(Builder)]
+struct FlagInStartFn {
+    #[builder(flag, start_fn)]
+    value: bool,
+}
+
+#[derive(Builder)]
+#[builder(on(bool, flag))]
+struct RedundantFlag {
+    #[builder(flag)]
+    value: bool,
+}
+
+#[derive(Builder)]
+struct FlagSetterDoesntAcceptValue {
+    #[builder(flag)]
+    value: bool,
+}
+
+fn main() {
+    FlagSetterDoesntAcceptValue::builder().value(true);
+}
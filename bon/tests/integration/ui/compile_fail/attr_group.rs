@@ -0,0 +1,78 @@
+use bon::Builder;
+
+#[derive(Builder)]
+#[builder(groups(source = exactly_one))]
+struct ExactlyOne {
+    #[builder(group = source)]
+    path: Option<u32>,
+
+    #[builder(group = source)]
+    url: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(auth = at_most_one))]
+struct AtMostOne {
+    #[builder(group = auth)]
+    token: Option<u32>,
+
+    #[builder(group = auth)]
+    password: Option<u32>,
+}
+
+#[derive(Builder)]
+struct UndeclaredGroup {
+    #[builder(group = source)]
+    path: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(source = exactly_one))]
+struct SingleMember {
+    #[builder(group = source)]
+    path: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(source = exactly_one))]
+struct RequiredMember {
+    #[builder(group = source)]
+    path: u32,
+
+    #[builder(group = source)]
+    url: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(source = one_or_two))]
+struct UnknownKind {
+    #[builder(group = source)]
+    path: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(source = exactly_one, source = at_most_one))]
+struct DuplicateGroup {
+    #[builder(group = source)]
+    path: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(groups(source = exactly_one))]
+struct OptionFnConfig {
+    #[builder(group = source, setters(option_fn = maybe_path))]
+    path: Option<u32>,
+
+    #[builder(group = source)]
+    url: Option<u32>,
+}
+
+fn main() {
+    // Two members of the group are set
+    let _ = ExactlyOne::builder().path(1).url(2).build();
+
+    // No members of the group are set
+    let _ = ExactlyOne::builder().build();
+
+    let _ = AtMostOne::builder().token(1).password(2).build();
+}
@@ -1,11 +1,24 @@
-use bon::{builder, Builder};
+use bon::builder;
 
-// IntoFuture can only be used with async functions
+// IntoFuture can only be used with async functions or functions returning futures
 #[builder(derive(IntoFuture(Box)))]
-fn sync_function() -> u32 {
+fn sync_function_without_output() {}
+
+#[builder(derive(IntoFuture))]
+fn sync_function_unboxed() -> u32 {
     42
 }
 
+// The output type of `impl Trait` must be specified explicitly
+#[builder(derive(IntoFuture(Box)))]
+fn impl_trait_without_output() -> impl Send {}
+
+// `impl Trait` return types can't be named in `IntoFuture::IntoFuture`
+#[builder(derive(IntoFuture))]
+fn impl_future_unboxed() -> impl core::future::Future<Output = u32> {
+    core::future::ready(42)
+}
+
 // IntoFuture is not supported for unsafe functions
 #[builder(derive(IntoFuture(Box)))]
 async unsafe fn unsafe_async_function() -> u32 {
@@ -18,7 +31,7 @@ async fn with_finish_fn(#[builder(finish_fn)] value: u32) -> u32 {
     value
 }
 
-// IntoFuture requires Box argument
+// Futures returned by async functions are unnameable, so they must be boxed
 #[builder(derive(IntoFuture))]
 async fn missing_box_arg() -> u32 {
     42
@@ -30,17 +43,22 @@ async fn wrong_container() -> u32 {
     42
 }
 
+#[builder(derive(IntoFuture = Box))]
+async fn name_value_syntax() -> u32 {
+    42
+}
+
 // Wrong syntax for ?Send
 #[builder(derive(IntoFuture(Box, Send)))]
 async fn wrong_send_syntax() -> u32 {
     42
 }
 
-// Cannot be used on structs
-#[derive(Builder)]
-#[builder(derive(IntoFuture(Box)))]
-struct AsyncConfig {
-    value: u32,
+#[builder(derive(IntoFuture), finish_fn(validate = |value| -> Result<(), String> {
+    Ok(())
+}))]
+fn fallible_sync_function(value: u32) -> core::future::Ready<u32> {
+    core::future::ready(value)
 }
 
 fn main() {}
@@ -0,0 +1,56 @@
+use bon::builder;
+
+#[builder(derive(IntoIterator))]
+async fn async_function() -> Vec<u32> {
+    vec![]
+}
+
+#[builder(derive(IntoIterator))]
+unsafe fn unsafe_function() -> Vec<u32> {
+    vec![]
+}
+
+#[builder(derive(IntoIterator))]
+fn finish_fn_member(#[builder(finish_fn)] value: u32) -> Vec<u32> {
+    vec![value]
+}
+
+#[builder(derive(IntoIterator))]
+fn unit_return_type() {}
+
+#[builder(derive(IntoIterator))]
+fn impl_trait_return_type() -> impl Iterator<Item = u32> {
+    core::iter::empty()
+}
+
+#[builder(derive(IntoIterator(Box)))]
+fn impl_trait_without_item() -> impl Clone {}
+
+#[builder(derive(IntoIterator = Box))]
+fn name_value_syntax() -> Vec<u32> {
+    vec![]
+}
+
+#[builder(derive(IntoIterator(Rc)))]
+fn unsupported_pointer() -> Vec<u32> {
+    vec![]
+}
+
+#[builder(derive(IntoIterator))]
+fn not_an_iterator() -> u32 {
+    99
+}
+
+#[builder(derive(IntoIterator), finish_fn(validate = |items| -> Result<(), String> {
+    Ok(())
+}))]
+fn fallible_finish_fn(items: Vec<u32>) -> Vec<u32> {
+    items
+}
+
+#[builder(derive(IntoIterator(Box)))]
+fn fallible_finish_fn_boxed(#[builder(validate = |value| *value > 0)] value: u32) -> Vec<u32> {
+    vec![value]
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use bon::{builder, Builder};
+
+#[builder(const, maybe_uninit)]
+const fn const_fn(x: u32) -> u32 {
+    x
+}
+
+#[derive(Builder)]
+#[builder(maybe_uninit)]
+struct Sut {
+    x: u32,
+}
+
+fn main() {
+    // The values of the members must be set before the getters are called
+    // and before the builder is finished, like with the default storage
+    let _ = Sut::builder().build();
+}
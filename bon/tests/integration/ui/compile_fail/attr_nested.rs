@@ -0,0 +1,38 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct Inner {
+    x: u32,
+    y: u32,
+}
+
+struct NoBuilder;
+
+#[derive(Builder)]
+struct IncompleteInner {
+    #[builder(nested)]
+    inner: Inner,
+}
+
+#[derive(Builder)]
+struct NestedWithInto {
+    #[builder(nested, into)]
+    inner: Inner,
+}
+
+#[derive(Builder)]
+#[builder(const)]
+struct NestedConst {
+    #[builder(nested)]
+    inner: Inner,
+}
+
+#[derive(Builder)]
+struct NestedWithoutBuilder {
+    #[builder(nested)]
+    value: NoBuilder,
+}
+
+fn main() {
+    let _ = IncompleteInner::builder().inner(|b| b.x(1)).build();
+}
@@ -45,10 +45,28 @@ fn non_consecutive_on2() {}
 )]
 fn non_consecutive_on3() {}
 
-#[builder(on(_, into), on(_, required))]
-fn non_first_required() {}
+#[builder(on(String, getter(name = get_value)))]
+fn getter_name_in_on(_x: String) {}
 
-#[builder(on(u8, required))]
-fn non_wildcard_required() {}
+#[builder(on(String, setters(name = set_value)))]
+fn setters_name_in_on(_x: String) {}
+
+#[builder(on(String, setters(some_fn(name = set_value))))]
+fn some_fn_name_in_on(_x: Option<String>) {}
+
+#[builder(on(String, into, with = |value: &str| value.to_owned()))]
+fn with_and_into_in_one_on(_x: String) {}
+
+#[builder(on(*::&str, into))]
+fn path_suffix_not_a_path(_x: &str) {}
+
+#[builder(on(*::::std::time::Duration, into))]
+fn path_suffix_with_leading_colon(_x: std::time::Duration) {}
+
+#[builder(on(String | &dyn std::fmt::Debug, into))]
+fn invalid_type_pattern_alternative(_x: String) {}
+
+#[builder(const, on(u32, default))]
+const fn default_trait_in_const_on(_x: u32) {}
 
 fn main() {}
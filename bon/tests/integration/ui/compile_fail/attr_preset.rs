@@ -0,0 +1,33 @@
+use bon::{bon, builder, Builder};
+
+bon::preset!(into_u32 = (on(u32, into)));
+bon::preset!(unknown_param = (unknown));
+
+#[derive(Builder)]
+#[builder(preset = "into_u32")]
+struct InvalidPresetValue {}
+
+#[derive(Builder)]
+#[builder(preset = <u32>::into_u32)]
+struct QualifiedSelfPresetPath {}
+
+#[builder(preset = unknown_param)]
+fn unknown_param_in_preset() {}
+
+struct Sut;
+
+#[bon]
+impl Sut {
+    #[builder(preset = unknown_param)]
+    fn method() {}
+}
+
+#[derive(Builder)]
+struct MemberLevelPreset {
+    #[builder(preset = into_u32)]
+    x1: u32,
+}
+
+bon::preset!(empty = ());
+
+fn main() {}
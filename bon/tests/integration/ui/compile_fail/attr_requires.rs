@@ -0,0 +1,60 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct Sut {
+    client_cert: Option<u32>,
+
+    #[builder(requires = client_cert)]
+    client_key: Option<u32>,
+
+    #[builder(flag, conflicts_with = ca_bundle)]
+    insecure: bool,
+
+    ca_bundle: Option<u32>,
+}
+
+#[derive(Builder)]
+struct UnknownMember {
+    #[builder(requires = unknown)]
+    a: Option<u32>,
+}
+
+#[derive(Builder)]
+struct SelfReference {
+    #[builder(conflicts_with = a)]
+    a: Option<u32>,
+}
+
+#[derive(Builder)]
+struct ConflictsWithRequired {
+    a: u32,
+
+    #[builder(conflicts_with = a)]
+    b: Option<u32>,
+}
+
+#[derive(Builder)]
+struct RequiresEach {
+    #[builder(each = item)]
+    items: Vec<u32>,
+
+    #[builder(requires = items)]
+    b: Option<u32>,
+}
+
+#[derive(Builder)]
+struct ConflictsWithEach {
+    #[builder(each = item, conflicts_with = b)]
+    items: Vec<u32>,
+
+    b: Option<u32>,
+}
+
+fn main() {
+    // `client_cert` isn't set
+    let _ = Sut::builder().client_key(1).build();
+
+    // `insecure` conflicts with `ca_bundle`
+    let _ = Sut::builder().insecure().ca_bundle(1).build();
+    let _ = Sut::builder().ca_bundle(1).insecure().build();
+}
@@ -0,0 +1,20 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct Sut {
+    #[builder(stage = 1)]
+    host: Option<String>,
+
+    #[builder(stage = 2)]
+    user: Option<String>,
+}
+
+#[derive(Builder)]
+struct StageWithEach {
+    #[builder(stage = 1, each = item)]
+    items: Vec<u32>,
+}
+
+fn main() {
+    let _ = Sut::builder().user("admin".to_owned()).host("localhost".to_owned());
+}
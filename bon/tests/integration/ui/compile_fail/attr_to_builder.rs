@@ -0,0 +1,28 @@
+use bon::{builder, Builder};
+
+#[builder(to_builder)]
+fn sut(x: u32) -> u32 {
+    x
+}
+
+#[derive(Builder)]
+#[builder(to_builder)]
+enum Msg {
+    Ping { seq: u32 },
+}
+
+#[derive(Builder)]
+#[builder(to_builder)]
+struct NotClone {
+    #[builder(start_fn)]
+    x: u32,
+
+    y: NonClone,
+}
+
+struct NonClone;
+
+fn main() {
+    let sut = NotClone::builder(1).y(NonClone).build();
+    let _ = sut.to_builder();
+}
@@ -0,0 +1,61 @@
+use bon::Builder;
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = |a| -> Result<(), ()> { Ok(()) }))]
+struct WrongInputsCount {
+    a: u32,
+    b: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = |a| Ok(())))]
+struct MissingReturnType {
+    a: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = |a| -> Result<u32, ()> { Ok(*a) }))]
+struct NonUnitOk {
+    a: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = validate))]
+struct PathWithoutError {
+    a: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = |a| -> Result<(), ()> { Ok(()) }, error = ()))]
+struct ClosureWithError {
+    a: u32,
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(error = ()))]
+struct ErrorWithoutValidate {
+    a: u32,
+}
+
+fn validate(_: &u32) -> Result<(), String> {
+    Ok(())
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = validate, error = ()))]
+struct MismatchedErrorType {
+    a: u32,
+}
+
+fn validate_unit_err(_: &u32) -> Result<(), ()> {
+    Ok(())
+}
+
+#[derive(Builder)]
+#[builder(finish_fn(validate = validate_unit_err, error = ()))]
+struct MissingFromImpl {
+    #[builder(validate = |a| *a > 0)]
+    a: u32,
+}
+
+fn main() {}
@@ -0,0 +1,49 @@
+use bon::{builder, Builder};
+
+#[builder(update)]
+fn sut(x: u32) -> u32 {
+    x
+}
+
+#[derive(Builder)]
+#[builder(update)]
+enum Msg {
+    Ping { seq: u32 },
+}
+
+#[derive(Builder)]
+#[builder(update, groups(source = exactly_one))]
+struct Group {
+    #[builder(group = source)]
+    path: Option<u32>,
+
+    #[builder(group = source)]
+    url: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(update)]
+struct Requires {
+    path: Option<u32>,
+
+    #[builder(requires = path)]
+    port: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(update)]
+struct ConflictsWith {
+    #[builder(conflicts_with = url)]
+    path: Option<u32>,
+    url: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(update)]
+struct Stage {
+    #[builder(stage = 1)]
+    path: Option<u32>,
+    url: Option<u32>,
+}
+
+fn main() {}
@@ -0,0 +1,59 @@
+use bon::Builder;
+
+#[derive(Builder)]
+struct TwoInputs {
+    #[builder(validate = |a, b| a == b)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct NotAClosure {
+    #[builder(validate = validate_value)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct WrongReturnType {
+    #[builder(validate = |value| -> u32 { *value })]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct ResultWithoutErrorType {
+    #[builder(validate = |value| -> Result<()> { Ok(()) })]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct ResultWithNonUnitOk {
+    #[builder(validate = |value| -> Result<u32, ()> { Ok(*value) })]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct ValidateStartFn {
+    #[builder(start_fn, validate = |value| *value > 0)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct ValidateSkip {
+    #[builder(skip, validate = |value| *value > 0)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct WrongValueType {
+    #[builder(validate = |value: u32| value > 0)]
+    value: u32,
+}
+
+#[derive(Builder)]
+struct ResultNotHandled {
+    #[builder(validate = |value| *value > 0)]
+    value: u32,
+}
+
+fn main() {
+    let _: ResultNotHandled = ResultNotHandled::builder().value(1).build();
+}
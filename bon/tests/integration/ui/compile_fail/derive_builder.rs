@@ -1,12 +1,70 @@
 use bon::Builder;
 
 #[derive(Builder)]
-struct TupleStruct(u32, u32);
+struct UnitStruct;
 
 #[derive(Builder)]
-struct TupleStructsAreUnsupported(u32, u32);
+struct TupleStructUnnamedFieldWithConfig(#[builder(default)] u32, u32);
 
 #[derive(Builder)]
-enum EnumsAreUnsupportedWithDerive {}
+struct TupleStructPositionalAfterNamed(#[builder(name = x)] u32, u32);
+
+#[derive(Builder)]
+enum EmptyEnum {}
+
+#[derive(Builder)]
+enum EnumWithoutNamedFields {
+    Unit,
+    Tuple(u32),
+}
+
+#[derive(Builder)]
+#[builder(start_fn = create)]
+enum EnumLevelStartFnName {
+    A { x: u32 },
+}
+
+#[derive(Builder)]
+#[builder(builder_type(name = SharedBuilder))]
+enum EnumLevelBuilderTypeName {
+    A { x: u32 },
+}
+
+#[derive(Builder)]
+#[builder(state_mod = shared)]
+enum EnumLevelStateModName {
+    A { x: u32 },
+}
+
+#[derive(Builder)]
+enum VariantLevelConfig {
+    #[builder(start_fn = a)]
+    A { x: u32 },
+
+    #[builder(start_fn = a)]
+    B { x: u32 },
+}
+
+#[derive(Builder)]
+enum BuilderAttrOnTupleVariant {
+    A { x: u32 },
+
+    #[builder(start_fn = b)]
+    B(u32),
+}
+
+#[derive(Builder)]
+enum BuilderAttrOnTupleVariantField {
+    A { x: u32 },
+    B(#[builder(default)] u32),
+}
+
+#[derive(Builder)]
+enum BuilderAttrOnUnitVariant {
+    A { x: u32 },
+
+    #[builder(start_fn = c)]
+    C,
+}
 
 fn main() {}
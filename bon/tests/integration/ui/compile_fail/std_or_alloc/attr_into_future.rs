@@ -1,4 +1,4 @@
-use bon::{bon, builder};
+use bon::{bon, builder, Builder};
 use core::future::IntoFuture;
 
 fn _non_send() {
@@ -24,4 +24,22 @@ fn _non_send() {
     assert_send(&sut().value(21).into_future());
 }
 
+// IntoFuture can only be used with async functions or functions returning futures
+#[builder(derive(IntoFuture(Box)))]
+fn sync_function() -> u32 {
+    42
+}
+
+#[builder(derive(IntoFuture(Box)), typestate = false)]
+fn fallible_sync_function_boxed(value: u32) -> core::future::Ready<u32> {
+    core::future::ready(value)
+}
+
+// Cannot be used on structs
+#[derive(Builder)]
+#[builder(derive(IntoFuture(Box)))]
+struct AsyncConfig {
+    value: u32,
+}
+
 fn main() {}
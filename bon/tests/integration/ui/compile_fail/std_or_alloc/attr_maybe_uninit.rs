@@ -0,0 +1,9 @@
+use bon::Builder;
+
+#[derive(Builder)]
+#[builder(maybe_uninit, typestate = false)]
+struct NoTypestate {
+    x: u32,
+}
+
+fn main() {}
@@ -0,0 +1,46 @@
+use bon::{builder, Builder};
+
+#[builder(const, typestate = false)]
+const fn const_fn(x: u32) -> u32 {
+    x
+}
+
+#[derive(Builder)]
+#[builder(typestate = false, groups(source = exactly_one))]
+struct Groups {
+    #[builder(group = source)]
+    a: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(typestate = false, derive(Into))]
+struct DeriveInto {
+    x: u32,
+}
+
+#[derive(Builder)]
+#[builder(typestate = false)]
+struct Requires {
+    x: Option<u32>,
+
+    #[builder(requires = x)]
+    y: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(typestate = false)]
+struct ConflictsWith {
+    x: Option<u32>,
+
+    #[builder(conflicts_with = x)]
+    y: Option<u32>,
+}
+
+#[derive(Builder)]
+#[builder(typestate = false)]
+struct Stage {
+    #[builder(stage = 1)]
+    x: u32,
+}
+
+fn main() {}
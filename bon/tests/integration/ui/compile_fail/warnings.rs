@@ -76,4 +76,27 @@ fn main() {
         builder.get_x2();
         builder.get_x3();
     }
+
+    // Test attributes forwarded via `attrs(...)`
+    {
+        #[derive(Builder)]
+        #[builder(
+            builder_type(attrs(must_use = "custom builder must use")),
+            finish_fn(attrs(must_use = "custom finish_fn must use")),
+        )]
+        struct CustomAttrs {
+            #[builder(setters(attrs(deprecated = "custom deprecation")))]
+            x1: u32,
+
+            #[builder(getter(attrs(must_use = "custom getter must use")))]
+            x2: u32,
+        }
+
+        CustomAttrs::builder();
+
+        let builder = CustomAttrs::builder().x1(1).x2(2);
+
+        builder.get_x2();
+        builder.build();
+    }
 }
@@ -170,6 +170,14 @@ export default defineConfig({
                             text: "Compatibility",
                             link: "/guide/basics/compatibility",
                         },
+                        {
+                            text: "Enums",
+                            link: "/guide/basics/enums",
+                        },
+                        {
+                            text: "Tuple Structs",
+                            link: "/guide/basics/tuple-structs",
+                        },
                     ],
                 },
                 {
@@ -283,10 +291,22 @@ export default defineConfig({
                                     text: "generics 🔬",
                                     link: "/reference/builder/top-level/generics",
                                 },
+                                {
+                                    text: "groups",
+                                    link: "/reference/builder/top-level/groups",
+                                },
+                                {
+                                    text: "maybe_uninit",
+                                    link: "/reference/builder/top-level/maybe_uninit",
+                                },
                                 {
                                     text: "on",
                                     link: "/reference/builder/top-level/on",
                                 },
+                                {
+                                    text: "preset",
+                                    link: "/reference/builder/top-level/preset",
+                                },
                                 {
                                     text: "start_fn",
                                     link: "/reference/builder/top-level/start_fn",
@@ -295,6 +315,18 @@ export default defineConfig({
                                     text: "state_mod",
                                     link: "/reference/builder/top-level/state_mod",
                                 },
+                                {
+                                    text: "to_builder",
+                                    link: "/reference/builder/top-level/to_builder",
+                                },
+                                {
+                                    text: "typestate",
+                                    link: "/reference/builder/top-level/typestate",
+                                },
+                                {
+                                    text: "update",
+                                    link: "/reference/builder/top-level/update",
+                                },
                             ],
                         },
                         {
@@ -302,10 +334,18 @@ export default defineConfig({
                             link: "/reference/builder#member-attributes",
                             collapsed: false,
                             items: [
+                                {
+                                    text: "conflicts_with",
+                                    link: "/reference/builder/member/conflicts_with",
+                                },
                                 {
                                     text: "default",
                                     link: "/reference/builder/member/default",
                                 },
+                                {
+                                    text: "each",
+                                    link: "/reference/builder/member/each",
+                                },
                                 {
                                     text: "field",
                                     link: "/reference/builder/member/field",
@@ -314,10 +354,18 @@ export default defineConfig({
                                     text: "finish_fn",
                                     link: "/reference/builder/member/finish_fn",
                                 },
+                                {
+                                    text: "flag",
+                                    link: "/reference/builder/member/flag",
+                                },
                                 {
                                     text: "getter",
                                     link: "/reference/builder/member/getter",
                                 },
+                                {
+                                    text: "group",
+                                    link: "/reference/builder/member/group",
+                                },
                                 {
                                     text: "into",
                                     link: "/reference/builder/member/into",
@@ -326,6 +374,10 @@ export default defineConfig({
                                     text: "name",
                                     link: "/reference/builder/member/name",
                                 },
+                                {
+                                    text: "nested",
+                                    link: "/reference/builder/member/nested",
+                                },
                                 {
                                     text: "overwritable 🔬",
                                     link: "/reference/builder/member/overwritable",
@@ -334,6 +386,10 @@ export default defineConfig({
                                     text: "required",
                                     link: "/reference/builder/member/required",
                                 },
+                                {
+                                    text: "requires",
+                                    link: "/reference/builder/member/requires",
+                                },
                                 {
                                     text: "setters",
                                     link: "/reference/builder/member/setters",
@@ -342,10 +398,18 @@ export default defineConfig({
                                     text: "skip",
                                     link: "/reference/builder/member/skip",
                                 },
+                                {
+                                    text: "stage",
+                                    link: "/reference/builder/member/stage",
+                                },
                                 {
                                     text: "start_fn",
                                     link: "/reference/builder/member/start_fn",
                                 },
+                                {
+                                    text: "validate",
+                                    link: "/reference/builder/member/validate",
+                                },
                                 {
                                     text: "with",
                                     link: "/reference/builder/member/with",